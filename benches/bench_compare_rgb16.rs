@@ -2,7 +2,7 @@ use std::num::NonZeroU32;
 
 use glassbench::*;
 use image::imageops;
-use resize::Pixel::{RGB16, RGB8};
+use resize::Pixel::RGB16;
 use rgb::{FromSlice, RGB};
 
 use fast_image_resize::Image;
@@ -52,6 +52,19 @@ fn get_big_u16x3_source_image() -> Image<'static> {
     .unwrap()
 }
 
+fn get_big_u16x4_source_image() -> Image<'static> {
+    let img = utils::get_big_rgba16_image();
+    let width = img.width();
+    let height = img.height();
+    Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        img.as_raw().iter().flat_map(|&c| c.to_le_bytes()).collect(),
+        PixelType::U16x4,
+    )
+    .unwrap()
+}
+
 fn get_big_i32_image() -> Image<'static> {
     let img = utils::get_big_luma16_image();
     let img_data: Vec<u8> = img
@@ -70,6 +83,24 @@ fn get_big_i32_image() -> Image<'static> {
     .unwrap()
 }
 
+fn get_big_f32_image() -> Image<'static> {
+    let img = utils::get_big_luma16_image();
+    let img_data: Vec<u8> = img
+        .as_raw()
+        .iter()
+        .flat_map(|&p| (p as f32 * (i16::MAX as f32 + 1.0)).to_le_bytes())
+        .collect();
+    let width = img.width();
+    let height = img.height();
+    Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        img_data,
+        PixelType::F32,
+    )
+    .unwrap()
+}
+
 fn get_big_u8_image() -> Image<'static> {
     let img = utils::get_big_luma8_image();
     let width = img.width();
@@ -83,6 +114,32 @@ fn get_big_u8_image() -> Image<'static> {
     .unwrap()
 }
 
+fn get_big_u16_image() -> Image<'static> {
+    let img = utils::get_big_luma16_image();
+    let width = img.width();
+    let height = img.height();
+    Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        img.as_raw().iter().flat_map(|&c| c.to_le_bytes()).collect(),
+        PixelType::U16,
+    )
+    .unwrap()
+}
+
+fn get_big_u8x2_image() -> Image<'static> {
+    let img = utils::get_big_luma_alpha8_image();
+    let width = img.width();
+    let height = img.height();
+    Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        img.into_raw(),
+        PixelType::U8x2,
+    )
+    .unwrap()
+}
+
 fn get_small_source_image() -> Image<'static> {
     let img = utils::get_small_rgba_image();
     let width = img.width();
@@ -178,7 +235,7 @@ fn avx2_lanczos3_upscale_bench(bench: &mut Bench) {
     });
 }
 
-fn native_lanczos3_i32_bench(bench: &mut Bench) {
+fn i32_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name: &str) {
     let image = get_big_i32_image();
     let mut res_image = Image::new(
         NonZeroU32::new(NEW_WIDTH).unwrap(),
@@ -189,9 +246,29 @@ fn native_lanczos3_i32_bench(bench: &mut Bench) {
     let mut dst_image = res_image.view_mut();
     let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
     unsafe {
-        resizer.set_cpu_extensions(CpuExtensions::None);
+        resizer.set_cpu_extensions(cpu_extensions);
     }
-    bench.task("i32 lanczos3 wo SIMD", |task| {
+    bench.task(name, |task| {
+        task.iter(|| {
+            resizer.resize(&src_image, &mut dst_image).unwrap();
+        })
+    });
+}
+
+fn f32_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name: &str) {
+    let image = get_big_f32_image();
+    let mut res_image = Image::new(
+        NonZeroU32::new(NEW_WIDTH).unwrap(),
+        NonZeroU32::new(NEW_HEIGHT).unwrap(),
+        image.pixel_type(),
+    );
+    let src_image = image.view();
+    let mut dst_image = res_image.view_mut();
+    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+    unsafe {
+        resizer.set_cpu_extensions(cpu_extensions);
+    }
+    bench.task(name, |task| {
         task.iter(|| {
             resizer.resize(&src_image, &mut dst_image).unwrap();
         })
@@ -238,6 +315,26 @@ fn native_nearest_u8_bench(bench: &mut Bench) {
     });
 }
 
+fn u8x2_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name: &str) {
+    let image = get_big_u8x2_image();
+    let mut res_image = Image::new(
+        NonZeroU32::new(NEW_WIDTH).unwrap(),
+        NonZeroU32::new(NEW_HEIGHT).unwrap(),
+        image.pixel_type(),
+    );
+    let src_image = image.view();
+    let mut dst_image = res_image.view_mut();
+    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+    unsafe {
+        resizer.set_cpu_extensions(cpu_extensions);
+    }
+    bench.task(name, |task| {
+        task.iter(|| {
+            resizer.resize(&src_image, &mut dst_image).unwrap();
+        })
+    });
+}
+
 fn u8x3_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name: &str) {
     let image = get_big_u8x3_source_image();
     let mut res_image = Image::new(
@@ -258,6 +355,26 @@ fn u8x3_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name: &
     });
 }
 
+fn u16_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name: &str) {
+    let image = get_big_u16_image();
+    let mut res_image = Image::new(
+        NonZeroU32::new(NEW_WIDTH).unwrap(),
+        NonZeroU32::new(NEW_HEIGHT).unwrap(),
+        image.pixel_type(),
+    );
+    let src_image = image.view();
+    let mut dst_image = res_image.view_mut();
+    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+    unsafe {
+        resizer.set_cpu_extensions(cpu_extensions);
+    }
+    bench.task(name, |task| {
+        task.iter(|| {
+            resizer.resize(&src_image, &mut dst_image).unwrap();
+        })
+    });
+}
+
 fn u16x3_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name: &str) {
     let image = get_big_u16x3_source_image();
     let mut res_image = Image::new(
@@ -278,6 +395,26 @@ fn u16x3_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name:
     });
 }
 
+fn u16x4_lanczos3_bench(bench: &mut Bench, cpu_extensions: CpuExtensions, name: &str) {
+    let image = get_big_u16x4_source_image();
+    let mut res_image = Image::new(
+        NonZeroU32::new(NEW_WIDTH).unwrap(),
+        NonZeroU32::new(NEW_HEIGHT).unwrap(),
+        image.pixel_type(),
+    );
+    let src_image = image.view();
+    let mut dst_image = res_image.view_mut();
+    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+    unsafe {
+        resizer.set_cpu_extensions(cpu_extensions);
+    }
+    bench.task(name, |task| {
+        task.iter(|| {
+            resizer.resize(&src_image, &mut dst_image).unwrap();
+        })
+    });
+}
+
 pub fn main() {
     use glassbench::*;
     let name = env!("CARGO_CRATE_NAME");
@@ -288,23 +425,39 @@ pub fn main() {
         native_nearest_u8_bench(&mut bench);
 
         u8_lanczos3_bench(&mut bench, CpuExtensions::None, "u8 lanczos3 wo SIMD");
+        u8x2_lanczos3_bench(&mut bench, CpuExtensions::None, "u8x2 lanczos3 wo SIMD");
         u8x3_lanczos3_bench(&mut bench, CpuExtensions::None, "u8x3 lanczos3 wo SIMD");
         u8x4_lanczos3_bench(&mut bench, CpuExtensions::None, "u8x4 lanczos3 wo SIMD");
+        u16_lanczos3_bench(&mut bench, CpuExtensions::None, "u16 lanczos3 wo SIMD");
         u16x3_lanczos3_bench(&mut bench, CpuExtensions::None, "u16x3 lanczos3 wo SIMD");
-        native_lanczos3_i32_bench(&mut bench);
+        u16x4_lanczos3_bench(&mut bench, CpuExtensions::None, "u16x4 lanczos3 wo SIMD");
+        i32_lanczos3_bench(&mut bench, CpuExtensions::None, "i32 lanczos3 wo SIMD");
+        f32_lanczos3_bench(&mut bench, CpuExtensions::None, "f32 lanczos3 wo SIMD");
         #[cfg(target_arch = "x86_64")]
         {
             u8_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "u8 lanczos3 sse4.1");
             u8_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "u8 lanczos3 avx2");
 
+            u8x2_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "u8x2 lanczos3 sse4.1");
+            u8x2_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "u8x2 lanczos3 avx2");
+
             u8x3_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "u8x3 lanczos3 sse4.1");
             u8x3_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "u8x3 lanczos3 avx2");
+            u16_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "u16 lanczos3 sse4.1");
+            u16_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "u16 lanczos3 avx2");
             u16x3_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "u16x3 lanczos3 sse4.1");
             u16x3_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "u16x3 lanczos3 avx2");
+            u16x4_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "u16x4 lanczos3 sse4.1");
+            u16x4_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "u16x4 lanczos3 avx2");
 
             u8x4_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "u8x4 lanczos3 sse4.1");
             u8x4_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "u8x4 lanczos3 avx2");
 
+            i32_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "i32 lanczos3 sse4.1");
+            i32_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "i32 lanczos3 avx2");
+            f32_lanczos3_bench(&mut bench, CpuExtensions::Sse4_1, "f32 lanczos3 sse4.1");
+            f32_lanczos3_bench(&mut bench, CpuExtensions::Avx2, "f32 lanczos3 avx2");
+
             avx2_supersampling_lanczos3_bench(&mut bench);
             avx2_lanczos3_upscale_bench(&mut bench);
         }
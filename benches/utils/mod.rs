@@ -1,11 +1,14 @@
+#![allow(dead_code)]
+
 use std::collections::HashMap;
 use std::env;
 
 use glassbench::*;
 use image::io::Reader;
-use image::{GrayImage, ImageBuffer, Luma, Rgb, RgbImage, RgbaImage};
+use image::{GrayAlphaImage, GrayImage, ImageBuffer, Luma, Rgb, RgbImage, Rgba, RgbaImage};
 
 pub type Rgb16Image = ImageBuffer<Rgb<u16>, Vec<u16>>;
+pub type Rgba16Image = ImageBuffer<Rgba<u16>, Vec<u16>>;
 
 pub fn get_big_rgb_image() -> RgbImage {
     let cur_dir = env::current_dir().unwrap();
@@ -25,6 +28,15 @@ pub fn get_big_rgb16_image() -> Rgb16Image {
     img.to_rgb16()
 }
 
+pub fn get_big_rgba16_image() -> Rgba16Image {
+    let cur_dir = env::current_dir().unwrap();
+    let img = Reader::open(cur_dir.join("data/nasa-4928x3279-rgba.png"))
+        .unwrap()
+        .decode()
+        .unwrap();
+    img.to_rgba16()
+}
+
 pub fn get_big_rgba_image() -> RgbaImage {
     let cur_dir = env::current_dir().unwrap();
     let img = Reader::open(cur_dir.join("data/nasa-4928x3279-rgba.png"))
@@ -52,6 +64,15 @@ pub fn get_big_luma8_image() -> GrayImage {
     img.to_luma8()
 }
 
+pub fn get_big_luma_alpha8_image() -> GrayAlphaImage {
+    let cur_dir = env::current_dir().unwrap();
+    let img = Reader::open(cur_dir.join("data/nasa-4928x3279-rgba.png"))
+        .unwrap()
+        .decode()
+        .unwrap();
+    img.to_luma_alpha8()
+}
+
 pub fn get_small_rgba_image() -> RgbaImage {
     let cur_dir = env::current_dir().unwrap();
     let img = Reader::open(cur_dir.join("data/nasa-852x567.png"))
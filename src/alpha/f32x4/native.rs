@@ -0,0 +1,65 @@
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::F32x4;
+
+// Multiplication
+
+#[inline]
+pub(crate) fn multiply_alpha(
+    src_image: TypedImageView<F32x4>,
+    mut dst_image: TypedImageViewMut<F32x4>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+pub(crate) fn multiply_alpha_inplace(mut image: TypedImageViewMut<F32x4>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = unsafe { std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len()) };
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline(always)]
+pub(crate) fn multiply_alpha_row(src_row: &[F32x4], dst_row: &mut [F32x4]) {
+    for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row) {
+        let [r, g, b, a] = src_pixel.0;
+        dst_pixel.0 = [r * a, g * a, b * a, a];
+    }
+}
+
+// Division
+
+#[inline]
+pub(crate) fn divide_alpha(
+    src_image: TypedImageView<F32x4>,
+    mut dst_image: TypedImageViewMut<F32x4>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+pub(crate) fn divide_alpha_inplace(mut image: TypedImageViewMut<F32x4>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = unsafe { std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len()) };
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline(always)]
+pub(crate) fn divide_alpha_row(src_row: &[F32x4], dst_row: &mut [F32x4]) {
+    for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row) {
+        let [r, g, b, a] = src_pixel.0;
+        let recip_alpha = if a == 0. { 0. } else { 1. / a };
+        dst_pixel.0 = [r * recip_alpha, g * recip_alpha, b * recip_alpha, a];
+    }
+}
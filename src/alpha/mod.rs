@@ -1,16 +1,37 @@
 pub use errors::*;
 
 use crate::image_view::{TypedImageView, TypedImageViewMut};
-use crate::pixels::U8x4;
+use crate::pixels::{F32x4, Pixel, PixelType, U16x4, U8x2, U8x4};
 use crate::CpuExtensions;
 use crate::{ImageView, ImageViewMut};
 
-#[cfg(target_arch = "x86_64")]
-mod avx2;
+mod common;
 mod errors;
-mod native;
-#[cfg(target_arch = "x86_64")]
-mod sse4;
+mod f32x4;
+mod u16x4;
+mod u8x2;
+mod u8x4;
+
+pub(crate) trait AlphaMulDiv
+where
+    Self: Pixel,
+{
+    fn multiply_alpha(
+        src_image: TypedImageView<Self>,
+        dst_image: TypedImageViewMut<Self>,
+        cpu_extensions: CpuExtensions,
+    );
+
+    fn multiply_alpha_inplace(image: TypedImageViewMut<Self>, cpu_extensions: CpuExtensions);
+
+    fn divide_alpha(
+        src_image: TypedImageView<Self>,
+        dst_image: TypedImageViewMut<Self>,
+        cpu_extensions: CpuExtensions,
+    );
+
+    fn divide_alpha_inplace(image: TypedImageViewMut<Self>, cpu_extensions: CpuExtensions);
+}
 
 /// Methods of this structure used to multiply or divide RGB-channels
 /// by alpha-channel.
@@ -51,107 +72,166 @@ impl MulDiv {
         self.cpu_extensions = extensions;
     }
 
-    /// Multiplies RGB-channels of source image by alpha-channel and store
+    /// Multiplies color-channels of source image by alpha-channel and store
     /// result into destination image.
     pub fn multiply_alpha(
         &self,
         src_image: &ImageView,
         dst_image: &mut ImageViewMut,
     ) -> Result<(), MulDivImagesError> {
-        let (src_image_u8x4, dst_image_u8x4) = assert_images(src_image, dst_image)?;
-        match self.cpu_extensions {
-            #[cfg(target_arch = "x86_64")]
-            CpuExtensions::Avx2 => unsafe {
-                avx2::mul::multiply_alpha_avx2(src_image_u8x4, dst_image_u8x4)
-            },
-            #[cfg(target_arch = "x86_64")]
-            CpuExtensions::Sse4_1 => unsafe {
-                sse4::mul::multiply_alpha_sse4(src_image_u8x4, dst_image_u8x4)
-            },
-            _ => native::mul::multiply_alpha_native(src_image_u8x4, dst_image_u8x4),
+        if src_image.pixel_type() != dst_image.pixel_type() {
+            return Err(MulDivImagesError::PixelTypeIsDifferent);
+        }
+        match src_image.pixel_type() {
+            PixelType::U8x2 => {
+                if let Some(src) = src_image.u8x2_image() {
+                    if let Some(dst) = dst_image.u8x2_image() {
+                        let (src, dst) = assert_images(src, dst)?;
+                        U8x2::multiply_alpha(src, dst, self.cpu_extensions);
+                    }
+                }
+            }
+            PixelType::U8x4 => {
+                if let Some(src) = src_image.u8x4_image() {
+                    if let Some(dst) = dst_image.u8x4_image() {
+                        let (src, dst) = assert_images(src, dst)?;
+                        U8x4::multiply_alpha(src, dst, self.cpu_extensions);
+                    }
+                }
+            }
+            PixelType::U16x4 => {
+                if let Some(src) = src_image.u16x4_image() {
+                    if let Some(dst) = dst_image.u16x4_image() {
+                        let (src, dst) = assert_images(src, dst)?;
+                        U16x4::multiply_alpha(src, dst, self.cpu_extensions);
+                    }
+                }
+            }
+            PixelType::F32x4 => {
+                if let Some(src) = src_image.f32x4_image() {
+                    if let Some(dst) = dst_image.f32x4_image() {
+                        let (src, dst) = assert_images(src, dst)?;
+                        F32x4::multiply_alpha(src, dst, self.cpu_extensions);
+                    }
+                }
+            }
+            _ => return Err(MulDivImagesError::UnsupportedPixelType),
         }
         Ok(())
     }
 
-    /// Multiplies RGB-channels of image by alpha-channel inplace.
+    /// Multiplies color-channels of image by alpha-channel inplace.
     pub fn multiply_alpha_inplace(&self, image: &mut ImageViewMut) -> Result<(), MulDivImageError> {
-        let image_u8x4 = assert_image(image)?;
-        match self.cpu_extensions {
-            #[cfg(target_arch = "x86_64")]
-            CpuExtensions::Avx2 => unsafe { avx2::mul::multiply_alpha_inplace_avx2(image_u8x4) },
-            #[cfg(target_arch = "x86_64")]
-            CpuExtensions::Sse4_1 => unsafe { sse4::mul::multiply_alpha_inplace_sse4(image_u8x4) },
-            _ => native::mul::multiply_alpha_inplace_native(image_u8x4),
+        match image.pixel_type() {
+            PixelType::U8x2 => {
+                if let Some(image) = image.u8x2_image() {
+                    U8x2::multiply_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            PixelType::U8x4 => {
+                if let Some(image) = image.u8x4_image() {
+                    U8x4::multiply_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            PixelType::U16x4 => {
+                if let Some(image) = image.u16x4_image() {
+                    U16x4::multiply_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            PixelType::F32x4 => {
+                if let Some(image) = image.f32x4_image() {
+                    F32x4::multiply_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            _ => return Err(MulDivImageError::UnsupportedPixelType),
         }
         Ok(())
     }
 
-    /// Divides RGB-channels of source image by alpha-channel and store
+    /// Divides color-channels of source image by alpha-channel and store
     /// result into destination image.
     pub fn divide_alpha(
         &self,
         src_image: &ImageView,
         dst_image: &mut ImageViewMut,
     ) -> Result<(), MulDivImagesError> {
-        let (src_image_u8x4, dst_image_u8x4) = assert_images(src_image, dst_image)?;
-        match self.cpu_extensions {
-            #[cfg(target_arch = "x86_64")]
-            CpuExtensions::Avx2 => unsafe {
-                avx2::div::divide_alpha_avx2(src_image_u8x4, dst_image_u8x4)
-            },
-            #[cfg(target_arch = "x86_64")]
-            CpuExtensions::Sse4_1 => unsafe {
-                sse4::div::divide_alpha_sse4(src_image_u8x4, dst_image_u8x4)
-            },
-            _ => native::div::divide_alpha_native(src_image_u8x4, dst_image_u8x4),
+        if src_image.pixel_type() != dst_image.pixel_type() {
+            return Err(MulDivImagesError::PixelTypeIsDifferent);
+        }
+        match src_image.pixel_type() {
+            PixelType::U8x2 => {
+                if let Some(src) = src_image.u8x2_image() {
+                    if let Some(dst) = dst_image.u8x2_image() {
+                        let (src, dst) = assert_images(src, dst)?;
+                        U8x2::divide_alpha(src, dst, self.cpu_extensions);
+                    }
+                }
+            }
+            PixelType::U8x4 => {
+                if let Some(src) = src_image.u8x4_image() {
+                    if let Some(dst) = dst_image.u8x4_image() {
+                        let (src, dst) = assert_images(src, dst)?;
+                        U8x4::divide_alpha(src, dst, self.cpu_extensions);
+                    }
+                }
+            }
+            PixelType::U16x4 => {
+                if let Some(src) = src_image.u16x4_image() {
+                    if let Some(dst) = dst_image.u16x4_image() {
+                        let (src, dst) = assert_images(src, dst)?;
+                        U16x4::divide_alpha(src, dst, self.cpu_extensions);
+                    }
+                }
+            }
+            PixelType::F32x4 => {
+                if let Some(src) = src_image.f32x4_image() {
+                    if let Some(dst) = dst_image.f32x4_image() {
+                        let (src, dst) = assert_images(src, dst)?;
+                        F32x4::divide_alpha(src, dst, self.cpu_extensions);
+                    }
+                }
+            }
+            _ => return Err(MulDivImagesError::UnsupportedPixelType),
         }
         Ok(())
     }
 
-    /// Divides RGB-channels of image by alpha-channel inplace.
+    /// Divides color-channels of image by alpha-channel inplace.
     pub fn divide_alpha_inplace(&self, image: &mut ImageViewMut) -> Result<(), MulDivImageError> {
-        let image_u8x4 = assert_image(image)?;
-        match self.cpu_extensions {
-            #[cfg(target_arch = "x86_64")]
-            CpuExtensions::Avx2 => unsafe { avx2::div::divide_alpha_inplace_avx2(image_u8x4) },
-            #[cfg(target_arch = "x86_64")]
-            CpuExtensions::Sse4_1 => unsafe { sse4::div::divide_alpha_inplace_sse4(image_u8x4) },
-            _ => native::div::divide_alpha_inplace_native(image_u8x4),
+        match image.pixel_type() {
+            PixelType::U8x2 => {
+                if let Some(image) = image.u8x2_image() {
+                    U8x2::divide_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            PixelType::U8x4 => {
+                if let Some(image) = image.u8x4_image() {
+                    U8x4::divide_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            PixelType::U16x4 => {
+                if let Some(image) = image.u16x4_image() {
+                    U16x4::divide_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            PixelType::F32x4 => {
+                if let Some(image) = image.f32x4_image() {
+                    F32x4::divide_alpha_inplace(image, self.cpu_extensions);
+                }
+            }
+            _ => return Err(MulDivImageError::UnsupportedPixelType),
         }
         Ok(())
     }
 }
 
 #[inline]
-fn assert_images<'s, 'd, 'da>(
-    src_image: &'s ImageView<'s>,
-    dst_image: &'d mut ImageViewMut<'da>,
-) -> Result<
-    (
-        TypedImageView<'s, 's, U8x4>,
-        TypedImageViewMut<'d, 'da, U8x4>,
-    ),
-    MulDivImagesError,
-> {
-    let src_image_u8x4 = src_image
-        .u8x4_image()
-        .ok_or(MulDivImagesError::UnsupportedPixelType)?;
-    let dst_image_u8x4 = dst_image
-        .u8x4_image()
-        .ok_or(MulDivImagesError::UnsupportedPixelType)?;
-    if src_image_u8x4.width() != dst_image_u8x4.width()
-        || src_image_u8x4.height() != dst_image_u8x4.height()
-    {
+fn assert_images<'s, 'd, 'da, P: Pixel>(
+    src_image: TypedImageView<'s, 's, P>,
+    dst_image: TypedImageViewMut<'d, 'da, P>,
+) -> Result<(TypedImageView<'s, 's, P>, TypedImageViewMut<'d, 'da, P>), MulDivImagesError> {
+    if src_image.width() != dst_image.width() || src_image.height() != dst_image.height() {
         return Err(MulDivImagesError::SizeIsDifferent);
     }
-    Ok((src_image_u8x4, dst_image_u8x4))
-}
-
-#[inline]
-fn assert_image<'a, 'b>(
-    image: &'a mut ImageViewMut<'b>,
-) -> Result<TypedImageViewMut<'a, 'b, U8x4>, MulDivImageError> {
-    image
-        .u8x4_image()
-        .ok_or(MulDivImageError::UnsupportedPixelType)
+    Ok((src_image, dst_image))
 }
@@ -1,2 +0,0 @@
-pub(crate) mod div;
-pub(crate) mod mul;
@@ -0,0 +1,145 @@
+use std::arch::x86_64::*;
+
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::U16x4;
+use crate::simd_utils;
+
+use super::sse4;
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn multiply_alpha(
+    src_image: TypedImageView<U16x4>,
+    mut dst_image: TypedImageViewMut<U16x4>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn multiply_alpha_inplace(mut image: TypedImageViewMut<U16x4>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len());
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn multiply_alpha_row(src_row: &[U16x4], dst_row: &mut [U16x4]) {
+    let half = _mm256_set1_epi32(0x8000);
+
+    let src_chunks = src_row.chunks_exact(4);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst_row.chunks_exact_mut(4);
+
+    for (src, dst) in src_chunks.zip(&mut dst_chunks) {
+        let src_pixels = simd_utils::loadu_si256(src, 0);
+
+        // Pixels 0 and 1
+        let pix01 = _mm256_cvtepu16_epi32(_mm256_extracti128_si256::<0>(src_pixels));
+        // Pixels 2 and 3
+        let pix23 = _mm256_cvtepu16_epi32(_mm256_extracti128_si256::<1>(src_pixels));
+
+        let alpha01 = _mm256_shuffle_epi32::<0b11_11_11_11>(pix01);
+        let alpha23 = _mm256_shuffle_epi32::<0b11_11_11_11>(pix23);
+
+        let pix01 = _mm256_add_epi32(_mm256_mullo_epi32(pix01, alpha01), half);
+        let pix01 =
+            _mm256_srli_epi32::<16>(_mm256_add_epi32(pix01, _mm256_srli_epi32::<16>(pix01)));
+
+        let pix23 = _mm256_add_epi32(_mm256_mullo_epi32(pix23, alpha23), half);
+        let pix23 =
+            _mm256_srli_epi32::<16>(_mm256_add_epi32(pix23, _mm256_srli_epi32::<16>(pix23)));
+
+        // Packing is performed inside of 128-bit lanes,
+        // so pixels have order 0, 2, 1, 3 after it.
+        let rgb = _mm256_packus_epi32(pix01, pix23);
+        let rgb = _mm256_permute4x64_epi64::<0b11_01_10_00>(rgb);
+        let dst_pixels = _mm256_blend_epi16::<0b1000_1000>(rgb, src_pixels);
+
+        _mm256_storeu_si256(dst.as_mut_ptr() as *mut __m256i, dst_pixels);
+    }
+
+    if !src_remainder.is_empty() {
+        let dst_reminder = dst_chunks.into_remainder();
+        sse4::multiply_alpha_row(src_remainder, dst_reminder);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn divide_alpha(
+    src_image: TypedImageView<U16x4>,
+    mut dst_image: TypedImageViewMut<U16x4>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn divide_alpha_inplace(mut image: TypedImageViewMut<U16x4>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len());
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn divide_alpha_row(src_row: &[U16x4], dst_row: &mut [U16x4]) {
+    let src_chunks = src_row.chunks_exact(4);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst_row.chunks_exact_mut(4);
+
+    for (src, dst) in src_chunks.zip(&mut dst_chunks) {
+        let src_pixels = simd_utils::loadu_si256(src, 0);
+
+        let pix01 = _mm256_cvtepi32_ps(_mm256_cvtepu16_epi32(_mm256_extracti128_si256::<0>(
+            src_pixels,
+        )));
+        let pix23 = _mm256_cvtepi32_ps(_mm256_cvtepu16_epi32(_mm256_extracti128_si256::<1>(
+            src_pixels,
+        )));
+
+        let pix01 = _mm256_cvttps_epi32(divide_pixels_by_alpha(pix01));
+        let pix23 = _mm256_cvttps_epi32(divide_pixels_by_alpha(pix23));
+
+        // Packing is performed inside of 128-bit lanes,
+        // so pixels have order 0, 2, 1, 3 after it.
+        let rgb = _mm256_packus_epi32(pix01, pix23);
+        let rgb = _mm256_permute4x64_epi64::<0b11_01_10_00>(rgb);
+        let dst_pixels = _mm256_blend_epi16::<0b1000_1000>(rgb, src_pixels);
+
+        _mm256_storeu_si256(dst.as_mut_ptr() as *mut __m256i, dst_pixels);
+    }
+
+    if !src_remainder.is_empty() {
+        let dst_reminder = dst_chunks.into_remainder();
+        sse4::divide_alpha_row(src_remainder, dst_reminder);
+    }
+}
+
+/// Divides components of two pixels (stored as f32 values) by its alpha.
+/// The result is rounded and clipped, but isn't truncated to integers yet.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn divide_pixels_by_alpha(pix: __m256) -> __m256 {
+    let zero = _mm256_setzero_ps();
+    let half = _mm256_set1_ps(0.5);
+    let max_value = _mm256_set1_ps(65535.0);
+
+    let alpha = _mm256_shuffle_ps::<0b11_11_11_11>(pix, pix);
+    let recip_alpha = _mm256_div_ps(max_value, alpha);
+    // Result of division by zero alpha must be zero.
+    let recip_alpha = _mm256_andnot_ps(_mm256_cmp_ps::<_CMP_EQ_OQ>(alpha, zero), recip_alpha);
+
+    let pix = _mm256_add_ps(_mm256_mul_ps(pix, recip_alpha), half);
+    _mm256_min_ps(pix, max_value)
+}
@@ -0,0 +1,107 @@
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::U16x4;
+
+// Multiplication
+
+#[inline]
+pub(crate) fn multiply_alpha(
+    src_image: TypedImageView<U16x4>,
+    mut dst_image: TypedImageViewMut<U16x4>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+pub(crate) fn multiply_alpha_inplace(mut image: TypedImageViewMut<U16x4>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = unsafe { std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len()) };
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline(always)]
+pub(crate) fn multiply_alpha_row(src_row: &[U16x4], dst_row: &mut [U16x4]) {
+    for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row) {
+        let components = src_pixel.0;
+        let alpha = components[3];
+        dst_pixel.0 = [
+            mul_div_65535(components[0], alpha),
+            mul_div_65535(components[1], alpha),
+            mul_div_65535(components[2], alpha),
+            alpha,
+        ];
+    }
+}
+
+#[inline(always)]
+pub(crate) fn mul_div_65535(a: u16, b: u16) -> u16 {
+    let tmp = a as u32 * b as u32 + 0x8000;
+    (((tmp >> 16) + tmp) >> 16) as u16
+}
+
+// Division
+
+#[inline]
+pub(crate) fn divide_alpha(
+    src_image: TypedImageView<U16x4>,
+    mut dst_image: TypedImageViewMut<U16x4>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+pub(crate) fn divide_alpha_inplace(mut image: TypedImageViewMut<U16x4>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = unsafe { std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len()) };
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline(always)]
+pub(crate) fn divide_alpha_row(src_row: &[U16x4], dst_row: &mut [U16x4]) {
+    for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row) {
+        let components = src_pixel.0;
+        let alpha = components[3];
+        let recip_alpha = if alpha == 0 {
+            0.0
+        } else {
+            65535.0 / alpha as f32
+        };
+        dst_pixel.0 = [
+            div_and_clip(components[0], recip_alpha),
+            div_and_clip(components[1], recip_alpha),
+            div_and_clip(components[2], recip_alpha),
+            alpha,
+        ];
+    }
+}
+
+#[inline(always)]
+fn div_and_clip(v: u16, recip_alpha: f32) -> u16 {
+    (v as f32 * recip_alpha + 0.5).min(65535.0) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_div_65535() {
+        for a in (0..=u16::MAX).step_by(7).chain([u16::MAX]) {
+            for b in (0..=u16::MAX).step_by(251).chain([u16::MAX]) {
+                let expected = (a as f64 * b as f64 / 65535.).round() as u16;
+                assert_eq!(mul_div_65535(a, b), expected, "a={}, b={}", a, b);
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::arch::x86_64::*;
+
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::U16x4;
+use crate::simd_utils;
+
+use super::native;
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn multiply_alpha(
+    src_image: TypedImageView<U16x4>,
+    mut dst_image: TypedImageViewMut<U16x4>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn multiply_alpha_inplace(mut image: TypedImageViewMut<U16x4>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len());
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn multiply_alpha_row(src_row: &[U16x4], dst_row: &mut [U16x4]) {
+    let src_chunks = src_row.chunks_exact(2);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst_row.chunks_exact_mut(2);
+
+    for (src, dst) in src_chunks.zip(&mut dst_chunks) {
+        let src_pixels = simd_utils::loadu_si128(src, 0);
+        let dst_pixels = multiply_alpha_two_pixels(src_pixels);
+        _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, dst_pixels);
+    }
+
+    if !src_remainder.is_empty() {
+        let dst_reminder = dst_chunks.into_remainder();
+        native::multiply_alpha_row(src_remainder, dst_reminder);
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn multiply_alpha_two_pixels(src_pixels: __m128i) -> __m128i {
+    let half = _mm_set1_epi32(0x8000);
+
+    let pix0 = _mm_cvtepu16_epi32(src_pixels);
+    let pix1 = _mm_cvtepu16_epi32(_mm_srli_si128::<8>(src_pixels));
+
+    let alpha0 = _mm_shuffle_epi32::<0b11_11_11_11>(pix0);
+    let alpha1 = _mm_shuffle_epi32::<0b11_11_11_11>(pix1);
+
+    let pix0 = _mm_add_epi32(_mm_mullo_epi32(pix0, alpha0), half);
+    let pix0 = _mm_srli_epi32::<16>(_mm_add_epi32(pix0, _mm_srli_epi32::<16>(pix0)));
+
+    let pix1 = _mm_add_epi32(_mm_mullo_epi32(pix1, alpha1), half);
+    let pix1 = _mm_srli_epi32::<16>(_mm_add_epi32(pix1, _mm_srli_epi32::<16>(pix1)));
+
+    let rgb = _mm_packus_epi32(pix0, pix1);
+    _mm_blend_epi16::<0b1000_1000>(rgb, src_pixels)
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn divide_alpha(
+    src_image: TypedImageView<U16x4>,
+    mut dst_image: TypedImageViewMut<U16x4>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn divide_alpha_inplace(mut image: TypedImageViewMut<U16x4>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len());
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn divide_alpha_row(src_row: &[U16x4], dst_row: &mut [U16x4]) {
+    let src_chunks = src_row.chunks_exact(2);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst_row.chunks_exact_mut(2);
+
+    for (src, dst) in src_chunks.zip(&mut dst_chunks) {
+        let src_pixels = simd_utils::loadu_si128(src, 0);
+        let dst_pixels = divide_alpha_two_pixels(src_pixels);
+        _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, dst_pixels);
+    }
+
+    if !src_remainder.is_empty() {
+        let dst_reminder = dst_chunks.into_remainder();
+        native::divide_alpha_row(src_remainder, dst_reminder);
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn divide_alpha_two_pixels(src_pixels: __m128i) -> __m128i {
+    let pix0 = _mm_cvtepi32_ps(_mm_cvtepu16_epi32(src_pixels));
+    let pix1 = _mm_cvtepi32_ps(_mm_cvtepu16_epi32(_mm_srli_si128::<8>(src_pixels)));
+
+    let pix0 = _mm_cvttps_epi32(divide_pixel_by_alpha(pix0));
+    let pix1 = _mm_cvttps_epi32(divide_pixel_by_alpha(pix1));
+
+    let rgb = _mm_packus_epi32(pix0, pix1);
+    _mm_blend_epi16::<0b1000_1000>(rgb, src_pixels)
+}
+
+/// Divides components of one pixel (stored as f32 values) by its alpha.
+/// The result is rounded and clipped, but isn't truncated to integers yet.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn divide_pixel_by_alpha(pix: __m128) -> __m128 {
+    let zero = _mm_setzero_ps();
+    let half = _mm_set1_ps(0.5);
+    let max_value = _mm_set1_ps(65535.0);
+
+    let alpha = _mm_shuffle_ps::<0b11_11_11_11>(pix, pix);
+    let recip_alpha = _mm_div_ps(max_value, alpha);
+    // Result of division by zero alpha must be zero.
+    let recip_alpha = _mm_andnot_ps(_mm_cmpeq_ps(alpha, zero), recip_alpha);
+
+    let pix = _mm_add_ps(_mm_mul_ps(pix, recip_alpha), half);
+    _mm_min_ps(pix, max_value)
+}
@@ -0,0 +1,136 @@
+use std::arch::x86_64::*;
+
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::U8x2;
+
+use super::sse4;
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn multiply_alpha(
+    src_image: TypedImageView<U8x2>,
+    mut dst_image: TypedImageViewMut<U8x2>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn multiply_alpha_inplace(mut image: TypedImageViewMut<U8x2>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len());
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn multiply_alpha_row(src_row: &[U8x2], dst_row: &mut [U8x2]) {
+    let zero = _mm256_setzero_si256();
+    let half = _mm256_set1_epi16(128);
+
+    const MAX_A: i16 = 0xff00u16 as i16;
+    let max_alpha = _mm256_set1_epi16(MAX_A);
+    #[rustfmt::skip]
+    let factor_mask = _mm256_set_epi8(
+        15, 15, 13, 13, 11, 11, 9, 9, 7, 7, 5, 5, 3, 3, 1, 1,
+        15, 15, 13, 13, 11, 11, 9, 9, 7, 7, 5, 5, 3, 3, 1, 1,
+    );
+
+    let src_chunks = src_row.chunks_exact(16);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst_row.chunks_exact_mut(16);
+
+    for (src, dst) in src_chunks.zip(&mut dst_chunks) {
+        let src_pixels = _mm256_loadu_si256(src.as_ptr() as *const __m256i);
+
+        let factor_pixels = _mm256_shuffle_epi8(src_pixels, factor_mask);
+        let factor_pixels = _mm256_or_si256(factor_pixels, max_alpha);
+
+        let pix1 = _mm256_unpacklo_epi8(src_pixels, zero);
+        let factors = _mm256_unpacklo_epi8(factor_pixels, zero);
+        let pix1 = _mm256_add_epi16(_mm256_mullo_epi16(pix1, factors), half);
+        let pix1 = _mm256_add_epi16(pix1, _mm256_srli_epi16::<8>(pix1));
+        let pix1 = _mm256_srli_epi16::<8>(pix1);
+
+        let pix2 = _mm256_unpackhi_epi8(src_pixels, zero);
+        let factors = _mm256_unpackhi_epi8(factor_pixels, zero);
+        let pix2 = _mm256_add_epi16(_mm256_mullo_epi16(pix2, factors), half);
+        let pix2 = _mm256_add_epi16(pix2, _mm256_srli_epi16::<8>(pix2));
+        let pix2 = _mm256_srli_epi16::<8>(pix2);
+
+        let dst_pixels = _mm256_packus_epi16(pix1, pix2);
+
+        _mm256_storeu_si256(dst.as_mut_ptr() as *mut __m256i, dst_pixels);
+    }
+
+    if !src_remainder.is_empty() {
+        let dst_reminder = dst_chunks.into_remainder();
+        sse4::multiply_alpha_row(src_remainder, dst_reminder);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn divide_alpha(
+    src_image: TypedImageView<U8x2>,
+    mut dst_image: TypedImageViewMut<U8x2>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn divide_alpha_inplace(mut image: TypedImageViewMut<U8x2>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len());
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn divide_alpha_row(src_row: &[U8x2], dst_row: &mut [U8x2]) {
+    let zero = _mm256_setzero_si256();
+    let alpha_mask = _mm256_set1_epi16(0xff00u16 as i16);
+    let alpha_scale = _mm256_set1_ps(255.0 * 256.0);
+
+    let src_chunks = src_row.chunks_exact(16);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst_row.chunks_exact_mut(16);
+
+    for (src, dst) in src_chunks.zip(&mut dst_chunks) {
+        let src_pixels = _mm256_loadu_si256(src.as_ptr() as *const __m256i);
+
+        let alpha_i16 = _mm256_srli_epi16::<8>(src_pixels);
+        let alpha_lo_f32 = _mm256_cvtepi32_ps(_mm256_unpacklo_epi16(alpha_i16, zero));
+        let alpha_hi_f32 = _mm256_cvtepi32_ps(_mm256_unpackhi_epi16(alpha_i16, zero));
+        let scaled_alpha_lo_i32 = _mm256_cvtps_epi32(_mm256_div_ps(alpha_scale, alpha_lo_f32));
+        let scaled_alpha_hi_i32 = _mm256_cvtps_epi32(_mm256_div_ps(alpha_scale, alpha_hi_f32));
+        let scaled_alpha_u16 = _mm256_packus_epi32(scaled_alpha_lo_i32, scaled_alpha_hi_i32);
+        let mma0 = _mm256_unpacklo_epi16(scaled_alpha_u16, scaled_alpha_u16);
+        let mma1 = _mm256_unpackhi_epi16(scaled_alpha_u16, scaled_alpha_u16);
+
+        let pix0 = _mm256_unpacklo_epi8(zero, src_pixels);
+        let pix1 = _mm256_unpackhi_epi8(zero, src_pixels);
+
+        let pix0 = _mm256_mulhi_epu16(pix0, mma0);
+        let pix1 = _mm256_mulhi_epu16(pix1, mma1);
+
+        let alpha = _mm256_and_si256(src_pixels, alpha_mask);
+        let luma = _mm256_packus_epi16(pix0, pix1);
+        let dst_pixels = _mm256_blendv_epi8(luma, alpha, alpha_mask);
+
+        _mm256_storeu_si256(dst.as_mut_ptr() as *mut __m256i, dst_pixels);
+    }
+
+    if !src_remainder.is_empty() {
+        let dst_reminder = dst_chunks.into_remainder();
+        sse4::divide_alpha_row(src_remainder, dst_reminder);
+    }
+}
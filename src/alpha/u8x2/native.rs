@@ -0,0 +1,69 @@
+use crate::alpha::common::{div_and_clip, mul_div_255, RECIP_ALPHA};
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::U8x2;
+
+// Multiplication
+
+#[inline]
+pub(crate) fn multiply_alpha(
+    src_image: TypedImageView<U8x2>,
+    mut dst_image: TypedImageViewMut<U8x2>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+pub(crate) fn multiply_alpha_inplace(mut image: TypedImageViewMut<U8x2>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = unsafe { std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len()) };
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline(always)]
+pub(crate) fn multiply_alpha_row(src_row: &[U8x2], dst_row: &mut [U8x2]) {
+    for (src_pixel, dst_pixel) in src_row.iter().zip(dst_row) {
+        let [luma, alpha] = src_pixel.0.to_le_bytes();
+        dst_pixel.0 = u16::from_le_bytes([mul_div_255(luma, alpha), alpha]);
+    }
+}
+
+// Division
+
+#[inline]
+pub(crate) fn divide_alpha(
+    src_image: TypedImageView<U8x2>,
+    mut dst_image: TypedImageViewMut<U8x2>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+pub(crate) fn divide_alpha_inplace(mut image: TypedImageViewMut<U8x2>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = unsafe { std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len()) };
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline(always)]
+pub(crate) fn divide_alpha_row(src_row: &[U8x2], dst_row: &mut [U8x2]) {
+    src_row
+        .iter()
+        .zip(dst_row)
+        .for_each(|(src_pixel, dst_pixel)| {
+            let [luma, alpha] = src_pixel.0.to_le_bytes();
+            let recip_alpha = RECIP_ALPHA[alpha as usize];
+            dst_pixel.0 = u16::from_le_bytes([div_and_clip(luma, recip_alpha), alpha]);
+        });
+}
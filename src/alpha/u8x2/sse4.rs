@@ -0,0 +1,133 @@
+use std::arch::x86_64::*;
+
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::U8x2;
+
+use super::native;
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn multiply_alpha(
+    src_image: TypedImageView<U8x2>,
+    mut dst_image: TypedImageViewMut<U8x2>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn multiply_alpha_inplace(mut image: TypedImageViewMut<U8x2>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len());
+        multiply_alpha_row(src_row, dst_row);
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn multiply_alpha_row(src_row: &[U8x2], dst_row: &mut [U8x2]) {
+    let zero = _mm_setzero_si128();
+    let half = _mm_set1_epi16(128);
+
+    const MAX_A: i16 = 0xff00u16 as i16;
+    let max_alpha = _mm_set1_epi16(MAX_A);
+    let factor_mask = _mm_set_epi8(15, 15, 13, 13, 11, 11, 9, 9, 7, 7, 5, 5, 3, 3, 1, 1);
+
+    let src_chunks = src_row.chunks_exact(8);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst_row.chunks_exact_mut(8);
+
+    for (src, dst) in src_chunks.zip(&mut dst_chunks) {
+        let src_pixels = _mm_loadu_si128(src.as_ptr() as *const __m128i);
+
+        let factor_pixels = _mm_shuffle_epi8(src_pixels, factor_mask);
+        let factor_pixels = _mm_or_si128(factor_pixels, max_alpha);
+
+        let pix1 = _mm_unpacklo_epi8(src_pixels, zero);
+        let factors = _mm_unpacklo_epi8(factor_pixels, zero);
+        let pix1 = _mm_add_epi16(_mm_mullo_epi16(pix1, factors), half);
+        let pix1 = _mm_add_epi16(pix1, _mm_srli_epi16::<8>(pix1));
+        let pix1 = _mm_srli_epi16::<8>(pix1);
+
+        let pix2 = _mm_unpackhi_epi8(src_pixels, zero);
+        let factors = _mm_unpackhi_epi8(factor_pixels, zero);
+        let pix2 = _mm_add_epi16(_mm_mullo_epi16(pix2, factors), half);
+        let pix2 = _mm_add_epi16(pix2, _mm_srli_epi16::<8>(pix2));
+        let pix2 = _mm_srli_epi16::<8>(pix2);
+
+        let dst_pixels = _mm_packus_epi16(pix1, pix2);
+
+        _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, dst_pixels);
+    }
+
+    if !src_remainder.is_empty() {
+        let dst_reminder = dst_chunks.into_remainder();
+        native::multiply_alpha_row(src_remainder, dst_reminder);
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn divide_alpha(
+    src_image: TypedImageView<U8x2>,
+    mut dst_image: TypedImageViewMut<U8x2>,
+) {
+    let src_rows = src_image.iter_rows(0);
+    let dst_rows = dst_image.iter_rows_mut();
+
+    for (src_row, dst_row) in src_rows.zip(dst_rows) {
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn divide_alpha_inplace(mut image: TypedImageViewMut<U8x2>) {
+    for dst_row in image.iter_rows_mut() {
+        let src_row = std::slice::from_raw_parts(dst_row.as_ptr(), dst_row.len());
+        divide_alpha_row(src_row, dst_row);
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn divide_alpha_row(src_row: &[U8x2], dst_row: &mut [U8x2]) {
+    let zero = _mm_setzero_si128();
+    let alpha_mask = _mm_set1_epi16(0xff00u16 as i16);
+    let alpha_scale = _mm_set1_ps(255.0 * 256.0);
+
+    let src_chunks = src_row.chunks_exact(8);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst_row.chunks_exact_mut(8);
+
+    for (src, dst) in src_chunks.zip(&mut dst_chunks) {
+        let src_pixels = _mm_loadu_si128(src.as_ptr() as *const __m128i);
+
+        let alpha_i16 = _mm_srli_epi16::<8>(src_pixels);
+        let alpha_lo_f32 = _mm_cvtepi32_ps(_mm_unpacklo_epi16(alpha_i16, zero));
+        let alpha_hi_f32 = _mm_cvtepi32_ps(_mm_unpackhi_epi16(alpha_i16, zero));
+        let scaled_alpha_lo_i32 = _mm_cvtps_epi32(_mm_div_ps(alpha_scale, alpha_lo_f32));
+        let scaled_alpha_hi_i32 = _mm_cvtps_epi32(_mm_div_ps(alpha_scale, alpha_hi_f32));
+        // Division by zero gives i32::MIN, packing with unsigned saturation turns it into 0.
+        let scaled_alpha_u16 = _mm_packus_epi32(scaled_alpha_lo_i32, scaled_alpha_hi_i32);
+        let mma0 = _mm_unpacklo_epi16(scaled_alpha_u16, scaled_alpha_u16);
+        let mma1 = _mm_unpackhi_epi16(scaled_alpha_u16, scaled_alpha_u16);
+
+        let pix0 = _mm_unpacklo_epi8(zero, src_pixels);
+        let pix1 = _mm_unpackhi_epi8(zero, src_pixels);
+
+        let pix0 = _mm_mulhi_epu16(pix0, mma0);
+        let pix1 = _mm_mulhi_epu16(pix1, mma1);
+
+        let alpha = _mm_and_si128(src_pixels, alpha_mask);
+        let luma = _mm_packus_epi16(pix0, pix1);
+        let dst_pixels = _mm_blendv_epi8(luma, alpha, alpha_mask);
+
+        _mm_storeu_si128(dst.as_mut_ptr() as *mut __m128i, dst_pixels);
+    }
+
+    if !src_remainder.is_empty() {
+        let dst_reminder = dst_chunks.into_remainder();
+        native::divide_alpha_row(src_remainder, dst_reminder);
+    }
+}
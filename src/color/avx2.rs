@@ -0,0 +1,85 @@
+use std::arch::x86_64::*;
+
+use super::native;
+
+/// Type of source component that may be used as index of lookup table.
+pub(crate) trait TableIndex: Copy + Into<usize> {
+    /// Loads 8 components and converts them into `i32` indexes.
+    unsafe fn load_8_indexes(src: &[Self]) -> __m256i;
+}
+
+impl TableIndex for u8 {
+    #[inline(always)]
+    unsafe fn load_8_indexes(src: &[Self]) -> __m256i {
+        _mm256_cvtepu8_epi32(_mm_loadl_epi64(src.as_ptr() as *const __m128i))
+    }
+}
+
+impl TableIndex for u16 {
+    #[inline(always)]
+    unsafe fn load_8_indexes(src: &[Self]) -> __m256i {
+        _mm256_cvtepu16_epi32(_mm_loadu_si128(src.as_ptr() as *const __m128i))
+    }
+}
+
+/// Type of values of lookup table.
+pub(crate) trait TableValue: Copy {
+    /// Gathers 8 values from the table and stores them into destination.
+    ///
+    /// Table must contain `(4 - size_of::<Self>()) / size_of::<Self>()`
+    /// extra items after last value which index may be used.
+    unsafe fn gather_8_values(table: &[Self], indexes: __m256i, dst: &mut [Self]);
+}
+
+impl TableValue for u8 {
+    #[inline(always)]
+    unsafe fn gather_8_values(table: &[Self], indexes: __m256i, dst: &mut [Self]) {
+        let values = _mm256_i32gather_epi32::<1>(table.as_ptr() as *const i32, indexes);
+        let values = _mm256_and_si256(values, _mm256_set1_epi32(0xff));
+        let values = _mm256_packus_epi32(values, values);
+        let values = _mm256_packus_epi16(values, values);
+        let lo = _mm_cvtsi128_si32(_mm256_castsi256_si128(values)) as u32;
+        let hi = _mm_cvtsi128_si32(_mm256_extracti128_si256::<1>(values)) as u32;
+        let res = (lo as u64) | ((hi as u64) << 32);
+        (dst.as_mut_ptr() as *mut u64).write_unaligned(res);
+    }
+}
+
+impl TableValue for u16 {
+    #[inline(always)]
+    unsafe fn gather_8_values(table: &[Self], indexes: __m256i, dst: &mut [Self]) {
+        let values = _mm256_i32gather_epi32::<2>(table.as_ptr() as *const i32, indexes);
+        let values = _mm256_and_si256(values, _mm256_set1_epi32(0xffff));
+        let values = _mm256_packus_epi32(values, values);
+        const MASK: i32 = 0b10_00_10_00;
+        let values = _mm256_permute4x64_epi64::<MASK>(values);
+        _mm_storeu_si128(
+            dst.as_mut_ptr() as *mut __m128i,
+            _mm256_castsi256_si128(values),
+        );
+    }
+}
+
+impl TableValue for f32 {
+    #[inline(always)]
+    unsafe fn gather_8_values(table: &[Self], indexes: __m256i, dst: &mut [Self]) {
+        let values = _mm256_i32gather_ps::<4>(table.as_ptr(), indexes);
+        _mm256_storeu_ps(dst.as_mut_ptr(), values);
+    }
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn map_with_table<S, D>(table: &[D], src: &[S], dst: &mut [D])
+where
+    S: TableIndex,
+    D: TableValue,
+{
+    let src_chunks = src.chunks_exact(8);
+    let src_remainder = src_chunks.remainder();
+    let mut dst_chunks = dst.chunks_exact_mut(8);
+    for (src_chunk, dst_chunk) in src_chunks.zip(&mut dst_chunks) {
+        let indexes = S::load_8_indexes(src_chunk);
+        D::gather_8_values(table, indexes, dst_chunk);
+    }
+    native::map_with_table(table, src_remainder, dst_chunks.into_remainder());
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum MappingError {
+    #[error("Size of source image does not match to destination image")]
+    SizeIsDifferent,
+    #[error("Mapping from pixel type of source image into pixel type of destination image is not supported")]
+    UnsupportedCombinationOfImageTypes,
+}
@@ -0,0 +1,51 @@
+//! Functions to create mappers of the most popular color spaces.
+use std::sync::OnceLock;
+
+use super::ColorMapper;
+
+/// Converts sRGB-encoded value into linear value.
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts linear value into sRGB-encoded value.
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1. / 2.4) - 0.055
+    }
+}
+
+/// Creates mapper from sRGB color space into linear RGB (forward)
+/// and back (backward).
+pub fn create_srgb_mapper() -> ColorMapper {
+    ColorMapper::new(srgb_to_linear, linear_to_srgb)
+}
+
+/// Returns shared instance of the sRGB mapper, so lookup tables are
+/// computed only once. It is used by resizing in linear color space.
+pub(crate) fn srgb_mapper() -> &'static ColorMapper {
+    static MAPPER: OnceLock<ColorMapper> = OnceLock::new();
+    MAPPER.get_or_init(create_srgb_mapper)
+}
+
+/// Creates mapper from color space with given gamma into linear color space
+/// (forward) and back (backward).
+pub fn create_gamma_mapper(gamma: f32) -> ColorMapper {
+    let inv_gamma = 1. / gamma;
+    ColorMapper::new(
+        move |value: f32| value.powf(gamma),
+        move |value: f32| value.powf(inv_gamma),
+    )
+}
+
+/// Creates mapper from color space with gamma 2.2 into linear color space
+/// (forward) and back (backward).
+pub fn create_gamma_22_mapper() -> ColorMapper {
+    create_gamma_mapper(2.2)
+}
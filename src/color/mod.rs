@@ -0,0 +1,391 @@
+//! Functions and structs for mapping colors of images between color spaces.
+use std::fmt;
+use std::sync::Arc;
+
+pub use errors::*;
+
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::{Pixel, PixelType};
+use crate::CpuExtensions;
+use crate::{ImageView, ImageViewMut};
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+mod errors;
+pub mod mappers;
+mod native;
+
+/// Value of pixel component that may be converted
+/// into `f32` value in range `[0.0, 1.0]` and back.
+pub(crate) trait UnitValue: Copy {
+    fn to_unit(self) -> f32;
+
+    fn from_unit(value: f32) -> Self;
+}
+
+impl UnitValue for u8 {
+    #[inline(always)]
+    fn to_unit(self) -> f32 {
+        self as f32 / 255.
+    }
+
+    #[inline(always)]
+    fn from_unit(value: f32) -> Self {
+        (value.clamp(0., 1.) * 255.).round() as u8
+    }
+}
+
+impl UnitValue for u16 {
+    #[inline(always)]
+    fn to_unit(self) -> f32 {
+        self as f32 / 65535.
+    }
+
+    #[inline(always)]
+    fn from_unit(value: f32) -> Self {
+        (value.clamp(0., 1.) * 65535.).round() as u16
+    }
+}
+
+impl UnitValue for f32 {
+    #[inline(always)]
+    fn to_unit(self) -> f32 {
+        self
+    }
+
+    #[inline(always)]
+    fn from_unit(value: f32) -> Self {
+        value
+    }
+}
+
+type MapFunc = Arc<dyn Fn(f32) -> f32 + Send + Sync>;
+
+/// Lookup tables with precomputed results of mapping function.
+///
+/// Tables used by AVX2-version of mapping contain some extra items
+/// at the end, because `gather` instructions read 4 bytes
+/// for every value.
+pub(crate) struct MappingTables {
+    func: MapFunc,
+    u8_to_u8: Vec<u8>,
+    u8_to_u16: Vec<u16>,
+    u8_to_f32: Vec<f32>,
+    u16_to_u8: Vec<u8>,
+    u16_to_u16: Vec<u16>,
+    u16_to_f32: Vec<f32>,
+}
+
+impl MappingTables {
+    fn new(func: MapFunc) -> Self {
+        let u8_values: Vec<f32> = (0..=u8::MAX).map(|v| func(v.to_unit())).collect();
+        let u16_values: Vec<f32> = (0..=u16::MAX).map(|v| func(v.to_unit())).collect();
+        Self {
+            u8_to_u8: table(&u8_values, 3),
+            u8_to_u16: table(&u8_values, 1),
+            u8_to_f32: table(&u8_values, 0),
+            u16_to_u8: table(&u16_values, 3),
+            u16_to_u16: table(&u16_values, 1),
+            u16_to_f32: table(&u16_values, 0),
+            func,
+        }
+    }
+}
+
+/// Creates lookup table from given values and adds `gap` extra items at the end.
+fn table<T: UnitValue>(values: &[f32], gap: usize) -> Vec<T> {
+    let mut table: Vec<T> = values.iter().map(|&v| T::from_unit(v)).collect();
+    table.resize(table.len() + gap, T::from_unit(0.));
+    table
+}
+
+/// Maps slice of source components into slice of destination components.
+pub(crate) trait MapComponents<S, D> {
+    fn map_components(&self, src: &[S], dst: &mut [D], cpu_extensions: CpuExtensions);
+}
+
+macro_rules! map_with_table {
+    ($src_type:ty, $dst_type:ty, $table:ident) => {
+        impl MapComponents<$src_type, $dst_type> for MappingTables {
+            #[inline]
+            fn map_components(
+                &self,
+                src: &[$src_type],
+                dst: &mut [$dst_type],
+                cpu_extensions: CpuExtensions,
+            ) {
+                match cpu_extensions {
+                    #[cfg(target_arch = "x86_64")]
+                    CpuExtensions::Avx2 => unsafe { avx2::map_with_table(&self.$table, src, dst) },
+                    // SSE4.1 has no gather instructions to read lookup tables.
+                    #[cfg(target_arch = "x86_64")]
+                    CpuExtensions::Sse4_1 => native::map_with_table(&self.$table, src, dst),
+                    CpuExtensions::None => native::map_with_table(&self.$table, src, dst),
+                }
+            }
+        }
+    };
+}
+
+map_with_table!(u8, u8, u8_to_u8);
+map_with_table!(u8, u16, u8_to_u16);
+map_with_table!(u8, f32, u8_to_f32);
+map_with_table!(u16, u8, u16_to_u8);
+map_with_table!(u16, u16, u16_to_u16);
+map_with_table!(u16, f32, u16_to_f32);
+
+impl<D: UnitValue> MapComponents<f32, D> for MappingTables {
+    #[inline]
+    fn map_components(&self, src: &[f32], dst: &mut [D], _cpu_extensions: CpuExtensions) {
+        native::map_with_func(self.func.as_ref(), src, dst);
+    }
+}
+
+/// Methods of this structure used to map colors of images
+/// from one color space into another one (e.g. from sRGB into linear RGB).
+///
+/// Mapper is created from two functions: the forward function and
+/// the backward function. Both functions receive and return values
+/// in range `[0.0, 1.0]`. Results of the functions for all possible
+/// values of `u8` and `u16` components are precomputed in lookup tables
+/// while creating of mapper, so it is better to create mapper once
+/// and reuse it.
+///
+/// Source and destination images must have the same size and
+/// the same count of components per pixel, but may have different
+/// types of components. For example, `U8x3` image may be mapped into
+/// `U16x3` image. The alpha-channel of `U8x2`, `U8x4`, `U16x4` and
+/// `F32x4` images is not mapped, it is only converted into type of
+/// components of destination image.
+///
+/// By default, instance of `ColorMapper` created with best CPU-extensions
+/// provided by your CPU. You can change this by use method
+/// [ColorMapper::set_cpu_extensions].
+///
+/// Lookup tables are read with help of ``AVX2`` gather instructions.
+/// ``SSE4.1`` has no such instructions, so native Rust-code is used
+/// with `CpuExtensions::Sse4_1`. Images with `f32` source components
+/// are always mapped by native Rust-code with help of the functions.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use fast_image_resize::color::mappers::create_srgb_mapper;
+/// use fast_image_resize::pixels::PixelType;
+/// use fast_image_resize::Image;
+///
+/// let width = NonZeroU32::new(10).unwrap();
+/// let height = NonZeroU32::new(7).unwrap();
+/// let src_image = Image::new(width, height, PixelType::U8x3);
+/// let mut dst_image = Image::new(width, height, PixelType::U16x3);
+///
+/// let srgb_mapper = create_srgb_mapper();
+/// srgb_mapper.forward_map(&src_image.view(), &mut dst_image.view_mut()).unwrap();
+/// // ... resize linear image ...
+/// let mut srgb_image = Image::new(width, height, PixelType::U8x3);
+/// srgb_mapper.backward_map(&dst_image.view(), &mut srgb_image.view_mut()).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct ColorMapper {
+    forward: Arc<MappingTables>,
+    backward: Arc<MappingTables>,
+    cpu_extensions: CpuExtensions,
+}
+
+impl fmt::Debug for ColorMapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColorMapper")
+            .field("cpu_extensions", &self.cpu_extensions)
+            .finish()
+    }
+}
+
+impl ColorMapper {
+    /// Creates instance of `ColorMapper` from forward and backward functions.
+    pub fn new<F, B>(forward_map_func: F, backward_map_func: B) -> Self
+    where
+        F: Fn(f32) -> f32 + Send + Sync + 'static,
+        B: Fn(f32) -> f32 + Send + Sync + 'static,
+    {
+        Self {
+            forward: Arc::new(MappingTables::new(Arc::new(forward_map_func))),
+            backward: Arc::new(MappingTables::new(Arc::new(backward_map_func))),
+            cpu_extensions: Default::default(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn cpu_extensions(&self) -> CpuExtensions {
+        self.cpu_extensions
+    }
+
+    /// Only `CpuExtensions::Avx2` changes the way of mapping, other values
+    /// use native Rust-code. Results of mapping are identical for all
+    /// CPU-extensions.
+    ///
+    /// # Safety
+    /// This is unsafe because this method allows you to set a CPU-extensions
+    /// that is not actually supported by your CPU.
+    pub unsafe fn set_cpu_extensions(&mut self, extensions: CpuExtensions) {
+        self.cpu_extensions = extensions;
+    }
+
+    /// Maps colors of source image into destination image
+    /// with help of forward function.
+    pub fn forward_map(
+        &self,
+        src_image: &ImageView,
+        dst_image: &mut ImageViewMut,
+    ) -> Result<(), MappingError> {
+        map_image(&self.forward, src_image, dst_image, self.cpu_extensions)
+    }
+
+    /// Maps colors of source image into destination image
+    /// with help of backward function.
+    pub fn backward_map(
+        &self,
+        src_image: &ImageView,
+        dst_image: &mut ImageViewMut,
+    ) -> Result<(), MappingError> {
+        map_image(&self.backward, src_image, dst_image, self.cpu_extensions)
+    }
+
+    /// Maps colors of typed source image into typed destination image
+    /// of the same size with help of forward function.
+    pub(crate) fn forward_map_typed<S, D>(
+        &self,
+        src_image: TypedImageView<S>,
+        dst_image: TypedImageViewMut<D>,
+        has_alpha: bool,
+        cpu_extensions: CpuExtensions,
+    ) where
+        S: Pixel,
+        D: Pixel,
+        S::Component: UnitValue,
+        D::Component: UnitValue,
+        MappingTables: MapComponents<S::Component, D::Component>,
+    {
+        map_typed_image(
+            &self.forward,
+            src_image,
+            dst_image,
+            has_alpha,
+            cpu_extensions,
+        );
+    }
+
+    /// Maps colors of typed source image into typed destination image
+    /// of the same size with help of backward function.
+    pub(crate) fn backward_map_typed<S, D>(
+        &self,
+        src_image: TypedImageView<S>,
+        dst_image: TypedImageViewMut<D>,
+        has_alpha: bool,
+        cpu_extensions: CpuExtensions,
+    ) where
+        S: Pixel,
+        D: Pixel,
+        S::Component: UnitValue,
+        D::Component: UnitValue,
+        MappingTables: MapComponents<S::Component, D::Component>,
+    {
+        map_typed_image(
+            &self.backward,
+            src_image,
+            dst_image,
+            has_alpha,
+            cpu_extensions,
+        );
+    }
+}
+
+fn map_image(
+    tables: &MappingTables,
+    src_image: &ImageView,
+    dst_image: &mut ImageViewMut,
+    cpu_extensions: CpuExtensions,
+) -> Result<(), MappingError> {
+    if src_image.width() != dst_image.width() || src_image.height() != dst_image.height() {
+        return Err(MappingError::SizeIsDifferent);
+    }
+
+    macro_rules! map {
+        ($src_image:ident, $dst_image:ident, $has_alpha:expr) => {
+            if let Some(src) = src_image.$src_image() {
+                if let Some(dst) = dst_image.$dst_image() {
+                    map_typed_image(tables, src, dst, $has_alpha, cpu_extensions);
+                }
+            }
+        };
+    }
+
+    use PixelType as PT;
+    match (src_image.pixel_type(), dst_image.pixel_type()) {
+        (PT::U8, PT::U8) => map!(u8_image, u8_image, false),
+        (PT::U8, PT::U16) => map!(u8_image, u16_image, false),
+        (PT::U8, PT::F32) => map!(u8_image, f32_image, false),
+        (PT::U16, PT::U8) => map!(u16_image, u8_image, false),
+        (PT::U16, PT::U16) => map!(u16_image, u16_image, false),
+        (PT::U16, PT::F32) => map!(u16_image, f32_image, false),
+        (PT::F32, PT::U8) => map!(f32_image, u8_image, false),
+        (PT::F32, PT::U16) => map!(f32_image, u16_image, false),
+        (PT::F32, PT::F32) => map!(f32_image, f32_image, false),
+
+        (PT::U8x2, PT::U8x2) => map!(u8x2_image, u8x2_image, true),
+
+        (PT::U8x3, PT::U8x3) => map!(u8x3_image, u8x3_image, false),
+        (PT::U8x3, PT::U16x3) => map!(u8x3_image, u16x3_image, false),
+        (PT::U8x3, PT::F32x3) => map!(u8x3_image, f32x3_image, false),
+        (PT::U16x3, PT::U8x3) => map!(u16x3_image, u8x3_image, false),
+        (PT::U16x3, PT::U16x3) => map!(u16x3_image, u16x3_image, false),
+        (PT::U16x3, PT::F32x3) => map!(u16x3_image, f32x3_image, false),
+        (PT::F32x3, PT::U8x3) => map!(f32x3_image, u8x3_image, false),
+        (PT::F32x3, PT::U16x3) => map!(f32x3_image, u16x3_image, false),
+        (PT::F32x3, PT::F32x3) => map!(f32x3_image, f32x3_image, false),
+
+        (PT::U8x4, PT::U8x4) => map!(u8x4_image, u8x4_image, true),
+        (PT::U8x4, PT::U16x4) => map!(u8x4_image, u16x4_image, true),
+        (PT::U8x4, PT::F32x4) => map!(u8x4_image, f32x4_image, true),
+        (PT::U16x4, PT::U8x4) => map!(u16x4_image, u8x4_image, true),
+        (PT::U16x4, PT::U16x4) => map!(u16x4_image, u16x4_image, true),
+        (PT::U16x4, PT::F32x4) => map!(u16x4_image, f32x4_image, true),
+        (PT::F32x4, PT::U8x4) => map!(f32x4_image, u8x4_image, true),
+        (PT::F32x4, PT::U16x4) => map!(f32x4_image, u16x4_image, true),
+        (PT::F32x4, PT::F32x4) => map!(f32x4_image, f32x4_image, true),
+
+        _ => return Err(MappingError::UnsupportedCombinationOfImageTypes),
+    }
+    Ok(())
+}
+
+fn map_typed_image<S, D>(
+    tables: &MappingTables,
+    src_image: TypedImageView<S>,
+    mut dst_image: TypedImageViewMut<D>,
+    has_alpha: bool,
+    cpu_extensions: CpuExtensions,
+) where
+    S: Pixel,
+    D: Pixel,
+    S::Component: UnitValue,
+    D::Component: UnitValue,
+    MappingTables: MapComponents<S::Component, D::Component>,
+{
+    let components_count = S::components_count();
+    for (src_row, dst_row) in src_image.iter_rows(0).zip(dst_image.iter_rows_mut()) {
+        let src_components = S::components(src_row);
+        let dst_components = D::components_mut(dst_row);
+        tables.map_components(src_components, dst_components, cpu_extensions);
+        if has_alpha {
+            let src_alpha = src_components.iter().skip(components_count - 1);
+            let dst_alpha = dst_components.iter_mut().skip(components_count - 1);
+            for (&s, d) in src_alpha
+                .step_by(components_count)
+                .zip(dst_alpha.step_by(components_count))
+            {
+                *d = UnitValue::from_unit(s.to_unit());
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use super::UnitValue;
+
+#[inline(always)]
+pub(crate) fn map_with_table<S, D>(table: &[D], src: &[S], dst: &mut [D])
+where
+    S: Copy + Into<usize>,
+    D: Copy,
+{
+    for (&s, d) in src.iter().zip(dst) {
+        // Tables contain values for all possible values of source components.
+        *d = unsafe { *table.get_unchecked(s.into()) };
+    }
+}
+
+#[inline(always)]
+pub(crate) fn map_with_func<D: UnitValue>(func: &dyn Fn(f32) -> f32, src: &[f32], dst: &mut [D]) {
+    for (&s, d) in src.iter().zip(dst) {
+        *d = D::from_unit(func(s));
+    }
+}
@@ -0,0 +1,85 @@
+use std::arch::x86_64::*;
+
+use crate::convolution::{Coefficients, CoefficientsChunk};
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::F32;
+
+#[inline]
+pub(crate) fn vert_convolution(
+    src_image: TypedImageView<F32>,
+    mut dst_image: TypedImageViewMut<F32>,
+    coeffs: &Coefficients,
+) {
+    let coefficients_chunks = coeffs.get_chunks();
+    let dst_rows = dst_image.iter_rows_mut();
+    for (&coeffs_chunk, dst_row) in coefficients_chunks.iter().zip(dst_rows) {
+        unsafe {
+            vert_convolution_into_one_row(&src_image, dst_row, coeffs_chunk);
+        }
+    }
+}
+
+/// Loads four pixels starting from `x` and converts them into `f64`.
+#[inline(always)]
+unsafe fn load_pixels_pd(row: &[F32], x: usize) -> __m256d {
+    _mm256_cvtps_pd(_mm_loadu_ps(row.get_unchecked(x..).as_ptr() as *const f32))
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - length of all rows in src_image must be greater or equal to dst_row.len()
+/// - coeffs_chunk.start + coeffs_chunk.values.len() <= src_image.height()
+#[target_feature(enable = "avx2")]
+unsafe fn vert_convolution_into_one_row(
+    src_image: &TypedImageView<F32>,
+    dst_row: &mut [F32],
+    coeffs_chunk: CoefficientsChunk,
+) {
+    let first_y_src = coeffs_chunk.start;
+    let width = dst_row.len();
+    let mut buf = [0f64; 4];
+    let mut x_src: usize = 0;
+
+    while x_src + 8 <= width {
+        let mut sums = [_mm256_setzero_pd(); 2];
+        let src_rows = src_image.iter_rows(first_y_src);
+        for (src_row, &k) in src_rows.zip(coeffs_chunk.values) {
+            let coeff_f64x4 = _mm256_set1_pd(k);
+            for (i, sum) in sums.iter_mut().enumerate() {
+                let pixels_f64x4 = load_pixels_pd(src_row, x_src + i * 4);
+                *sum = _mm256_add_pd(*sum, _mm256_mul_pd(pixels_f64x4, coeff_f64x4));
+            }
+        }
+        for sum in sums {
+            _mm256_storeu_pd(buf.as_mut_ptr(), sum);
+            for ss in buf {
+                dst_row.get_unchecked_mut(x_src).0 = ss.round() as f32;
+                x_src += 1;
+            }
+        }
+    }
+
+    while x_src + 4 <= width {
+        let mut sum = _mm256_setzero_pd();
+        let src_rows = src_image.iter_rows(first_y_src);
+        for (src_row, &k) in src_rows.zip(coeffs_chunk.values) {
+            let coeff_f64x4 = _mm256_set1_pd(k);
+            let pixels_f64x4 = load_pixels_pd(src_row, x_src);
+            sum = _mm256_add_pd(sum, _mm256_mul_pd(pixels_f64x4, coeff_f64x4));
+        }
+        _mm256_storeu_pd(buf.as_mut_ptr(), sum);
+        for ss in buf {
+            dst_row.get_unchecked_mut(x_src).0 = ss.round() as f32;
+            x_src += 1;
+        }
+    }
+
+    for (x_src, dst_pixel) in dst_row.iter_mut().enumerate().skip(x_src) {
+        let mut ss = 0.;
+        let src_rows = src_image.iter_rows(first_y_src);
+        for (src_row, &k) in src_rows.zip(coeffs_chunk.values) {
+            let src_pixel = src_row.get_unchecked(x_src);
+            ss += src_pixel.0 as f64 * k;
+        }
+        dst_pixel.0 = ss.round() as f32;
+    }
+}
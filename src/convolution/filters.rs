@@ -1,8 +1,44 @@
 use std::f64::consts::PI;
+use std::fmt;
 
-pub type FilterFn<'a> = &'a dyn Fn(f64) -> f64;
+pub type FilterFn = Box<dyn Fn(f64) -> f64>;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+/// User-defined filter that may be used by convolution
+/// with help of [FilterType::Custom].
+///
+/// # Examples
+///
+/// ```
+/// use fast_image_resize::{Filter, FilterType, ResizeAlg, Resizer};
+///
+/// struct Triangle;
+///
+/// impl Filter for Triangle {
+///     fn value(&self, x: f64) -> f64 {
+///         (1. - x.abs()).max(0.)
+///     }
+///
+///     fn support(&self) -> f64 {
+///         1.
+///     }
+/// }
+///
+/// let filter = FilterType::Custom(&Triangle);
+/// let resizer = Resizer::new(ResizeAlg::Convolution(filter));
+/// ```
+pub trait Filter: Send + Sync {
+    /// Returns value of the filter kernel in the point `x`.
+    fn value(&self, x: f64) -> f64;
+
+    /// Returns radius of the filter kernel. Value of the kernel must be
+    /// equal to zero outside of `[-support, support]` range.
+    /// Support must be a positive number. The radius of the filter
+    /// (support multiplied by the scale and the blur factor) is clamped
+    /// by the doubled size of the source image.
+    fn support(&self) -> f64;
+}
+
+#[derive(Clone, Copy, Default)]
 #[non_exhaustive]
 pub enum FilterType {
     /// Each pixel of source image contributes to one pixel of the
@@ -29,25 +65,114 @@ pub enum FilterType {
     /// Lanczos3 filter calculate the output pixel value using a high-quality
     /// Lanczos filter (a truncated sinc) on all pixels that may contribute
     /// to the output value.
+    #[default]
     Lanczos3,
+    /// Lanczos filter with 2 lobes. Produces less ringing than `Lanczos3`,
+    /// but the result is a little softer.
+    Lanczos2,
+    /// Lanczos filter with 4 lobes. Sharper than `Lanczos3`,
+    /// but produces more ringing.
+    Lanczos4,
+    /// Gaussian filter with given standard deviation (sigma).
+    /// Support of the filter is three sigmas. The radius of the filter is
+    /// clamped by the doubled size of the source image, so huge values of
+    /// sigma give an almost flat filter.
+    /// Sigma equal to 0.5 gives a result comparable with ImageMagick.
+    /// Non-positive and non-finite values of sigma are replaced by 0.5.
+    Gaussian(f64),
+    /// Hermite filter is a cubic filter with B = 0 and C = 0.
+    /// It is smooth like `Bilinear`, but has no sharp corners.
+    Hermite,
+    /// Cubic B-spline filter (B = 1, C = 0). Produces very smooth
+    /// result without ringing.
+    BSpline,
+    /// Sinc filter with 3 lobes windowed by the Blackman window.
+    Blackman,
+    /// Sinc filter with 3 lobes windowed by the Kaiser window (beta = 6.5).
+    Kaiser,
+    /// Cubic filter from the Mitchell–Netravali family with
+    /// arbitrary parameters `B` and `C`.
+    ///
+    /// Some well-known pairs of parameters:
+    /// - `B = 1/3, C = 1/3` - `Mitchell`;
+    /// - `B = 0, C = 0.5` - `CatmullRom`;
+    /// - `B = 0, C = 0.75` - bicubic filter of OpenCV (`A = -0.75`);
+    /// - `B = 0, C = 0` - `Hermite`;
+    /// - `B = 1, C = 0` - `BSpline`.
+    Cubic { b: f64, c: f64 },
+    /// User-defined filter. Filters that aren't known at compile time
+    /// may be created with help of `Box::leak()`.
+    Custom(&'static dyn Filter),
+}
+
+impl fmt::Debug for FilterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Box => write!(f, "Box"),
+            Self::Bilinear => write!(f, "Bilinear"),
+            Self::Hamming => write!(f, "Hamming"),
+            Self::CatmullRom => write!(f, "CatmullRom"),
+            Self::Mitchell => write!(f, "Mitchell"),
+            Self::Lanczos3 => write!(f, "Lanczos3"),
+            Self::Lanczos2 => write!(f, "Lanczos2"),
+            Self::Lanczos4 => write!(f, "Lanczos4"),
+            Self::Gaussian(sigma) => f.debug_tuple("Gaussian").field(sigma).finish(),
+            Self::Hermite => write!(f, "Hermite"),
+            Self::BSpline => write!(f, "BSpline"),
+            Self::Blackman => write!(f, "Blackman"),
+            Self::Kaiser => write!(f, "Kaiser"),
+            Self::Cubic { b, c } => f.debug_struct("Cubic").field("b", b).field("c", c).finish(),
+            Self::Custom(filter) => f
+                .debug_struct("Custom")
+                .field("support", &filter.support())
+                .finish(),
+        }
+    }
 }
 
-impl Default for FilterType {
-    fn default() -> Self {
-        FilterType::Lanczos3
+/// Custom filters are compared by their addresses (and types), not by
+/// their values: filters are equal only if they are the same instance.
+impl PartialEq for FilterType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Gaussian(a), Self::Gaussian(b)) => a == b,
+            (Self::Cubic { b: b1, c: c1 }, Self::Cubic { b: b2, c: c2 }) => b1 == b2 && c1 == c2,
+            (Self::Custom(a), Self::Custom(b)) => std::ptr::eq(*a, *b),
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
     }
 }
 
-/// Returns reference to filter function and value of `filter_support`.
+/// Returns filter function and value of `filter_support`.
 #[inline]
-pub fn get_filter_func(filter_type: FilterType) -> (FilterFn<'static>, f64) {
-    match filter_type {
-        FilterType::Box => (&box_filter, 0.5),
-        FilterType::Bilinear => (&bilinear_filter, 1.0),
-        FilterType::Hamming => (&hamming_filter, 1.0),
-        FilterType::CatmullRom => (&catmul_filter, 2.0),
-        FilterType::Mitchell => (&mitchell_filter, 2.0),
-        FilterType::Lanczos3 => (&lanczos_filter, 3.0),
+pub fn get_filter_func(filter_type: &FilterType) -> (FilterFn, f64) {
+    match *filter_type {
+        FilterType::Box => (Box::new(box_filter), 0.5),
+        FilterType::Bilinear => (Box::new(bilinear_filter), 1.0),
+        FilterType::Hamming => (Box::new(hamming_filter), 1.0),
+        FilterType::CatmullRom => (Box::new(catmul_filter), 2.0),
+        FilterType::Mitchell => (Box::new(mitchell_filter), 2.0),
+        FilterType::Lanczos3 => (Box::new(|x| lanczos_filter(x, 3.)), 3.0),
+        FilterType::Lanczos2 => (Box::new(|x| lanczos_filter(x, 2.)), 2.0),
+        FilterType::Lanczos4 => (Box::new(|x| lanczos_filter(x, 4.)), 4.0),
+        FilterType::Gaussian(sigma) => {
+            let sigma = if sigma > 0. && sigma.is_finite() {
+                sigma
+            } else {
+                0.5
+            };
+            (Box::new(move |x| gaussian_filter(x, sigma)), 3. * sigma)
+        }
+        FilterType::Hermite => (Box::new(hermite_filter), 1.0),
+        FilterType::BSpline => (Box::new(bspline_filter), 2.0),
+        FilterType::Blackman => (Box::new(blackman_filter), 3.0),
+        FilterType::Kaiser => (Box::new(kaiser_filter), 3.0),
+        FilterType::Cubic { b, c } => (Box::new(move |x| cubic_filter(x, b, c)), 2.0),
+        FilterType::Custom(filter) => {
+            // `max()` also replaces NaN.
+            let support = filter.support().max(f64::MIN_POSITIVE);
+            (Box::new(move |x| filter.value(x)), support)
+        }
     }
 }
 
@@ -112,6 +237,21 @@ fn mitchell_filter(mut x: f64) -> f64 {
     }
 }
 
+/// Mitchell–Netravali family of cubic filters with parameters B and C
+/// https://en.wikipedia.org/wiki/Mitchell%E2%80%93Netravali_filters
+#[inline]
+fn cubic_filter(mut x: f64, b: f64, c: f64) -> f64 {
+    x = x.abs();
+    if x < 1.0 {
+        (((12. - 9. * b - 6. * c) * x + (-18. + 12. * b + 6. * c)) * x * x + (6. - 2. * b)) / 6.
+    } else if x < 2.0 {
+        (((-b - 6. * c) * x + (6. * b + 30. * c)) * x + (-12. * b - 48. * c)) * x / 6.
+            + (8. * b + 24. * c) / 6.
+    } else {
+        0.0
+    }
+}
+
 #[inline]
 fn sinc_filter(mut x: f64) -> f64 {
     if x == 0.0 {
@@ -122,12 +262,143 @@ fn sinc_filter(mut x: f64) -> f64 {
     }
 }
 
+/// Lanczos filter (a truncated sinc) with given count of lobes
 #[inline]
-fn lanczos_filter(x: f64) -> f64 {
-    // truncated sinc
-    if (-3.0..3.0).contains(&x) {
-        sinc_filter(x) * sinc_filter(x / 3.)
+fn lanczos_filter(x: f64, lobes: f64) -> f64 {
+    if (-lobes..lobes).contains(&x) {
+        sinc_filter(x) * sinc_filter(x / lobes)
     } else {
         0.0
     }
 }
+
+#[inline]
+fn gaussian_filter(x: f64, sigma: f64) -> f64 {
+    // Constant factor is omitted because coefficients are normalized.
+    (-x * x / (2. * sigma * sigma)).exp()
+}
+
+/// Hermite filter (B = 0, C = 0)
+#[inline]
+fn hermite_filter(mut x: f64) -> f64 {
+    x = x.abs();
+    if x < 1.0 {
+        (2. * x - 3.) * x * x + 1.
+    } else {
+        0.0
+    }
+}
+
+/// Cubic B-spline filter (B = 1, C = 0)
+#[inline]
+fn bspline_filter(mut x: f64) -> f64 {
+    x = x.abs();
+    if x < 1.0 {
+        (0.5 * x - 1.) * x * x + 2. / 3.
+    } else if x < 2.0 {
+        let t = 2. - x;
+        t * t * t / 6.
+    } else {
+        0.0
+    }
+}
+
+/// Sinc filter windowed by the Blackman window
+/// https://en.wikipedia.org/wiki/Window_function#Blackman_window
+#[inline]
+fn blackman_filter(x: f64) -> f64 {
+    const LOBES: f64 = 3.0;
+    if (-LOBES..LOBES).contains(&x) {
+        let t = PI * x / LOBES;
+        let window = 0.42 + 0.5 * t.cos() + 0.08 * (2. * t).cos();
+        sinc_filter(x) * window
+    } else {
+        0.0
+    }
+}
+
+/// Sinc filter windowed by the Kaiser window
+/// https://en.wikipedia.org/wiki/Kaiser_window
+#[inline]
+fn kaiser_filter(x: f64) -> f64 {
+    const LOBES: f64 = 3.0;
+    const BETA: f64 = 6.5;
+    if (-LOBES..LOBES).contains(&x) {
+        let t = x / LOBES;
+        let window = bessel_i0(BETA * (1. - t * t).sqrt()) / bessel_i0(BETA);
+        sinc_filter(x) * window
+    } else {
+        0.0
+    }
+}
+
+/// Modified Bessel function of the first kind of order zero.
+fn bessel_i0(x: f64) -> f64 {
+    let half_x = x / 2.;
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+    while term > sum * 1e-16 {
+        term *= (half_x / k) * (half_x / k);
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_are_zero_outside_of_support() {
+        let filter_types = [
+            FilterType::Box,
+            FilterType::Bilinear,
+            FilterType::Hamming,
+            FilterType::CatmullRom,
+            FilterType::Mitchell,
+            FilterType::Lanczos3,
+            FilterType::Lanczos2,
+            FilterType::Lanczos4,
+            FilterType::Hermite,
+            FilterType::BSpline,
+            FilterType::Blackman,
+            FilterType::Kaiser,
+            FilterType::Cubic { b: 0., c: 0.75 },
+        ];
+        for filter_type in filter_types {
+            let (filter, support) = get_filter_func(&filter_type);
+            assert!(filter(0.) > 0.5, "{:?}", filter_type);
+            for x in [support + 0.01, support + 0.5, -support - 0.01] {
+                assert!(filter(x).abs() < 1e-6, "{:?} {}", filter_type, x);
+            }
+        }
+    }
+
+    #[test]
+    fn filter_values() {
+        assert_eq!(hermite_filter(0.), 1.);
+        assert_eq!(hermite_filter(0.5), 0.5);
+        assert!((bspline_filter(0.) - 2. / 3.).abs() < 1e-12);
+        assert!((bspline_filter(1.) - 1. / 6.).abs() < 1e-12);
+        assert!((blackman_filter(0.) - 1.).abs() < 1e-12);
+        assert!((kaiser_filter(0.) - 1.).abs() < 1e-12);
+        assert!((bessel_i0(1.) - 1.266_065_877_752_008_4).abs() < 1e-12);
+
+        // Presets of cubic filters
+        for i in -25..=25 {
+            let x = i as f64 / 10.;
+            assert!((cubic_filter(x, 1. / 3., 1. / 3.) - mitchell_filter(x)).abs() < 1e-12);
+            assert!((cubic_filter(x, 0., 0.5) - catmul_filter(x)).abs() < 1e-12);
+            assert!((cubic_filter(x, 0., 0.) - hermite_filter(x)).abs() < 1e-12);
+            assert!((cubic_filter(x, 1., 0.) - bspline_filter(x)).abs() < 1e-12);
+        }
+
+        let (gaussian, support) = get_filter_func(&FilterType::Gaussian(0.5));
+        assert_eq!(support, 1.5);
+        assert!((gaussian(0.5) - (-0.5f64).exp()).abs() < 1e-12);
+        let (_, support) = get_filter_func(&FilterType::Gaussian(f64::NAN));
+        assert_eq!(support, 1.5);
+    }
+}
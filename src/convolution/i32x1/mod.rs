@@ -3,28 +3,36 @@ use crate::image_view::{TypedImageView, TypedImageViewMut};
 use crate::pixels::I32;
 use crate::CpuExtensions;
 
+#[cfg(target_arch = "x86_64")]
+mod avx2;
 mod native;
+#[cfg(target_arch = "x86_64")]
+mod sse4;
 
 impl Convolution for I32 {
     fn horiz_convolution(
         src_image: TypedImageView<Self>,
         dst_image: TypedImageViewMut<Self>,
         offset: u32,
-        coeffs: Coefficients,
-        cpu_extensions: CpuExtensions,
+        coeffs: &Coefficients,
+        _cpu_extensions: CpuExtensions,
     ) {
-        match cpu_extensions {
-            _ => native::horiz_convolution(src_image, dst_image, offset, coeffs),
-        }
+        // Products of one row of the horizontal pass can't be accumulated
+        // in parallel without changing the order of additions (and results).
+        native::horiz_convolution(src_image, dst_image, offset, coeffs);
     }
 
     fn vert_convolution(
         src_image: TypedImageView<Self>,
         dst_image: TypedImageViewMut<Self>,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     ) {
         match cpu_extensions {
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Avx2 => avx2::vert_convolution(src_image, dst_image, coeffs),
+            #[cfg(target_arch = "x86_64")]
+            CpuExtensions::Sse4_1 => sse4::vert_convolution(src_image, dst_image, coeffs),
             _ => native::vert_convolution(src_image, dst_image, coeffs),
         }
     }
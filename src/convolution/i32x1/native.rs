@@ -6,7 +6,7 @@ pub(crate) fn horiz_convolution(
     src_image: TypedImageView<I32>,
     mut dst_image: TypedImageViewMut<I32>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
     let coefficients_chunks = coeffs.get_chunks();
     let src_rows = src_image.iter_rows(offset);
@@ -27,7 +27,7 @@ pub(crate) fn horiz_convolution(
 pub(crate) fn vert_convolution(
     src_image: TypedImageView<I32>,
     mut dst_image: TypedImageViewMut<I32>,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
     let coefficients_chunks = coeffs.get_chunks();
     let dst_rows = dst_image.iter_rows_mut();
@@ -37,7 +37,7 @@ pub(crate) fn vert_convolution(
             let mut ss = 0.;
             let src_rows = src_image.iter_rows(first_y_src);
             for (src_row, &k) in src_rows.zip(coeffs_chunk.values) {
-                let src_pixel = unsafe { src_row.get_unchecked(x_src as usize) };
+                let src_pixel = unsafe { src_row.get_unchecked(x_src) };
                 ss += src_pixel.0 as f64 * k;
             }
             dst_pixel.0 = ss.round() as i32;
@@ -1,19 +1,26 @@
 use std::num::NonZeroU32;
+use std::sync::{Arc, OnceLock};
 
 use crate::image_view::{TypedImageView, TypedImageViewMut};
 use crate::pixels::Pixel;
 use crate::CpuExtensions;
-pub use filters::{get_filter_func, FilterType};
+pub use filters::{get_filter_func, Filter, FilterType};
+use optimisations::{NormalizerGuard16, NormalizerGuard32};
 
 #[macro_use]
 mod macros;
 
 mod f32x1;
+mod f32x3;
+mod f32x4;
 mod filters;
 mod i32x1;
 mod optimisations;
+mod u16x1;
 mod u16x3;
+mod u16x4;
 mod u8x1;
+mod u8x2;
 mod u8x3;
 mod u8x4;
 mod vertical_u16;
@@ -21,20 +28,20 @@ mod vertical_u8;
 
 pub(crate) trait Convolution
 where
-    Self: Pixel,
+    Self: Pixel + Send + Sync,
 {
     fn horiz_convolution(
         src_image: TypedImageView<Self>,
         dst_image: TypedImageViewMut<Self>,
         offset: u32,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     );
 
     fn vert_convolution(
         src_image: TypedImageView<Self>,
         dst_image: TypedImageViewMut<Self>,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     );
 }
@@ -47,9 +54,25 @@ pub struct Bound {
 
 #[derive(Debug, Clone)]
 pub struct Coefficients {
-    pub values: Vec<f64>,
     pub window_size: usize,
     pub bounds: Vec<Bound>,
+    /// Count of pixels that the image must be extended by from both
+    /// sides in `EdgeMode::Wrap` mode. Bounds are relative to
+    /// the extended image.
+    pub wrap_padding: u32,
+    /// Index of the first value of the instance in shared values.
+    first_value: usize,
+    /// Values and cache of normalized values shared between clones
+    /// and parts of the instance.
+    shared: Arc<SharedValues>,
+}
+
+#[derive(Debug)]
+struct SharedValues {
+    /// Values are read-only, because normalized values are cached.
+    values: Vec<f64>,
+    i16: OnceLock<NormalizerGuard16>,
+    i32: OnceLock<NormalizerGuard32>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -59,8 +82,58 @@ pub struct CoefficientsChunk<'a> {
 }
 
 impl Coefficients {
-    pub fn get_chunks(&self) -> Vec<CoefficientsChunk> {
-        let mut coeffs = self.values.as_slice();
+    pub fn new(values: Vec<f64>, window_size: usize, bounds: Vec<Bound>) -> Self {
+        Self {
+            window_size,
+            bounds,
+            wrap_padding: 0,
+            first_value: 0,
+            shared: Arc::new(SharedValues {
+                values,
+                i16: OnceLock::new(),
+                i32: OnceLock::new(),
+            }),
+        }
+    }
+
+    /// Returns coefficients of `count` pixels starting from the `first` one.
+    /// Values and their normalized versions are shared with the instance,
+    /// so convolution by parts gives the same result as by the whole.
+    pub fn part(&self, first: usize, count: usize) -> Self {
+        Self {
+            window_size: self.window_size,
+            bounds: self.bounds[first..first + count].to_vec(),
+            wrap_padding: self.wrap_padding,
+            first_value: self.first_value + first * self.window_size,
+            shared: self.shared.clone(),
+        }
+    }
+
+    pub fn values(&self) -> &[f64] {
+        let len = self.bounds.len() * self.window_size;
+        &self.shared.values[self.first_value..self.first_value + len]
+    }
+
+    /// Returns values normalized into `i16`.
+    /// Normalization is done only once for all clones and parts
+    /// of the instance.
+    pub fn normalizer16(&self) -> &NormalizerGuard16 {
+        self.shared
+            .i16
+            .get_or_init(|| NormalizerGuard16::new(&self.shared.values))
+    }
+
+    /// Returns values normalized into `i32`.
+    /// Normalization is done only once for all clones and parts
+    /// of the instance.
+    pub fn normalizer32(&self) -> &NormalizerGuard32 {
+        self.shared
+            .i32
+            .get_or_init(|| NormalizerGuard32::new(&self.shared.values))
+    }
+
+    pub fn get_chunks(&self) -> Vec<CoefficientsChunk<'_>> {
+        let mut coeffs = self.values();
         let mut res = Vec::with_capacity(self.bounds.len());
         for bound in &self.bounds {
             let (left, right) = coeffs.split_at(self.window_size);
@@ -75,6 +148,128 @@ impl Coefficients {
     }
 }
 
+/// Method of handling of filter's taps that are outside of the image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgeMode {
+    /// Taps outside of the image are ignored and weights of
+    /// remaining taps are renormalized.
+    #[default]
+    Renormalize,
+    /// Pixels outside of the image are equal to the nearest edge pixel
+    /// (`aaa|abc|ccc`).
+    Clamp,
+    /// Image is mirrored at its edges (`cba|abc|cba`).
+    Mirror,
+    /// Image is repeated in all directions (`abc|abc|abc`).
+    /// It is suitable for tiling textures.
+    Wrap,
+    /// Pixels outside of the image are equal to zero. Edges of the
+    /// image fade to transparent (or black for images without alpha channel).
+    Transparent,
+}
+
+impl EdgeMode {
+    /// Maps position of the pixel outside of the image into position
+    /// of the pixel inside of the image for `Clamp` and `Mirror` modes.
+    fn map(self, x: i64, size: i64) -> i64 {
+        match self {
+            Self::Mirror => {
+                let x = x.rem_euclid(2 * size);
+                if x < size {
+                    x
+                } else {
+                    2 * size - 1 - x
+                }
+            }
+            _ => x.clamp(0, size - 1),
+        }
+    }
+}
+
+/// Mapping of positions of pixels of the destination image
+/// into positions in the source image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SamplingGrid {
+    /// Centers of pixels are aligned, edges of the destination image
+    /// correspond to edges of the source image (crop box).
+    /// It is equal to `align_corners=False` in PyTorch and
+    /// `half_pixel_centers=True` in TensorFlow.
+    #[default]
+    HalfPixel,
+    /// Centers of corner pixels of the destination image correspond to
+    /// centers of corner pixels of the source image (crop box).
+    /// It is equal to `align_corners=True` in PyTorch and TensorFlow.
+    ///
+    /// Filter isn't stretched while downscaling (like `antialias=False`
+    /// in PyTorch), use the blur factor to reduce aliasing.
+    AlignCorners,
+    /// Top-left corners of pixels are aligned. It is equal to the legacy
+    /// mode of TensorFlow (`align_corners=False, half_pixel_centers=False`)
+    /// and to the `nearest` mode of PyTorch.
+    ///
+    /// Filter isn't stretched while downscaling, like with `AlignCorners`.
+    Asymmetric,
+}
+
+impl SamplingGrid {
+    /// Returns distance between centers of neighboring pixels
+    /// of the destination image in the source image.
+    pub(crate) fn step(self, in_length: f64, out_size: u32) -> f64 {
+        match self {
+            Self::AlignCorners if out_size > 1 => {
+                ((in_length - 1.) / (out_size - 1) as f64).max(0.)
+            }
+            Self::AlignCorners => 0.,
+            _ => in_length / out_size as f64,
+        }
+    }
+
+    /// Returns the point in the source image corresponding to
+    /// the center of given pixel of the destination image.
+    #[inline]
+    pub(crate) fn in_center(self, in0: f64, step: f64, out_x: u32) -> f64 {
+        match self {
+            Self::HalfPixel => in0 + (out_x as f64 + 0.5) * step,
+            _ => in0 + 0.5 + out_x as f64 * step,
+        }
+    }
+
+    /// Returns position of the first sample of the nearest-neighbor
+    /// resampling in the source image and distance between samples.
+    pub(crate) fn nearest_samples(self, in0: f64, in_length: f64, out_size: u32) -> (f64, f64) {
+        let step = self.step(in_length, out_size);
+        match self {
+            Self::HalfPixel => (in0 + step * 0.5, step),
+            Self::AlignCorners => (in0 + 0.5, step),
+            Self::Asymmetric => (in0, step),
+        }
+    }
+}
+
+/// Options of computing of coefficients that
+/// don't depend on sizes of images.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoefficientsOptions {
+    /// Multiplier of filter's support and argument
+    pub blur: f64,
+    pub edge_mode: EdgeMode,
+    pub sampling_grid: SamplingGrid,
+}
+
+impl Default for CoefficientsOptions {
+    fn default() -> Self {
+        Self {
+            blur: 1.0,
+            edge_mode: EdgeMode::Renormalize,
+            sampling_grid: SamplingGrid::HalfPixel,
+        }
+    }
+}
+
+/// Computes coefficients of convolution.
+///
+/// In `EdgeMode::Wrap` mode bounds are computed for the image
+/// extended by `wrap_padding` pixels from both sides.
 pub fn precompute_coefficients(
     in_size: NonZeroU32,
     in0: f64, // Left border for cropping
@@ -82,36 +277,65 @@ pub fn precompute_coefficients(
     out_size: NonZeroU32,
     filter: &dyn Fn(f64) -> f64,
     filter_support: f64,
+    options: CoefficientsOptions,
 ) -> Coefficients {
-    let in_size = in_size.get();
+    let in_size = in_size.get() as i64;
     let out_size = out_size.get();
+    let edge_mode = options.edge_mode;
 
-    let scale = (in1 - in0) / out_size as f64;
-    let filter_scale = scale.max(1.0);
+    let sampling_grid = options.sampling_grid;
+    let scale = sampling_grid.step(in1 - in0, out_size);
+    let filter_scale = match sampling_grid {
+        // Filter is stretched while downscaling to reduce aliasing.
+        SamplingGrid::HalfPixel => scale.max(1.0),
+        // Like PyTorch and TensorFlow without antialiasing.
+        _ => 1.0,
+    } * options.blur;
 
-    // Determine filter radius size (length of resampling filter)
-    let filter_radius = filter_support * filter_scale;
+    // Determine filter radius size (length of resampling filter).
+    // Support of the filter stretched by the scale and the blur factor
+    // may be infinite or too large, so the radius is clamped to limit
+    // count of coefficients. The clamped filter still covers the whole
+    // image and its mirrored or wrapped copies beyond both edges.
+    let filter_radius = (filter_support * filter_scale).min(2. * in_size as f64);
     // Maximum number of coeffs per out pixel
     let window_size = filter_radius.ceil() as usize * 2 + 1;
+    let window_size = match edge_mode {
+        // Coefficients of taps outside of the image are stored as is.
+        EdgeMode::Wrap => window_size,
+        // Coefficients are stored only for pixels inside of the image.
+        _ => window_size.min(in_size as usize),
+    };
     // Optimization: replace division by filter_scale
     // with multiplication by recip_filter_scale
     let recip_filter_scale = 1.0 / filter_scale;
+    // Returns the point in the input image corresponding to the centre
+    // of given pixel in the output image and range of pixels (including
+    // pixels outside of the image) covered by the filter.
+    let taps = |out_x: u32| {
+        let in_center = sampling_grid.in_center(in0, scale, out_x);
+        let x_first = (in_center - filter_radius).floor() as i64;
+        let x_last = (in_center + filter_radius).ceil() as i64;
+        (in_center, x_first, x_last)
+    };
+    // Count of pixels that the image is extended by from both sides,
+    // so that the extended image contains all pixels covered by the filter.
+    let wrap_padding = match edge_mode {
+        EdgeMode::Wrap => (0..out_size)
+            .map(|out_x| {
+                let (_, x_first, x_last) = taps(out_x);
+                (-x_first).max(x_last - in_size)
+            })
+            .fold(0, i64::max),
+        _ => 0,
+    };
 
     let count_of_coeffs = window_size * out_size as usize;
     let mut coeffs: Vec<f64> = Vec::with_capacity(count_of_coeffs);
     let mut bounds: Vec<Bound> = Vec::with_capacity(out_size as usize);
 
     for out_x in 0..out_size {
-        // Find the point in the input image corresponding to the centre
-        // of the current pixel in the output image.
-        let in_center = in0 + (out_x as f64 + 0.5) * scale;
-
-        // x_min and x_max are slice bounds for the input pixels relevant
-        // to the output pixel we are calculating. Pixel x is relevant
-        // if and only if (x >= x_min) && (x < x_max).
-        // Invariant: 0 <= x_min < x_max <= width
-        let x_min = (in_center - filter_radius).floor().max(0.) as u32;
-        let x_max = (in_center + filter_radius).ceil().min(in_size as f64) as u32;
+        let (in_center, x_first, x_last) = taps(out_x);
 
         let cur_index = coeffs.len();
         let mut ww: f64 = 0.0;
@@ -119,26 +343,131 @@ pub fn precompute_coefficients(
         // Optimisation for follow for-cycle:
         // (x + 0.5) - in_center => x - (in_center - 0.5) => x - center
         let center = in_center - 0.5;
+        let weight = |x: i64| filter((x as f64 - center) * recip_filter_scale);
 
+        // x_min and x_max are slice bounds for the input pixels relevant
+        // to the output pixel we are calculating. Pixel x is relevant
+        // if and only if (x >= x_min) && (x < x_max).
+        let (x_min, x_max) = match edge_mode {
+            EdgeMode::Renormalize | EdgeMode::Transparent => {
+                // Invariant: 0 <= x_min < x_max <= width
+                let x_min = x_first.max(0);
+                let x_max = x_last.min(in_size);
+                for x in x_min..x_max {
+                    let w = weight(x);
+                    coeffs.push(w);
+                    ww += w;
+                }
+                if edge_mode == EdgeMode::Transparent {
+                    // Pixels outside of the image are zero,
+                    // but their weights are taken into account.
+                    ww += (x_first..x_min)
+                        .chain(x_max..x_last)
+                        .map(weight)
+                        .sum::<f64>();
+                }
+                (x_min, x_max)
+            }
+            EdgeMode::Clamp | EdgeMode::Mirror => {
+                // Weights of taps outside of the image are added
+                // to weights of corresponding pixels inside of the image.
+                let mapped = (x_first..x_last).map(|x| edge_mode.map(x, in_size));
+                let x_min = mapped.clone().min().unwrap_or(0);
+                let x_max = mapped.max().map_or(0, |x| x + 1);
+                coeffs.resize(cur_index + (x_max - x_min) as usize, 0.);
+                for x in x_first..x_last {
+                    let w = weight(x);
+                    coeffs[cur_index + (edge_mode.map(x, in_size) - x_min) as usize] += w;
+                    ww += w;
+                }
+                (x_min, x_max)
+            }
+            EdgeMode::Wrap => {
+                for x in x_first..x_last {
+                    let w = weight(x);
+                    coeffs.push(w);
+                    ww += w;
+                }
+                (x_first + wrap_padding, x_last + wrap_padding)
+            }
+        };
+        debug_assert!(0 <= x_min && x_min < x_max && (x_max - x_min) as usize <= window_size);
+
+        if ww != 0.0 {
+            coeffs[cur_index..].iter_mut().for_each(|w| *w /= ww);
+        } else if x_max > x_min {
+            // Narrowed kernel (blur < 1) may miss all input pixels,
+            // so the nearest pixel is used instead.
+            let nearest = (center.round() as i64 + wrap_padding).clamp(x_min, x_max - 1);
+            coeffs[cur_index + (nearest - x_min) as usize] = 1.0;
+        }
+        // Remaining values should stay empty if they are used despite x_max.
+        coeffs.resize(cur_index + window_size, 0.);
+        bounds.push(Bound {
+            start: x_min as u32,
+            size: (x_max - x_min) as u32,
+        });
+    }
+
+    let mut coefficients = Coefficients::new(coeffs, window_size, bounds);
+    coefficients.wrap_padding = wrap_padding as u32;
+    coefficients
+}
+
+/// Computes coefficients of convolution that are equal to areas of pixels
+/// of the source image covered by pixels of the destination image
+/// (like `INTER_AREA` of OpenCV).
+///
+/// Every pixel of the destination image covers the interval of
+/// `(in1 - in0) / out_size` pixels of the source image. Pixels of
+/// the source image that are partially covered by the interval
+/// are taken with fractional weights.
+pub fn precompute_area_coefficients(
+    in_size: NonZeroU32,
+    in0: f64, // Left border for cropping
+    in1: f64, // Right border for cropping
+    out_size: NonZeroU32,
+) -> Coefficients {
+    let in_size = in_size.get() as i64;
+    let out_size = out_size.get();
+    let scale = (in1 - in0) / out_size as f64;
+    // Interval of the destination pixel intersects
+    // at most `ceil(scale) + 1` pixels of the source image.
+    let window_size = scale.ceil() as usize + 1;
+
+    let mut coeffs: Vec<f64> = Vec::with_capacity(window_size * out_size as usize);
+    let mut bounds: Vec<Bound> = Vec::with_capacity(out_size as usize);
+
+    for out_x in 0..out_size {
+        // Interval of the source image covered by the current pixel
+        // of the destination image.
+        let left = (in0 + out_x as f64 * scale).max(0.);
+        let right = (in0 + (out_x + 1) as f64 * scale).min(in_size as f64);
+
+        let x_min = (left.floor() as i64).clamp(0, in_size - 1);
+        let x_max = (right.ceil() as i64).clamp(x_min + 1, x_min + window_size as i64);
+        let x_max = x_max.min(in_size);
+
+        let cur_index = coeffs.len();
+        let mut ww: f64 = 0.0;
         for x in x_min..x_max {
-            let w: f64 = filter((x as f64 - center) * recip_filter_scale);
+            let w = (right.min((x + 1) as f64) - left.max(x as f64)).max(0.);
             coeffs.push(w);
             ww += w;
         }
         if ww != 0.0 {
             coeffs[cur_index..].iter_mut().for_each(|w| *w /= ww);
+        } else {
+            // Empty interval (e.g. crop box with zero size) is
+            // replaced by the nearest pixel.
+            coeffs[cur_index] = 1.0;
         }
-        // Remaining values should stay empty if they are used despite x_max.
         coeffs.resize(cur_index + window_size, 0.);
         bounds.push(Bound {
-            start: x_min,
-            size: x_max - x_min,
+            start: x_min as u32,
+            size: (x_max - x_min) as u32,
         });
     }
 
-    Coefficients {
-        values: coeffs,
-        window_size,
-        bounds,
-    }
+    Coefficients::new(coeffs, window_size, bounds)
 }
@@ -1,6 +1,4 @@
-use std::slice;
-
-use super::Bound;
+use super::Coefficients;
 
 // This code is based on C-implementation from Pillow-SIMD package for Python
 // https://github.com/uploadcare/pillow-simd
@@ -30,10 +28,10 @@ const PRECISION_BITS: u8 = 32 - 8 - 2;
 // We use i16 type to store coefficients.
 const MAX_COEFS_PRECISION: u8 = 16 - 1;
 
-/// Converts `Vec<f64>` into `&[i16]` without additional memory allocations.
-/// The memory buffer from `Vec<f64>` uses as `[i16]` .
+/// Normalized coefficients converted from `f64` into `i16`.
+#[derive(Debug)]
 pub struct NormalizerGuard16 {
-    values: Vec<f64>,
+    values: Vec<i16>,
     precision: u8,
 }
 
@@ -45,7 +43,7 @@ pub struct CoefficientsI16Chunk<'a> {
 
 impl NormalizerGuard16 {
     #[inline]
-    pub fn new(mut values: Vec<f64>) -> Self {
+    pub fn new(values: &[f64]) -> Self {
         let max_weight = values
             .iter()
             .max_by(|&x, &y| x.partial_cmp(y).unwrap())
@@ -63,29 +61,19 @@ impl NormalizerGuard16 {
         }
         debug_assert!(precision >= 4); // required for some SIMD optimisations
 
-        let len = values.len();
-        let ptr = values.as_mut_ptr();
-        // Size of `[i16]` always will be not greater than `[f64]` with same number of items
-        let values_i16 = unsafe { slice::from_raw_parts_mut(ptr as *mut i16, len) };
-
         let scale = (1 << precision) as f64;
-        for (&src, dst) in values.iter().zip(values_i16.iter_mut()) {
-            *dst = (src * scale).round() as i16;
-        }
+        let values = values.iter().map(|&v| (v * scale).round() as i16).collect();
         Self { values, precision }
     }
 
     #[inline]
-    pub fn normalized_chunks(
-        &self,
-        window_size: usize,
-        bounds: &[Bound],
-    ) -> Vec<CoefficientsI16Chunk> {
-        let len = self.values.len();
-        let ptr = self.values.as_ptr();
-        let mut cooefs = unsafe { slice::from_raw_parts(ptr as *const i16, len) };
-        let mut res = Vec::with_capacity(bounds.len());
-        for bound in bounds {
+    /// Returns chunks of normalized values of given coefficients.
+    /// The normalizer must be received from the same coefficients.
+    pub fn normalized_chunks(&self, coeffs: &Coefficients) -> Vec<CoefficientsI16Chunk<'_>> {
+        let window_size = coeffs.window_size;
+        let mut cooefs = &self.values[coeffs.first_value..];
+        let mut res = Vec::with_capacity(coeffs.bounds.len());
+        for bound in &coeffs.bounds {
             let (left, right) = cooefs.split_at(window_size);
             cooefs = right;
             let size = bound.size as usize;
@@ -129,16 +117,16 @@ pub struct CoefficientsI32Chunk<'a> {
     pub values: &'a [i32],
 }
 
-/// Converts `Vec<f64>` into `&[i32]` without additional memory allocations.
-/// The memory buffer from `Vec<f64>` uses as `[i32]` .
+/// Normalized coefficients converted from `f64` into `i32`.
+#[derive(Debug)]
 pub struct NormalizerGuard32 {
-    values: Vec<f64>,
+    values: Vec<i32>,
     precision: u8,
 }
 
 impl NormalizerGuard32 {
     #[inline]
-    pub fn new(mut values: Vec<f64>) -> Self {
+    pub fn new(values: &[f64]) -> Self {
         let max_weight = values
             .iter()
             .max_by(|&x, &y| x.partial_cmp(y).unwrap())
@@ -156,29 +144,19 @@ impl NormalizerGuard32 {
         }
         debug_assert!(precision >= 4); // required for some SIMD optimisations
 
-        let len = values.len();
-        let ptr = values.as_mut_ptr();
-        // Size of `[i32]` always will be not greater than `[f64]` with same number of items
-        let values_i32 = unsafe { slice::from_raw_parts_mut(ptr as *mut i32, len) };
-
         let scale = (1i64 << precision) as f64;
-        for (&src, dst) in values.iter().zip(values_i32.iter_mut()) {
-            *dst = (src * scale).round() as i32;
-        }
+        let values = values.iter().map(|&v| (v * scale).round() as i32).collect();
         Self { values, precision }
     }
 
     #[inline]
-    pub fn normalized_chunks(
-        &self,
-        window_size: usize,
-        bounds: &[Bound],
-    ) -> Vec<CoefficientsI32Chunk> {
-        let len = self.values.len();
-        let ptr = self.values.as_ptr();
-        let mut cooefs = unsafe { slice::from_raw_parts(ptr as *const i32, len) };
-        let mut res = Vec::with_capacity(bounds.len());
-        for bound in bounds {
+    /// Returns chunks of normalized values of given coefficients.
+    /// The normalizer must be received from the same coefficients.
+    pub fn normalized_chunks(&self, coeffs: &Coefficients) -> Vec<CoefficientsI32Chunk<'_>> {
+        let window_size = coeffs.window_size;
+        let mut cooefs = &self.values[coeffs.first_value..];
+        let mut res = Vec::with_capacity(coeffs.bounds.len());
+        for bound in &coeffs.bounds {
             let (left, right) = cooefs.split_at(window_size);
             cooefs = right;
             let size = bound.size as usize;
@@ -208,9 +186,9 @@ mod tests {
     #[test]
     fn test_minimal_precision() {
         // required for some SIMD optimisations
-        assert!(NormalizerGuard16::new(vec![0.0]).precision() >= 4);
-        assert!(NormalizerGuard16::new(vec![2.0]).precision() >= 4);
-        assert!(NormalizerGuard32::new(vec![0.0]).precision() >= 4);
-        assert!(NormalizerGuard32::new(vec![2.0]).precision() >= 4);
+        assert!(NormalizerGuard16::new(&[0.0]).precision() >= 4);
+        assert!(NormalizerGuard16::new(&[2.0]).precision() >= 4);
+        assert!(NormalizerGuard32::new(&[0.0]).precision() >= 4);
+        assert!(NormalizerGuard32::new(&[2.0]).precision() >= 4);
     }
 }
@@ -0,0 +1,193 @@
+use std::arch::x86_64::*;
+
+use crate::convolution::{optimisations, Coefficients};
+use crate::image_view::{FourRows, FourRowsMut, TypedImageView, TypedImageViewMut};
+use crate::pixels::U16;
+use crate::simd_utils;
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: TypedImageView<U16>,
+    mut dst_image: TypedImageViewMut<U16>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer_guard = coeffs.normalizer32();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
+    let dst_height = dst_image.height().get();
+
+    let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
+    let dst_iter = dst_image.iter_4_rows_mut();
+    for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
+        unsafe {
+            horiz_convolution_four_rows(src_rows, dst_rows, &coefficients_chunks, normalizer_guard);
+        }
+    }
+
+    let mut yy = dst_height - dst_height % 4;
+    while yy < dst_height {
+        unsafe {
+            horiz_convolution_one_row(
+                src_image.get_row(yy + offset).unwrap(),
+                dst_image.get_row_mut(yy).unwrap(),
+                &coefficients_chunks,
+                normalizer_guard,
+            );
+        }
+        yy += 1;
+    }
+}
+
+/*
+    |L0  | |L1  | |L2  | |L3  | |L4  | |L5  | |L6  | |L7  |
+    |0001| |0203| |0405| |0607| |0809| |1011| |1213| |1415|
+
+    Pixels are zero-extended into i64 by fours (L0-L3 and L4-L7) and
+    multiplied by coefficients which are sign-extended into i64
+    with order k0-k3 and k4-k7 accordingly.
+*/
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - length of all rows in src_rows must be equal
+/// - length of all rows in dst_rows must be equal
+/// - coefficients_chunks.len() == dst_rows.0.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.0.len()
+#[target_feature(enable = "avx2")]
+unsafe fn horiz_convolution_four_rows(
+    src_rows: FourRows<U16>,
+    dst_rows: FourRowsMut<U16>,
+    coefficients_chunks: &[optimisations::CoefficientsI32Chunk],
+    normalizer_guard: &optimisations::NormalizerGuard32,
+) {
+    let (s_row0, s_row1, s_row2, s_row3) = src_rows;
+    let s_rows = [s_row0, s_row1, s_row2, s_row3];
+    let (d_row0, d_row1, d_row2, d_row3) = dst_rows;
+    let d_rows = [d_row0, d_row1, d_row2, d_row3];
+    let precision = normalizer_guard.precision();
+    let half_error = 1i64 << (precision - 1);
+    let mut ll_buf = [0i64; 4];
+
+    for (dst_x, coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let mut x: usize = coeffs_chunk.start as usize;
+        let mut ll_sum = [_mm256_setzero_si256(); 4];
+
+        let coeffs_by_8 = coeffs_chunk.values.chunks_exact(8);
+        let reminder8 = coeffs_by_8.remainder();
+
+        for k in coeffs_by_8 {
+            let coeff0123_i64x4 = _mm256_cvtepi32_epi64(simd_utils::loadu_si128(k, 0));
+            let coeff4567_i64x4 = _mm256_cvtepi32_epi64(simd_utils::loadu_si128(k, 4));
+
+            for i in 0..4 {
+                let source = simd_utils::loadu_si128(s_rows[i], x);
+
+                let l0123_i64x4 = _mm256_cvtepu16_epi64(source);
+                ll_sum[i] =
+                    _mm256_add_epi64(ll_sum[i], _mm256_mul_epi32(l0123_i64x4, coeff0123_i64x4));
+
+                let l4567_i64x4 = _mm256_cvtepu16_epi64(_mm_srli_si128::<8>(source));
+                ll_sum[i] =
+                    _mm256_add_epi64(ll_sum[i], _mm256_mul_epi32(l4567_i64x4, coeff4567_i64x4));
+            }
+            x += 8;
+        }
+
+        let mut coeffs_by_4 = reminder8.chunks_exact(4);
+        let reminder4 = coeffs_by_4.remainder();
+
+        if let Some(k) = coeffs_by_4.next() {
+            let coeff0123_i64x4 = _mm256_cvtepi32_epi64(simd_utils::loadu_si128(k, 0));
+
+            for i in 0..4 {
+                let source = simd_utils::loadl_epi64(s_rows[i], x);
+                let l0123_i64x4 = _mm256_cvtepu16_epi64(source);
+                ll_sum[i] =
+                    _mm256_add_epi64(ll_sum[i], _mm256_mul_epi32(l0123_i64x4, coeff0123_i64x4));
+            }
+            x += 4;
+        }
+
+        for &k in reminder4 {
+            let coeff_i64x4 = _mm256_set_epi64x(0, 0, 0, k as i64);
+
+            for i in 0..4 {
+                let &pixel = s_rows[i].get_unchecked(x);
+                let l_i64x4 = _mm256_set_epi64x(0, 0, 0, pixel.0 as i64);
+                ll_sum[i] = _mm256_add_epi64(ll_sum[i], _mm256_mul_epi32(l_i64x4, coeff_i64x4));
+            }
+            x += 1;
+        }
+
+        for i in 0..4 {
+            _mm256_storeu_si256(ll_buf.as_mut_ptr() as *mut __m256i, ll_sum[i]);
+            let dst_pixel = d_rows[i].get_unchecked_mut(dst_x);
+            dst_pixel.0 =
+                normalizer_guard.clip(ll_buf[0] + ll_buf[1] + ll_buf[2] + ll_buf[3] + half_error);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - bounds.len() == dst_row.len()
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "avx2")]
+unsafe fn horiz_convolution_one_row(
+    src_row: &[U16],
+    dst_row: &mut [U16],
+    coefficients_chunks: &[optimisations::CoefficientsI32Chunk],
+    normalizer_guard: &optimisations::NormalizerGuard32,
+) {
+    let precision = normalizer_guard.precision();
+    let half_error = 1i64 << (precision - 1);
+    let mut ll_buf = [0i64; 4];
+
+    for (dst_x, &coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let mut x: usize = coeffs_chunk.start as usize;
+        let mut ll_sum = _mm256_setzero_si256();
+
+        let coeffs_by_8 = coeffs_chunk.values.chunks_exact(8);
+        let reminder8 = coeffs_by_8.remainder();
+
+        for k in coeffs_by_8 {
+            let coeff0123_i64x4 = _mm256_cvtepi32_epi64(simd_utils::loadu_si128(k, 0));
+            let coeff4567_i64x4 = _mm256_cvtepi32_epi64(simd_utils::loadu_si128(k, 4));
+
+            let source = simd_utils::loadu_si128(src_row, x);
+
+            let l0123_i64x4 = _mm256_cvtepu16_epi64(source);
+            ll_sum = _mm256_add_epi64(ll_sum, _mm256_mul_epi32(l0123_i64x4, coeff0123_i64x4));
+
+            let l4567_i64x4 = _mm256_cvtepu16_epi64(_mm_srli_si128::<8>(source));
+            ll_sum = _mm256_add_epi64(ll_sum, _mm256_mul_epi32(l4567_i64x4, coeff4567_i64x4));
+
+            x += 8;
+        }
+
+        let mut coeffs_by_4 = reminder8.chunks_exact(4);
+        let reminder4 = coeffs_by_4.remainder();
+
+        if let Some(k) = coeffs_by_4.next() {
+            let coeff0123_i64x4 = _mm256_cvtepi32_epi64(simd_utils::loadu_si128(k, 0));
+            let source = simd_utils::loadl_epi64(src_row, x);
+            let l0123_i64x4 = _mm256_cvtepu16_epi64(source);
+            ll_sum = _mm256_add_epi64(ll_sum, _mm256_mul_epi32(l0123_i64x4, coeff0123_i64x4));
+
+            x += 4;
+        }
+
+        for &k in reminder4 {
+            let coeff_i64x4 = _mm256_set_epi64x(0, 0, 0, k as i64);
+            let &pixel = src_row.get_unchecked(x);
+            let l_i64x4 = _mm256_set_epi64x(0, 0, 0, pixel.0 as i64);
+            ll_sum = _mm256_add_epi64(ll_sum, _mm256_mul_epi32(l_i64x4, coeff_i64x4));
+
+            x += 1;
+        }
+
+        _mm256_storeu_si256(ll_buf.as_mut_ptr() as *mut __m256i, ll_sum);
+        let dst_pixel = dst_row.get_unchecked_mut(dst_x);
+        dst_pixel.0 =
+            normalizer_guard.clip(ll_buf[0] + ll_buf[1] + ll_buf[2] + ll_buf[3] + half_error);
+    }
+}
@@ -0,0 +1,167 @@
+use std::arch::x86_64::*;
+
+use crate::convolution::optimisations::CoefficientsI32Chunk;
+use crate::convolution::{optimisations, Coefficients};
+use crate::image_view::{FourRows, FourRowsMut, TypedImageView, TypedImageViewMut};
+use crate::pixels::U16;
+use crate::simd_utils;
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: TypedImageView<U16>,
+    mut dst_image: TypedImageViewMut<U16>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer_guard = coeffs.normalizer32();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
+    let dst_height = dst_image.height().get();
+
+    let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
+    let dst_iter = dst_image.iter_4_rows_mut();
+    for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
+        unsafe {
+            horiz_convolution_four_rows(src_rows, dst_rows, &coefficients_chunks, normalizer_guard);
+        }
+    }
+
+    let mut yy = dst_height - dst_height % 4;
+    while yy < dst_height {
+        unsafe {
+            horiz_convolution_one_row(
+                src_image.get_row(yy + offset).unwrap(),
+                dst_image.get_row_mut(yy).unwrap(),
+                &coefficients_chunks,
+                normalizer_guard,
+            );
+        }
+        yy += 1;
+    }
+}
+
+/*
+    |L0  | |L1  | |L2  | |L3  |
+    |0001| |0203| |0405| |0607|
+
+    Pixels are zero-extended into i64 by pairs (L0 L1 and L2 L3) and
+    multiplied by coefficients with order k0 k1 and k2 k3 accordingly.
+    Low 32 bits of every i64 lane of coefficients must contain
+    coefficient value, so they are shuffled into order k0 k0 k1 k1
+    and k2 k2 k3 k3.
+*/
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - length of all rows in src_rows must be equal
+/// - length of all rows in dst_rows must be equal
+/// - coefficients_chunks.len() == dst_rows.0.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.0.len()
+#[target_feature(enable = "sse4.1")]
+unsafe fn horiz_convolution_four_rows(
+    src_rows: FourRows<U16>,
+    dst_rows: FourRowsMut<U16>,
+    coefficients_chunks: &[CoefficientsI32Chunk],
+    normalizer_guard: &optimisations::NormalizerGuard32,
+) {
+    let (s_row0, s_row1, s_row2, s_row3) = src_rows;
+    let s_rows = [s_row0, s_row1, s_row2, s_row3];
+    let (d_row0, d_row1, d_row2, d_row3) = dst_rows;
+    let d_rows = [d_row0, d_row1, d_row2, d_row3];
+    let precision = normalizer_guard.precision();
+    let half_error = 1i64 << (precision - 1);
+    let mut ll_buf = [0i64; 2];
+
+    for (dst_x, coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let mut x: usize = coeffs_chunk.start as usize;
+        let mut ll_sum = [_mm_setzero_si128(); 4];
+
+        let coeffs_by_4 = coeffs_chunk.values.chunks_exact(4);
+        let reminder = coeffs_by_4.remainder();
+
+        for k in coeffs_by_4 {
+            let coeffs_i32x4 = simd_utils::loadu_si128(k, 0);
+            let coeff01_i64x2 = _mm_shuffle_epi32::<0b01_01_00_00>(coeffs_i32x4);
+            let coeff23_i64x2 = _mm_shuffle_epi32::<0b11_11_10_10>(coeffs_i32x4);
+
+            for i in 0..4 {
+                let source = simd_utils::loadl_epi64(s_rows[i], x);
+
+                let l01_i64x2 = _mm_cvtepu16_epi64(source);
+                ll_sum[i] = _mm_add_epi64(ll_sum[i], _mm_mul_epi32(l01_i64x2, coeff01_i64x2));
+
+                let l23_i64x2 = _mm_cvtepu16_epi64(_mm_srli_si128::<4>(source));
+                ll_sum[i] = _mm_add_epi64(ll_sum[i], _mm_mul_epi32(l23_i64x2, coeff23_i64x2));
+            }
+            x += 4;
+        }
+
+        for &k in reminder {
+            let coeff_i64x2 = _mm_set_epi64x(0, k as i64);
+
+            for i in 0..4 {
+                let &pixel = s_rows[i].get_unchecked(x);
+                let l_i64x2 = _mm_set_epi64x(0, pixel.0 as i64);
+                ll_sum[i] = _mm_add_epi64(ll_sum[i], _mm_mul_epi32(l_i64x2, coeff_i64x2));
+            }
+            x += 1;
+        }
+
+        for i in 0..4 {
+            _mm_storeu_si128(ll_buf.as_mut_ptr() as *mut __m128i, ll_sum[i]);
+            let dst_pixel = d_rows[i].get_unchecked_mut(dst_x);
+            dst_pixel.0 = normalizer_guard.clip(ll_buf[0] + ll_buf[1] + half_error);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - bounds.len() == dst_row.len()
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "sse4.1")]
+unsafe fn horiz_convolution_one_row(
+    src_row: &[U16],
+    dst_row: &mut [U16],
+    coefficients_chunks: &[CoefficientsI32Chunk],
+    normalizer_guard: &optimisations::NormalizerGuard32,
+) {
+    let precision = normalizer_guard.precision();
+    let half_error = 1i64 << (precision - 1);
+    let mut ll_buf = [0i64; 2];
+
+    for (dst_x, &coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let mut x: usize = coeffs_chunk.start as usize;
+        let mut ll_sum = _mm_setzero_si128();
+
+        let coeffs_by_4 = coeffs_chunk.values.chunks_exact(4);
+        let reminder = coeffs_by_4.remainder();
+
+        for k in coeffs_by_4 {
+            let coeffs_i32x4 = simd_utils::loadu_si128(k, 0);
+            let coeff01_i64x2 = _mm_shuffle_epi32::<0b01_01_00_00>(coeffs_i32x4);
+            let coeff23_i64x2 = _mm_shuffle_epi32::<0b11_11_10_10>(coeffs_i32x4);
+
+            let source = simd_utils::loadl_epi64(src_row, x);
+
+            let l01_i64x2 = _mm_cvtepu16_epi64(source);
+            ll_sum = _mm_add_epi64(ll_sum, _mm_mul_epi32(l01_i64x2, coeff01_i64x2));
+
+            let l23_i64x2 = _mm_cvtepu16_epi64(_mm_srli_si128::<4>(source));
+            ll_sum = _mm_add_epi64(ll_sum, _mm_mul_epi32(l23_i64x2, coeff23_i64x2));
+
+            x += 4;
+        }
+
+        for &k in reminder {
+            let coeff_i64x2 = _mm_set_epi64x(0, k as i64);
+            let &pixel = src_row.get_unchecked(x);
+            let l_i64x2 = _mm_set_epi64x(0, pixel.0 as i64);
+            ll_sum = _mm_add_epi64(ll_sum, _mm_mul_epi32(l_i64x2, coeff_i64x2));
+
+            x += 1;
+        }
+
+        _mm_storeu_si128(ll_buf.as_mut_ptr() as *mut __m128i, ll_sum);
+        let dst_pixel = dst_row.get_unchecked_mut(dst_x);
+        dst_pixel.0 = normalizer_guard.clip(ll_buf[0] + ll_buf[1] + half_error);
+    }
+}
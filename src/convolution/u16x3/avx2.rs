@@ -10,25 +10,17 @@ pub(crate) fn horiz_convolution(
     src_image: TypedImageView<U16x3>,
     mut dst_image: TypedImageViewMut<U16x3>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
-
-    let normalizer_guard = optimisations::NormalizerGuard32::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let normalizer_guard = coeffs.normalizer32();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
     let dst_height = dst_image.height().get();
 
     let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
     let dst_iter = dst_image.iter_4_rows_mut();
     for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
         unsafe {
-            horiz_convolution_four_rows(
-                src_rows,
-                dst_rows,
-                &coefficients_chunks,
-                &normalizer_guard,
-            );
+            horiz_convolution_four_rows(src_rows, dst_rows, &coefficients_chunks, normalizer_guard);
         }
     }
 
@@ -39,7 +31,7 @@ pub(crate) fn horiz_convolution(
                 src_image.get_row(yy + offset).unwrap(),
                 dst_image.get_row_mut(yy).unwrap(),
                 &coefficients_chunks,
-                &normalizer_guard,
+                normalizer_guard,
             );
         }
         yy += 1;
@@ -173,9 +165,9 @@ unsafe fn horiz_convolution_four_rows(
         }
 
         for i in 0..4 {
-            _mm256_storeu_si256((&mut rg_buf).as_mut_ptr() as *mut __m256i, rg_sum[i]);
-            _mm256_storeu_si256((&mut rg_bb_buf).as_mut_ptr() as *mut __m256i, rg_bb_sum[i]);
-            _mm256_storeu_si256((&mut bbb_buf).as_mut_ptr() as *mut __m256i, bbb_sum[i]);
+            _mm256_storeu_si256(rg_buf.as_mut_ptr() as *mut __m256i, rg_sum[i]);
+            _mm256_storeu_si256(rg_bb_buf.as_mut_ptr() as *mut __m256i, rg_bb_sum[i]);
+            _mm256_storeu_si256(bbb_buf.as_mut_ptr() as *mut __m256i, bbb_sum[i]);
             let dst_pixel = d_rows[i].get_unchecked_mut(dst_x);
             dst_pixel.0[0] =
                 normalizer_guard.clip(rg_buf[0] + rg_buf[2] + rg_bb_buf[0] + half_error);
@@ -302,9 +294,9 @@ unsafe fn horiz_convolution_one_row(
             x += 1;
         }
 
-        _mm256_storeu_si256((&mut rg_buf).as_mut_ptr() as *mut __m256i, rg_sum);
-        _mm256_storeu_si256((&mut rg_bb_buf).as_mut_ptr() as *mut __m256i, rg_bb_sum);
-        _mm256_storeu_si256((&mut bbb_buf).as_mut_ptr() as *mut __m256i, bbb_sum);
+        _mm256_storeu_si256(rg_buf.as_mut_ptr() as *mut __m256i, rg_sum);
+        _mm256_storeu_si256(rg_bb_buf.as_mut_ptr() as *mut __m256i, rg_bb_sum);
+        _mm256_storeu_si256(bbb_buf.as_mut_ptr() as *mut __m256i, bbb_sum);
         let dst_pixel = dst_row.get_unchecked_mut(dst_x);
         dst_pixel.0[0] = normalizer_guard.clip(rg_buf[0] + rg_buf[2] + rg_bb_buf[0] + half_error);
         dst_pixel.0[1] = normalizer_guard.clip(rg_buf[1] + rg_buf[3] + rg_bb_buf[1] + half_error);
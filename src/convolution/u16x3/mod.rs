@@ -15,7 +15,7 @@ impl Convolution for U16x3 {
         src_image: TypedImageView<Self>,
         dst_image: TypedImageViewMut<Self>,
         offset: u32,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     ) {
         match cpu_extensions {
@@ -30,7 +30,7 @@ impl Convolution for U16x3 {
     fn vert_convolution(
         src_image: TypedImageView<Self>,
         dst_image: TypedImageViewMut<Self>,
-        coeffs: Coefficients,
+        coeffs: &Coefficients,
         cpu_extensions: CpuExtensions,
     ) {
         vert_convolution_u16(src_image, dst_image, coeffs, cpu_extensions);
@@ -14,20 +14,17 @@ pub(crate) fn horiz_convolution(
     src_image: TypedImageView<U16x3>,
     mut dst_image: TypedImageViewMut<U16x3>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
-
-    let normalizer_guard = optimisations::NormalizerGuard32::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let normalizer_guard = coeffs.normalizer32();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
     let dst_height = dst_image.height().get();
 
     let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
     let dst_iter = dst_image.iter_4_rows_mut();
     for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
         unsafe {
-            horiz_convolution_8u4x(src_rows, dst_rows, &coefficients_chunks, &normalizer_guard);
+            horiz_convolution_8u4x(src_rows, dst_rows, &coefficients_chunks, normalizer_guard);
         }
     }
 
@@ -38,7 +35,7 @@ pub(crate) fn horiz_convolution(
                 src_image.get_row(yy + offset).unwrap(),
                 dst_image.get_row_mut(yy).unwrap(),
                 &coefficients_chunks,
-                &normalizer_guard,
+                normalizer_guard,
             );
         }
         yy += 1;
@@ -135,8 +132,8 @@ unsafe fn horiz_convolution_8u4x(
         }
 
         for i in 0..4 {
-            _mm_storeu_si128((&mut rg_buf).as_mut_ptr() as *mut __m128i, rg_sum[i]);
-            _mm_storeu_si128((&mut bb_buf).as_mut_ptr() as *mut __m128i, bb_sum[i]);
+            _mm_storeu_si128(rg_buf.as_mut_ptr() as *mut __m128i, rg_sum[i]);
+            _mm_storeu_si128(bb_buf.as_mut_ptr() as *mut __m128i, bb_sum[i]);
             let dst_pixel = d_rows[i].get_unchecked_mut(dst_x);
             dst_pixel.0[0] = normalizer_guard.clip(rg_buf[0] + half_error);
             dst_pixel.0[1] = normalizer_guard.clip(rg_buf[1] + half_error);
@@ -228,8 +225,8 @@ unsafe fn horiz_convolution_8u(
             x += 1;
         }
 
-        _mm_storeu_si128((&mut rg_buf).as_mut_ptr() as *mut __m128i, rg_sum);
-        _mm_storeu_si128((&mut bb_buf).as_mut_ptr() as *mut __m128i, bb_sum);
+        _mm_storeu_si128(rg_buf.as_mut_ptr() as *mut __m128i, rg_sum);
+        _mm_storeu_si128(bb_buf.as_mut_ptr() as *mut __m128i, bb_sum);
         let dst_pixel = dst_row.get_unchecked_mut(dst_x);
         dst_pixel.0[0] = normalizer_guard.clip(rg_buf[0]);
         dst_pixel.0[1] = normalizer_guard.clip(rg_buf[1]);
@@ -0,0 +1,232 @@
+use std::arch::x86_64::*;
+
+use crate::convolution::optimisations::CoefficientsI32Chunk;
+use crate::convolution::{optimisations, Coefficients};
+use crate::image_view::{FourRows, FourRowsMut, TypedImageView, TypedImageViewMut};
+use crate::pixels::U16x4;
+use crate::simd_utils;
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: TypedImageView<U16x4>,
+    mut dst_image: TypedImageViewMut<U16x4>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer_guard = coeffs.normalizer32();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
+    let dst_height = dst_image.height().get();
+
+    let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
+    let dst_iter = dst_image.iter_4_rows_mut();
+    for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
+        unsafe {
+            horiz_convolution_four_rows(src_rows, dst_rows, &coefficients_chunks, normalizer_guard);
+        }
+    }
+
+    let mut yy = dst_height - dst_height % 4;
+    while yy < dst_height {
+        unsafe {
+            horiz_convolution_one_row(
+                src_image.get_row(yy + offset).unwrap(),
+                dst_image.get_row_mut(yy).unwrap(),
+                &coefficients_chunks,
+                normalizer_guard,
+            );
+        }
+        yy += 1;
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - length of all rows in src_rows must be equal
+/// - length of all rows in dst_rows must be equal
+/// - coefficients_chunks.len() == dst_rows.0.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.0.len()
+#[target_feature(enable = "sse4.1")]
+unsafe fn horiz_convolution_four_rows(
+    src_rows: FourRows<U16x4>,
+    dst_rows: FourRowsMut<U16x4>,
+    coefficients_chunks: &[CoefficientsI32Chunk],
+    normalizer_guard: &optimisations::NormalizerGuard32,
+) {
+    let (s_row0, s_row1, s_row2, s_row3) = src_rows;
+    let s_rows = [s_row0, s_row1, s_row2, s_row3];
+    let (d_row0, d_row1, d_row2, d_row3) = dst_rows;
+    let d_rows = [d_row0, d_row1, d_row2, d_row3];
+    let precision = normalizer_guard.precision();
+    let half_error = 1i64 << (precision - 1);
+    let mut rg_buf = [0i64; 2];
+    let mut ba_buf = [0i64; 2];
+
+    /*
+        |R    G    B    A   | |R    G    B    A   |
+        |0001 0203 0405 0607| |0809 1011 1213 1415|
+
+        Shuffle to extract RG components of first pixel as i64:
+        -1, -1, -1, -1, -1, -1, 3, 2, -1, -1, -1, -1, -1, -1, 1, 0
+
+        Shuffle to extract BA components of first pixel as i64:
+        -1, -1, -1, -1, -1, -1, 7, 6, -1, -1, -1, -1, -1, -1, 5, 4
+
+        Shuffle to extract RG components of second pixel as i64:
+        -1, -1, -1, -1, -1, -1, 11, 10, -1, -1, -1, -1, -1, -1, 9, 8
+
+        Shuffle to extract BA components of second pixel as i64:
+        -1, -1, -1, -1, -1, -1, 15, 14, -1, -1, -1, -1, -1, -1, 13, 12
+    */
+
+    let rg0_shuffle = _mm_set_epi8(-1, -1, -1, -1, -1, -1, 3, 2, -1, -1, -1, -1, -1, -1, 1, 0);
+    let ba0_shuffle = _mm_set_epi8(-1, -1, -1, -1, -1, -1, 7, 6, -1, -1, -1, -1, -1, -1, 5, 4);
+    let rg1_shuffle = _mm_set_epi8(-1, -1, -1, -1, -1, -1, 11, 10, -1, -1, -1, -1, -1, -1, 9, 8);
+    let ba1_shuffle = _mm_set_epi8(
+        -1, -1, -1, -1, -1, -1, 15, 14, -1, -1, -1, -1, -1, -1, 13, 12,
+    );
+
+    for (dst_x, coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let mut x: usize = coeffs_chunk.start as usize;
+        let mut rg_sum = [_mm_set1_epi8(0); 4];
+        let mut ba_sum = [_mm_set1_epi8(0); 4];
+
+        let coeffs_by_2 = coeffs_chunk.values.chunks_exact(2);
+        let reminder = coeffs_by_2.remainder();
+
+        for k in coeffs_by_2 {
+            let coeff0_i64x2 = _mm_set1_epi64x(k[0] as i64);
+            let coeff1_i64x2 = _mm_set1_epi64x(k[1] as i64);
+
+            for i in 0..4 {
+                let source = simd_utils::loadu_si128(s_rows[i], x);
+
+                let rg_i64x2 = _mm_shuffle_epi8(source, rg0_shuffle);
+                rg_sum[i] = _mm_add_epi64(rg_sum[i], _mm_mul_epi32(rg_i64x2, coeff0_i64x2));
+
+                let ba_i64x2 = _mm_shuffle_epi8(source, ba0_shuffle);
+                ba_sum[i] = _mm_add_epi64(ba_sum[i], _mm_mul_epi32(ba_i64x2, coeff0_i64x2));
+
+                let rg_i64x2 = _mm_shuffle_epi8(source, rg1_shuffle);
+                rg_sum[i] = _mm_add_epi64(rg_sum[i], _mm_mul_epi32(rg_i64x2, coeff1_i64x2));
+
+                let ba_i64x2 = _mm_shuffle_epi8(source, ba1_shuffle);
+                ba_sum[i] = _mm_add_epi64(ba_sum[i], _mm_mul_epi32(ba_i64x2, coeff1_i64x2));
+            }
+            x += 2;
+        }
+
+        if let Some(&k) = reminder.first() {
+            let coeff_i64x2 = _mm_set1_epi64x(k as i64);
+
+            for i in 0..4 {
+                let source = simd_utils::loadl_epi64(s_rows[i], x);
+
+                let rg_i64x2 = _mm_shuffle_epi8(source, rg0_shuffle);
+                rg_sum[i] = _mm_add_epi64(rg_sum[i], _mm_mul_epi32(rg_i64x2, coeff_i64x2));
+
+                let ba_i64x2 = _mm_shuffle_epi8(source, ba0_shuffle);
+                ba_sum[i] = _mm_add_epi64(ba_sum[i], _mm_mul_epi32(ba_i64x2, coeff_i64x2));
+            }
+        }
+
+        for i in 0..4 {
+            _mm_storeu_si128(rg_buf.as_mut_ptr() as *mut __m128i, rg_sum[i]);
+            _mm_storeu_si128(ba_buf.as_mut_ptr() as *mut __m128i, ba_sum[i]);
+            let dst_pixel = d_rows[i].get_unchecked_mut(dst_x);
+            dst_pixel.0[0] = normalizer_guard.clip(rg_buf[0] + half_error);
+            dst_pixel.0[1] = normalizer_guard.clip(rg_buf[1] + half_error);
+            dst_pixel.0[2] = normalizer_guard.clip(ba_buf[0] + half_error);
+            dst_pixel.0[3] = normalizer_guard.clip(ba_buf[1] + half_error);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - bounds.len() == dst_row.len()
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+#[target_feature(enable = "sse4.1")]
+unsafe fn horiz_convolution_one_row(
+    src_row: &[U16x4],
+    dst_row: &mut [U16x4],
+    coefficients_chunks: &[CoefficientsI32Chunk],
+    normalizer_guard: &optimisations::NormalizerGuard32,
+) {
+    let precision = normalizer_guard.precision();
+    let initial = _mm_set1_epi64x(1 << (precision - 1));
+
+    /*
+        |R    G    B    A   | |R    G    B    A   |
+        |0001 0203 0405 0607| |0809 1011 1213 1415|
+
+        Shuffle to extract RG components of first pixel as i64:
+        -1, -1, -1, -1, -1, -1, 3, 2, -1, -1, -1, -1, -1, -1, 1, 0
+
+        Shuffle to extract BA components of first pixel as i64:
+        -1, -1, -1, -1, -1, -1, 7, 6, -1, -1, -1, -1, -1, -1, 5, 4
+
+        Shuffle to extract RG components of second pixel as i64:
+        -1, -1, -1, -1, -1, -1, 11, 10, -1, -1, -1, -1, -1, -1, 9, 8
+
+        Shuffle to extract BA components of second pixel as i64:
+        -1, -1, -1, -1, -1, -1, 15, 14, -1, -1, -1, -1, -1, -1, 13, 12
+    */
+
+    let rg0_shuffle = _mm_set_epi8(-1, -1, -1, -1, -1, -1, 3, 2, -1, -1, -1, -1, -1, -1, 1, 0);
+    let ba0_shuffle = _mm_set_epi8(-1, -1, -1, -1, -1, -1, 7, 6, -1, -1, -1, -1, -1, -1, 5, 4);
+    let rg1_shuffle = _mm_set_epi8(-1, -1, -1, -1, -1, -1, 11, 10, -1, -1, -1, -1, -1, -1, 9, 8);
+    let ba1_shuffle = _mm_set_epi8(
+        -1, -1, -1, -1, -1, -1, 15, 14, -1, -1, -1, -1, -1, -1, 13, 12,
+    );
+    let mut rg_buf = [0i64; 2];
+    let mut ba_buf = [0i64; 2];
+
+    for (dst_x, &coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let mut x: usize = coeffs_chunk.start as usize;
+
+        let mut rg_sum = initial;
+        let mut ba_sum = initial;
+
+        let coeffs_by_2 = coeffs_chunk.values.chunks_exact(2);
+        let reminder = coeffs_by_2.remainder();
+
+        for k in coeffs_by_2 {
+            let coeff0_i64x2 = _mm_set1_epi64x(k[0] as i64);
+            let coeff1_i64x2 = _mm_set1_epi64x(k[1] as i64);
+
+            let source = simd_utils::loadu_si128(src_row, x);
+
+            let rg_i64x2 = _mm_shuffle_epi8(source, rg0_shuffle);
+            rg_sum = _mm_add_epi64(rg_sum, _mm_mul_epi32(rg_i64x2, coeff0_i64x2));
+
+            let ba_i64x2 = _mm_shuffle_epi8(source, ba0_shuffle);
+            ba_sum = _mm_add_epi64(ba_sum, _mm_mul_epi32(ba_i64x2, coeff0_i64x2));
+
+            let rg_i64x2 = _mm_shuffle_epi8(source, rg1_shuffle);
+            rg_sum = _mm_add_epi64(rg_sum, _mm_mul_epi32(rg_i64x2, coeff1_i64x2));
+
+            let ba_i64x2 = _mm_shuffle_epi8(source, ba1_shuffle);
+            ba_sum = _mm_add_epi64(ba_sum, _mm_mul_epi32(ba_i64x2, coeff1_i64x2));
+
+            x += 2;
+        }
+
+        if let Some(&k) = reminder.first() {
+            let coeff_i64x2 = _mm_set1_epi64x(k as i64);
+            let source = simd_utils::loadl_epi64(src_row, x);
+
+            let rg_i64x2 = _mm_shuffle_epi8(source, rg0_shuffle);
+            rg_sum = _mm_add_epi64(rg_sum, _mm_mul_epi32(rg_i64x2, coeff_i64x2));
+
+            let ba_i64x2 = _mm_shuffle_epi8(source, ba0_shuffle);
+            ba_sum = _mm_add_epi64(ba_sum, _mm_mul_epi32(ba_i64x2, coeff_i64x2));
+        }
+
+        _mm_storeu_si128(rg_buf.as_mut_ptr() as *mut __m128i, rg_sum);
+        _mm_storeu_si128(ba_buf.as_mut_ptr() as *mut __m128i, ba_sum);
+        let dst_pixel = dst_row.get_unchecked_mut(dst_x);
+        dst_pixel.0[0] = normalizer_guard.clip(rg_buf[0]);
+        dst_pixel.0[1] = normalizer_guard.clip(rg_buf[1]);
+        dst_pixel.0[2] = normalizer_guard.clip(ba_buf[0]);
+        dst_pixel.0[3] = normalizer_guard.clip(ba_buf[1]);
+    }
+}
@@ -10,20 +10,17 @@ pub(crate) fn horiz_convolution(
     src_image: TypedImageView<U8>,
     mut dst_image: TypedImageViewMut<U8>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
-
-    let normalizer_guard = optimisations::NormalizerGuard16::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let normalizer_guard = coeffs.normalizer16();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
     let dst_height = dst_image.height().get();
 
     let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
     let dst_iter = dst_image.iter_4_rows_mut();
     for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
         unsafe {
-            horiz_convolution_8u4x(src_rows, dst_rows, &coefficients_chunks, &normalizer_guard);
+            horiz_convolution_8u4x(src_rows, dst_rows, &coefficients_chunks, normalizer_guard);
         }
     }
 
@@ -34,7 +31,7 @@ pub(crate) fn horiz_convolution(
                 src_image.get_row(yy + offset).unwrap(),
                 dst_image.get_row_mut(yy).unwrap(),
                 &coefficients_chunks,
-                &normalizer_guard,
+                normalizer_guard,
             );
         }
         yy += 1;
@@ -187,7 +184,7 @@ unsafe fn hsum_epi32_avx(x: __m128i) -> i32 {
     // 3-operand non-destructive AVX lets us save a byte without needing a movdqa
     let hi64 = _mm_unpackhi_epi64(x, x);
     let sum64 = _mm_add_epi32(hi64, x);
-    const I: i32 = ((2 << 6) | (3 << 4) | 1) as i32;
+    const I: i32 = (2 << 6) | (3 << 4) | 1;
     let hi32 = _mm_shuffle_epi32::<I>(sum64); // Swap the low two elements
     let sum32 = _mm_add_epi32(sum64, hi32);
     _mm_cvtsi128_si32(sum32) // movd
@@ -0,0 +1,237 @@
+use std::arch::x86_64::*;
+
+use crate::convolution::{optimisations, Coefficients};
+use crate::image_view::{FourRows, FourRowsMut, TypedImageView, TypedImageViewMut};
+use crate::pixels::{Pixel, U8x2};
+use crate::simd_utils;
+
+#[inline]
+pub(crate) fn horiz_convolution(
+    src_image: TypedImageView<U8x2>,
+    mut dst_image: TypedImageViewMut<U8x2>,
+    offset: u32,
+    coeffs: &Coefficients,
+) {
+    let normalizer_guard = coeffs.normalizer16();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
+    let dst_height = dst_image.height().get();
+
+    let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
+    let dst_iter = dst_image.iter_4_rows_mut();
+    for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
+        unsafe {
+            horiz_convolution_four_rows(src_rows, dst_rows, &coefficients_chunks, normalizer_guard);
+        }
+    }
+
+    let mut yy = dst_height - dst_height % 4;
+    while yy < dst_height {
+        unsafe {
+            horiz_convolution_one_row(
+                src_image.get_row(yy + offset).unwrap(),
+                dst_image.get_row_mut(yy).unwrap(),
+                &coefficients_chunks,
+                normalizer_guard,
+            );
+        }
+        yy += 1;
+    }
+}
+
+/*
+    |L0 A0| |L1 A1| |L2 A2| |L3 A3| |L4 A4| |L5 A5| |L6 A6| |L7 A7|
+    |00 01| |02 03| |04 05| |06 07| |08 09| |10 11| |12 13| |14 15|
+
+    Shuffle components of pixels 0-3 into i16 with order: L0 L1 A0 A1 L2 L3 A2 A3
+    -1, 7, -1, 5, -1, 6, -1, 4, -1, 3, -1, 1, -1, 2, -1, 0
+
+    Shuffle components of pixels 4-7 into i16 with order: L4 L5 A4 A5 L6 L7 A6 A7
+    -1, 15, -1, 13, -1, 14, -1, 12, -1, 11, -1, 9, -1, 10, -1, 8
+
+    After multiplying and adding (madd) the shuffled components by coefficients
+    with order k0 k1 k0 k1 k2 k3 k2 k3, we get i32 sums with order L A L A.
+*/
+
+#[inline(always)]
+unsafe fn pixels_lo_shuffle() -> __m128i {
+    _mm_set_epi8(-1, 7, -1, 5, -1, 6, -1, 4, -1, 3, -1, 1, -1, 2, -1, 0)
+}
+
+#[inline(always)]
+unsafe fn pixels_hi_shuffle() -> __m128i {
+    _mm_set_epi8(-1, 15, -1, 13, -1, 14, -1, 12, -1, 11, -1, 9, -1, 10, -1, 8)
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - length of all rows in src_rows must be equal
+/// - length of all rows in dst_rows must be equal
+/// - coefficients_chunks.len() == dst_rows.0.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.0.len()
+/// - precision <= MAX_COEFS_PRECISION
+#[target_feature(enable = "sse4.1")]
+unsafe fn horiz_convolution_four_rows(
+    src_rows: FourRows<U8x2>,
+    dst_rows: FourRowsMut<U8x2>,
+    coefficients_chunks: &[optimisations::CoefficientsI16Chunk],
+    normalizer_guard: &optimisations::NormalizerGuard16,
+) {
+    let s_rows = [src_rows.0, src_rows.1, src_rows.2, src_rows.3];
+    let d_rows = [dst_rows.0, dst_rows.1, dst_rows.2, dst_rows.3];
+    let half_error = 1 << (normalizer_guard.precision() - 1);
+    let initial = _mm_set_epi32(0, 0, half_error, half_error);
+    let lo_shuffle = pixels_lo_shuffle();
+    let hi_shuffle = pixels_hi_shuffle();
+
+    for (dst_x, coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let mut x: usize = coeffs_chunk.start as usize;
+        let mut sss = [initial; 4];
+
+        let coeffs_by_8 = coeffs_chunk.values.chunks_exact(8);
+        let reminder8 = coeffs_by_8.remainder();
+
+        for k in coeffs_by_8 {
+            let coeffs_i16x8 = simd_utils::loadu_si128(k, 0);
+            // [16] k1 k0 k1 k0 k3 k2 k3 k2
+            let mmk_lo = _mm_shuffle_epi32::<0b01_01_00_00>(coeffs_i16x8);
+            // [16] k5 k4 k5 k4 k7 k6 k7 k6
+            let mmk_hi = _mm_shuffle_epi32::<0b11_11_10_10>(coeffs_i16x8);
+
+            for i in 0..4 {
+                let source = simd_utils::loadu_si128(s_rows[i], x);
+                let pix = _mm_shuffle_epi8(source, lo_shuffle);
+                sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk_lo));
+                let pix = _mm_shuffle_epi8(source, hi_shuffle);
+                sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk_hi));
+            }
+            x += 8;
+        }
+
+        let mut coeffs_by_4 = reminder8.chunks_exact(4);
+        let reminder4 = coeffs_by_4.remainder();
+
+        if let Some(k) = coeffs_by_4.next() {
+            let coeffs_i16x4 = simd_utils::loadl_epi64(k, 0);
+            let mmk = _mm_shuffle_epi32::<0b01_01_00_00>(coeffs_i16x4);
+
+            for i in 0..4 {
+                let source = simd_utils::loadl_epi64(s_rows[i], x);
+                let pix = _mm_shuffle_epi8(source, lo_shuffle);
+                sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk));
+            }
+            x += 4;
+        }
+
+        let mut coeffs_by_2 = reminder4.chunks_exact(2);
+        let reminder2 = coeffs_by_2.remainder();
+
+        if let Some(k) = coeffs_by_2.next() {
+            // [16] k1 k0 k1 k0 k1 k0 k1 k0
+            let mmk = simd_utils::ptr_i16_to_set1_epi32(k, 0);
+
+            for i in 0..4 {
+                let source =
+                    simd_utils::mm_cvtsi32_si128_from_u8(U8x2::components(s_rows[i]), x * 2);
+                let pix = _mm_shuffle_epi8(source, lo_shuffle);
+                sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk));
+            }
+            x += 2;
+        }
+
+        if let Some(&k) = reminder2.first() {
+            let mmk = _mm_set1_epi32(k as i32);
+
+            for i in 0..4 {
+                let [l, a] = s_rows[i].get_unchecked(x).0.to_le_bytes();
+                let pix = _mm_set_epi32(0, 0, a as i32, l as i32);
+                sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk));
+            }
+        }
+
+        for i in 0..4 {
+            let sum = _mm_add_epi32(sss[i], _mm_srli_si128::<8>(sss[i]));
+            let l = normalizer_guard.clip(_mm_extract_epi32::<0>(sum));
+            let a = normalizer_guard.clip(_mm_extract_epi32::<1>(sum));
+            d_rows[i].get_unchecked_mut(dst_x).0 = u16::from_le_bytes([l, a]);
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - bounds.len() == dst_row.len()
+/// - coefficients_chunks.len() == dst_row.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.len()
+/// - precision <= MAX_COEFS_PRECISION
+#[target_feature(enable = "sse4.1")]
+unsafe fn horiz_convolution_one_row(
+    src_row: &[U8x2],
+    dst_row: &mut [U8x2],
+    coefficients_chunks: &[optimisations::CoefficientsI16Chunk],
+    normalizer_guard: &optimisations::NormalizerGuard16,
+) {
+    let half_error = 1 << (normalizer_guard.precision() - 1);
+    let initial = _mm_set_epi32(0, 0, half_error, half_error);
+    let lo_shuffle = pixels_lo_shuffle();
+    let hi_shuffle = pixels_hi_shuffle();
+    let src_components = U8x2::components(src_row);
+
+    for (dst_x, &coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let mut x: usize = coeffs_chunk.start as usize;
+        let mut sss = initial;
+
+        let coeffs_by_8 = coeffs_chunk.values.chunks_exact(8);
+        let reminder8 = coeffs_by_8.remainder();
+
+        for k in coeffs_by_8 {
+            let coeffs_i16x8 = simd_utils::loadu_si128(k, 0);
+            let mmk_lo = _mm_shuffle_epi32::<0b01_01_00_00>(coeffs_i16x8);
+            let mmk_hi = _mm_shuffle_epi32::<0b11_11_10_10>(coeffs_i16x8);
+
+            let source = simd_utils::loadu_si128(src_row, x);
+            let pix = _mm_shuffle_epi8(source, lo_shuffle);
+            sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk_lo));
+            let pix = _mm_shuffle_epi8(source, hi_shuffle);
+            sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk_hi));
+
+            x += 8;
+        }
+
+        let mut coeffs_by_4 = reminder8.chunks_exact(4);
+        let reminder4 = coeffs_by_4.remainder();
+
+        if let Some(k) = coeffs_by_4.next() {
+            let coeffs_i16x4 = simd_utils::loadl_epi64(k, 0);
+            let mmk = _mm_shuffle_epi32::<0b01_01_00_00>(coeffs_i16x4);
+
+            let source = simd_utils::loadl_epi64(src_row, x);
+            let pix = _mm_shuffle_epi8(source, lo_shuffle);
+            sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk));
+
+            x += 4;
+        }
+
+        let mut coeffs_by_2 = reminder4.chunks_exact(2);
+        let reminder2 = coeffs_by_2.remainder();
+
+        if let Some(k) = coeffs_by_2.next() {
+            let mmk = simd_utils::ptr_i16_to_set1_epi32(k, 0);
+
+            let source = simd_utils::mm_cvtsi32_si128_from_u8(src_components, x * 2);
+            let pix = _mm_shuffle_epi8(source, lo_shuffle);
+            sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk));
+
+            x += 2;
+        }
+
+        if let Some(&k) = reminder2.first() {
+            let mmk = _mm_set1_epi32(k as i32);
+            let [l, a] = src_row.get_unchecked(x).0.to_le_bytes();
+            let pix = _mm_set_epi32(0, 0, a as i32, l as i32);
+            sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk));
+        }
+
+        let sum = _mm_add_epi32(sss, _mm_srli_si128::<8>(sss));
+        let l = normalizer_guard.clip(_mm_extract_epi32::<0>(sum));
+        let a = normalizer_guard.clip(_mm_extract_epi32::<1>(sum));
+        dst_row.get_unchecked_mut(dst_x).0 = u16::from_le_bytes([l, a]);
+    }
+}
@@ -1,5 +1,4 @@
 use std::arch::x86_64::*;
-use std::intrinsics::transmute;
 
 use crate::convolution::{optimisations, Coefficients};
 use crate::image_view::{FourRows, FourRowsMut, TypedImageView, TypedImageViewMut};
@@ -11,14 +10,11 @@ pub(crate) fn horiz_convolution(
     src_image: TypedImageView<U8x3>,
     mut dst_image: TypedImageViewMut<U8x3>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
-
-    let normalizer_guard = optimisations::NormalizerGuard16::new(values);
+    let normalizer_guard = coeffs.normalizer16();
     let precision = normalizer_guard.precision();
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
     let dst_height = dst_image.height().get();
 
     let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
@@ -197,20 +193,16 @@ unsafe fn horiz_convolution_8u4x(
         sss0 = _mm256_packus_epi16(sss0, zero);
         sss1 = _mm256_packus_epi16(sss1, zero);
 
-        let pixel: u32 = transmute(_mm_cvtsi128_si32(_mm256_extracti128_si256::<0>(sss0)));
-        let bytes = pixel.to_le_bytes();
+        let bytes = _mm_cvtsi128_si32(_mm256_extracti128_si256::<0>(sss0)).to_le_bytes();
         d_row0.get_unchecked_mut(dst_x).0 = [bytes[0], bytes[1], bytes[2]];
 
-        let pixel: u32 = transmute(_mm_cvtsi128_si32(_mm256_extracti128_si256::<1>(sss0)));
-        let bytes = pixel.to_le_bytes();
+        let bytes = _mm_cvtsi128_si32(_mm256_extracti128_si256::<1>(sss0)).to_le_bytes();
         d_row1.get_unchecked_mut(dst_x).0 = [bytes[0], bytes[1], bytes[2]];
 
-        let pixel: u32 = transmute(_mm_cvtsi128_si32(_mm256_extracti128_si256::<0>(sss1)));
-        let bytes = pixel.to_le_bytes();
+        let bytes = _mm_cvtsi128_si32(_mm256_extracti128_si256::<0>(sss1)).to_le_bytes();
         d_row2.get_unchecked_mut(dst_x).0 = [bytes[0], bytes[1], bytes[2]];
 
-        let pixel: u32 = transmute(_mm_cvtsi128_si32(_mm256_extracti128_si256::<1>(sss1)));
-        let bytes = pixel.to_le_bytes();
+        let bytes = _mm_cvtsi128_si32(_mm256_extracti128_si256::<1>(sss1)).to_le_bytes();
         d_row3.get_unchecked_mut(dst_x).0 = [bytes[0], bytes[1], bytes[2]];
     }
 }
@@ -376,8 +368,7 @@ unsafe fn horiz_convolution_8u(
         constify_imm8!(precision, call);
 
         sss = _mm_packs_epi32(sss, sss);
-        let pixel: u32 = transmute(_mm_cvtsi128_si32(_mm_packus_epi16(sss, sss)));
-        let bytes = pixel.to_le_bytes();
+        let bytes = _mm_cvtsi128_si32(_mm_packus_epi16(sss, sss)).to_le_bytes();
         dst_row.get_unchecked_mut(dst_x).0 = [bytes[0], bytes[1], bytes[2]];
     }
 }
@@ -1,4 +1,4 @@
-use crate::convolution::{optimisations, Coefficients};
+use crate::convolution::Coefficients;
 use crate::image_view::{TypedImageView, TypedImageViewMut};
 use crate::pixels::U8x3;
 
@@ -7,13 +7,11 @@ pub(crate) fn horiz_convolution(
     src_image: TypedImageView<U8x3>,
     mut dst_image: TypedImageViewMut<U8x3>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
-    let (values, window_size, bounds) = (coeffs.values, coeffs.window_size, coeffs.bounds);
-
-    let normalizer_guard = optimisations::NormalizerGuard16::new(values);
+    let normalizer_guard = coeffs.normalizer16();
     let precision = normalizer_guard.precision();
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds);
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
     let initial = 1 << (precision - 1);
 
     let src_rows = src_image.iter_rows(offset);
@@ -1,15 +1,245 @@
-use crate::convolution::Coefficients;
-use crate::image_view::{TypedImageView, TypedImageViewMut};
-use crate::pixels::U8x3;
+use std::arch::x86_64::*;
 
-use super::native;
+use crate::convolution::{optimisations, Coefficients};
+use crate::image_view::{FourRows, FourRowsMut, TypedImageView, TypedImageViewMut};
+use crate::pixels::U8x3;
+use crate::simd_utils;
 
-#[target_feature(enable = "sse4.1")]
-pub(crate) unsafe fn horiz_convolution(
+#[inline]
+pub(crate) fn horiz_convolution(
     src_image: TypedImageView<U8x3>,
-    dst_image: TypedImageViewMut<U8x3>,
+    mut dst_image: TypedImageViewMut<U8x3>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
+) {
+    let normalizer_guard = coeffs.normalizer16();
+    let precision = normalizer_guard.precision();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
+    let dst_height = dst_image.height().get();
+
+    let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
+    let dst_iter = dst_image.iter_4_rows_mut();
+    for (src_rows, dst_rows) in src_iter.zip(dst_iter) {
+        unsafe {
+            horiz_convolution_8u4x(src_rows, dst_rows, &coefficients_chunks, precision);
+        }
+    }
+
+    let mut yy = dst_height - dst_height % 4;
+    while yy < dst_height {
+        unsafe {
+            horiz_convolution_8u(
+                src_image.get_row(yy + offset).unwrap(),
+                dst_image.get_row_mut(yy).unwrap(),
+                &coefficients_chunks,
+                precision,
+            );
+        }
+        yy += 1;
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - length of all rows in src_rows must be equal
+/// - length of all rows in dst_rows must be equal
+/// - coefficients_chunks.len() == dst_rows.0.len()
+/// - max(chunk.start + chunk.values.len() for chunk in coefficients_chunks) <= src_row.0.len()
+/// - precision <= MAX_COEFS_PRECISION
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn horiz_convolution_8u4x(
+    src_rows: FourRows<U8x3>,
+    dst_rows: FourRowsMut<U8x3>,
+    coefficients_chunks: &[optimisations::CoefficientsI16Chunk],
+    precision: u8,
+) {
+    let s_rows = [src_rows.0, src_rows.1, src_rows.2, src_rows.3];
+    let d_rows = [dst_rows.0, dst_rows.1, dst_rows.2, dst_rows.3];
+    let initial = _mm_set1_epi32(1 << (precision - 1));
+    let src_width = s_rows[0].len();
+
+    /*
+        |R  G  B | |R  G  B | |R  G  B | |R  G  B | |R  G  B | |R |
+        |00 01 02| |03 04 05| |06 07 08| |09 10 11| |12 13 14| |15|
+
+        Ignore 12-15 bytes in 16-bytes register and
+        shuffle other components with converting from u8 into i16:
+
+        x: |-1 -1| |-1 -1|
+        B: |-1 05| |-1 02|
+        G: |-1 04| |-1 01|
+        R: |-1 03| |-1 00|
+    */
+    let sh1 = _mm_set_epi8(-1, -1, -1, -1, -1, 5, -1, 2, -1, 4, -1, 1, -1, 3, -1, 0);
+    /*
+        x: |-1 -1| |-1 -1|
+        B: |-1 11| |-1 08|
+        G: |-1 10| |-1 07|
+        R: |-1 09| |-1 06|
+    */
+    let sh2 = _mm_set_epi8(-1, -1, -1, -1, -1, 11, -1, 8, -1, 10, -1, 7, -1, 9, -1, 6);
+
+    for (dst_x, coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let x_start = coeffs_chunk.start as usize;
+        let mut x = x_start;
+
+        let mut sss = [initial; 4];
+        let mut coeffs = coeffs_chunk.values;
+
+        // (16 bytes) / (3 bytes per pixel) = 5 whole pixels + 1 byte
+        let max_x = src_width.saturating_sub(5);
+        if x < max_x {
+            let coeffs_by_4 = coeffs.chunks_exact(4);
+
+            for k in coeffs_by_4 {
+                let mmk0 = simd_utils::ptr_i16_to_set1_epi32(k, 0);
+                let mmk1 = simd_utils::ptr_i16_to_set1_epi32(k, 2);
+
+                for i in 0..4 {
+                    let source = simd_utils::loadu_si128(s_rows[i], x);
+                    let pix = _mm_shuffle_epi8(source, sh1);
+                    sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk0));
+                    let pix = _mm_shuffle_epi8(source, sh2);
+                    sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk1));
+                }
+
+                x += 4;
+                if x >= max_x {
+                    break;
+                }
+            }
+        }
+
+        // (8 bytes) / (3 bytes per pixel) = 2 whole pixels + 2 bytes
+        let max_x = src_width.saturating_sub(2);
+        if x < max_x {
+            let coeffs_by_2 = coeffs[x - x_start..].chunks_exact(2);
+
+            for k in coeffs_by_2 {
+                let mmk = simd_utils::ptr_i16_to_set1_epi32(k, 0);
+
+                for i in 0..4 {
+                    let source = simd_utils::loadl_epi64(s_rows[i], x);
+                    let pix = _mm_shuffle_epi8(source, sh1);
+                    sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk));
+                }
+
+                x += 2;
+                if x >= max_x {
+                    break;
+                }
+            }
+        }
+
+        coeffs = coeffs.split_at(x - x_start).1;
+        for &k in coeffs {
+            // [16] xx k0 xx k0 xx k0 xx k0
+            let mmk = _mm_set1_epi32(k as i32);
+            for i in 0..4 {
+                // [16] xx a0 xx b0 xx g0 xx r0
+                let pix = simd_utils::mm_cvtepu8_epi32_u8x3(s_rows[i], x);
+                sss[i] = _mm_add_epi32(sss[i], _mm_madd_epi16(pix, mmk));
+            }
+            x += 1;
+        }
+
+        for i in 0..4 {
+            let mut s = sss[i];
+            macro_rules! call {
+                ($imm8:expr) => {{
+                    s = _mm_srai_epi32::<$imm8>(s);
+                }};
+            }
+            constify_imm8!(precision, call);
+
+            s = _mm_packs_epi32(s, s);
+            let bytes = _mm_cvtsi128_si32(_mm_packus_epi16(s, s)).to_le_bytes();
+            d_rows[i].get_unchecked_mut(dst_x).0 = [bytes[0], bytes[1], bytes[2]];
+        }
+    }
+}
+
+/// For safety, it is necessary to ensure the following conditions:
+/// - bounds.len() == dst_row.len()
+/// - coeffs.len() == dst_rows.0.len() * window_size
+/// - max(bound.start + bound.size for bound in bounds) <= src_row.len()
+/// - precision <= MAX_COEFS_PRECISION
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn horiz_convolution_8u(
+    src_row: &[U8x3],
+    dst_row: &mut [U8x3],
+    coefficients_chunks: &[optimisations::CoefficientsI16Chunk],
+    precision: u8,
 ) {
-    native::horiz_convolution(src_image, dst_image, offset, coeffs);
+    let initial = _mm_set1_epi32(1 << (precision - 1));
+    let sh1 = _mm_set_epi8(-1, -1, -1, -1, -1, 5, -1, 2, -1, 4, -1, 1, -1, 3, -1, 0);
+    let sh2 = _mm_set_epi8(-1, -1, -1, -1, -1, 11, -1, 8, -1, 10, -1, 7, -1, 9, -1, 6);
+    let src_width = src_row.len();
+
+    for (dst_x, &coeffs_chunk) in coefficients_chunks.iter().enumerate() {
+        let x_start = coeffs_chunk.start as usize;
+        let mut x = x_start;
+        let mut sss = initial;
+        let mut coeffs = coeffs_chunk.values;
+
+        // (16 bytes) / (3 bytes per pixel) = 5 whole pixels + 1 byte
+        let max_x = src_width.saturating_sub(5);
+        if x < max_x {
+            let coeffs_by_4 = coeffs.chunks_exact(4);
+
+            for k in coeffs_by_4 {
+                let mmk0 = simd_utils::ptr_i16_to_set1_epi32(k, 0);
+                let mmk1 = simd_utils::ptr_i16_to_set1_epi32(k, 2);
+
+                let source = simd_utils::loadu_si128(src_row, x);
+                let pix = _mm_shuffle_epi8(source, sh1);
+                sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk0));
+                let pix = _mm_shuffle_epi8(source, sh2);
+                sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk1));
+
+                x += 4;
+                if x >= max_x {
+                    break;
+                }
+            }
+        }
+
+        // (8 bytes) / (3 bytes per pixel) = 2 whole pixels + 2 bytes
+        let max_x = src_width.saturating_sub(2);
+        if x < max_x {
+            let coeffs_by_2 = coeffs[x - x_start..].chunks_exact(2);
+
+            for k in coeffs_by_2 {
+                let mmk = simd_utils::ptr_i16_to_set1_epi32(k, 0);
+                let source = simd_utils::loadl_epi64(src_row, x);
+                let pix = _mm_shuffle_epi8(source, sh1);
+                sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk));
+
+                x += 2;
+                if x >= max_x {
+                    break;
+                }
+            }
+        }
+
+        coeffs = coeffs.split_at(x - x_start).1;
+        for &k in coeffs {
+            let pix = simd_utils::mm_cvtepu8_epi32_u8x3(src_row, x);
+            let mmk = _mm_set1_epi32(k as i32);
+            sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk));
+            x += 1;
+        }
+
+        macro_rules! call {
+            ($imm8:expr) => {{
+                sss = _mm_srai_epi32::<$imm8>(sss);
+            }};
+        }
+        constify_imm8!(precision, call);
+
+        sss = _mm_packs_epi32(sss, sss);
+        let bytes = _mm_cvtsi128_si32(_mm_packus_epi16(sss, sss)).to_le_bytes();
+        dst_row.get_unchecked_mut(dst_x).0 = [bytes[0], bytes[1], bytes[2]];
+    }
 }
@@ -1,5 +1,4 @@
 use std::arch::x86_64::*;
-use std::intrinsics::transmute;
 
 use crate::convolution::{optimisations, Coefficients};
 use crate::image_view::{FourRows, FourRowsMut, TypedImageView, TypedImageViewMut};
@@ -14,14 +13,11 @@ pub(crate) fn horiz_convolution(
     src_image: TypedImageView<U8x4>,
     mut dst_image: TypedImageViewMut<U8x4>,
     offset: u32,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
-
-    let normalizer_guard = optimisations::NormalizerGuard16::new(values);
+    let normalizer_guard = coeffs.normalizer16();
     let precision = normalizer_guard.precision();
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
     let dst_height = dst_image.height().get();
 
     let src_iter = src_image.iter_4_rows(offset, dst_height + offset);
@@ -139,7 +135,7 @@ unsafe fn horiz_convolution_8u4x(
             x += 2;
         }
 
-        if let Some(&k) = reminder2.get(0) {
+        if let Some(&k) = reminder2.first() {
             // [16] xx k0 xx k0 xx k0 xx k0
             let mmk = _mm_set1_epi32(k as i32);
             // [16] xx a0 xx b0 xx g0 xx r0
@@ -171,13 +167,13 @@ unsafe fn horiz_convolution_8u4x(
         sss2 = _mm_packs_epi32(sss2, sss2);
         sss3 = _mm_packs_epi32(sss3, sss3);
         *d_row0.get_unchecked_mut(dst_x) =
-            transmute(_mm_cvtsi128_si32(_mm_packus_epi16(sss0, sss0)));
+            U8x4(_mm_cvtsi128_si32(_mm_packus_epi16(sss0, sss0)) as u32);
         *d_row1.get_unchecked_mut(dst_x) =
-            transmute(_mm_cvtsi128_si32(_mm_packus_epi16(sss1, sss1)));
+            U8x4(_mm_cvtsi128_si32(_mm_packus_epi16(sss1, sss1)) as u32);
         *d_row2.get_unchecked_mut(dst_x) =
-            transmute(_mm_cvtsi128_si32(_mm_packus_epi16(sss2, sss2)));
+            U8x4(_mm_cvtsi128_si32(_mm_packus_epi16(sss2, sss2)) as u32);
         *d_row3.get_unchecked_mut(dst_x) =
-            transmute(_mm_cvtsi128_si32(_mm_packus_epi16(sss3, sss3)));
+            U8x4(_mm_cvtsi128_si32(_mm_packus_epi16(sss3, sss3)) as u32);
     }
 }
 
@@ -267,7 +263,7 @@ unsafe fn horiz_convolution_8u(
             x += 2
         }
 
-        if let Some(&k) = reminder2.get(0) {
+        if let Some(&k) = reminder2.first() {
             let pix = simd_utils::mm_cvtepu8_epi32(src_row, x);
             let mmk = _mm_set1_epi32(k as i32);
             sss = _mm_add_epi32(sss, _mm_madd_epi16(pix, mmk));
@@ -282,6 +278,6 @@ unsafe fn horiz_convolution_8u(
 
         sss = _mm_packs_epi32(sss, sss);
         *dst_row.get_unchecked_mut(dst_x) =
-            transmute(_mm_cvtsi128_si32(_mm_packus_epi16(sss, sss)));
+            U8x4(_mm_cvtsi128_si32(_mm_packus_epi16(sss, sss)) as u32);
     }
 }
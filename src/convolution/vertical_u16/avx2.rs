@@ -8,21 +8,19 @@ use crate::simd_utils;
 pub(crate) fn vert_convolution<T>(
     src_image: TypedImageView<T>,
     mut dst_image: TypedImageViewMut<T>,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) where
     T: Pixel<Component = u16>,
 {
     // native::vert_convolution(src_image, dst_image, coeffs);
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
 
-    let normalizer_guard = optimisations::NormalizerGuard32::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let normalizer_guard = coeffs.normalizer32();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
 
     let dst_rows = dst_image.iter_rows_mut();
     for (dst_row, coeffs_chunk) in dst_rows.zip(coefficients_chunks) {
         unsafe {
-            vert_convolution_into_one_row_u16(&src_image, dst_row, coeffs_chunk, &normalizer_guard);
+            vert_convolution_into_one_row_u16(&src_image, dst_row, coeffs_chunk, normalizer_guard);
         }
     }
 }
@@ -105,8 +103,8 @@ pub(crate) unsafe fn vert_convolution_into_one_row_u16<T>(
             }
         }
 
-        for i in 0..4 {
-            _mm256_storeu_si256((&mut comp_buf).as_mut_ptr() as *mut __m256i, sum[i]);
+        for (i, &s) in sum.iter().enumerate() {
+            _mm256_storeu_si256(comp_buf.as_mut_ptr() as *mut __m256i, s);
             let component = dst_components.get_unchecked_mut(xx + i * 2);
             *component = normalizer_guard.clip(comp_buf[0]);
             let component = dst_components.get_unchecked_mut(xx + i * 2 + 1);
@@ -138,8 +136,8 @@ pub(crate) unsafe fn vert_convolution_into_one_row_u16<T>(
             }
         }
 
-        for i in 0..4 {
-            _mm256_storeu_si256((&mut comp_buf).as_mut_ptr() as *mut __m256i, sum[i]);
+        for (i, &s) in sum.iter().enumerate() {
+            _mm256_storeu_si256(comp_buf.as_mut_ptr() as *mut __m256i, s);
             let component = buf.get_unchecked_mut(i * 2);
             *component = normalizer_guard.clip(comp_buf[0]);
             let component = buf.get_unchecked_mut(i * 2 + 1);
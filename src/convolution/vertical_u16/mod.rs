@@ -12,7 +12,7 @@ pub(crate) mod sse4;
 pub(crate) fn vert_convolution_u16<T: Pixel<Component = u16>>(
     src_image: TypedImageView<T>,
     dst_image: TypedImageViewMut<T>,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
     cpu_extensions: CpuExtensions,
 ) {
     match cpu_extensions {
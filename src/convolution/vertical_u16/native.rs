@@ -6,15 +6,14 @@ use crate::pixels::Pixel;
 pub(crate) fn vert_convolution<T: Pixel<Component = u16>>(
     src_image: TypedImageView<T>,
     mut dst_image: TypedImageViewMut<T>,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
     // Check safety conditions
     debug_assert_eq!(src_image.width(), dst_image.width());
     debug_assert_eq!(coeffs.bounds.len(), dst_image.height().get() as usize);
 
-    let (values, window_size, bounds) = (coeffs.values, coeffs.window_size, coeffs.bounds);
-    let normalizer_guard = optimisations::NormalizerGuard32::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds);
+    let normalizer_guard = coeffs.normalizer32();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
     let precision = normalizer_guard.precision();
     let initial: i64 = 1 << (precision - 1);
 
@@ -27,7 +26,7 @@ pub(crate) fn vert_convolution<T: Pixel<Component = u16>>(
 
         convolution_by_u16(
             &src_image,
-            &normalizer_guard,
+            normalizer_guard,
             initial,
             dst_components,
             0,
@@ -52,7 +51,7 @@ pub(crate) fn convolution_by_u16<T: Pixel<Component = u16>>(
         let src_rows = src_image.iter_rows(first_y_src);
         for (&k, src_row) in ks.iter().zip(src_rows) {
             let src_ptr = src_row.as_ptr() as *const u16;
-            let src_component = unsafe { *src_ptr.add(x_src as usize) };
+            let src_component = unsafe { *src_ptr.add(x_src) };
             ss += src_component as i64 * (k as i64);
         }
         *dst_component = normalizer_guard.clip(ss);
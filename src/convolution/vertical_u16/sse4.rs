@@ -10,19 +10,17 @@ use crate::simd_utils;
 pub(crate) fn vert_convolution<T: Pixel<Component = u16>>(
     src_image: TypedImageView<T>,
     mut dst_image: TypedImageViewMut<T>,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
     // native::vert_convolution(src_image, dst_image, coeffs);
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
 
-    let normalizer_guard = optimisations::NormalizerGuard32::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let normalizer_guard = coeffs.normalizer32();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
 
     let dst_rows = dst_image.iter_rows_mut();
     for (dst_row, coeffs_chunk) in dst_rows.zip(coefficients_chunks) {
         unsafe {
-            vert_convolution_into_one_row_u16(&src_image, dst_row, coeffs_chunk, &normalizer_guard);
+            vert_convolution_into_one_row_u16(&src_image, dst_row, coeffs_chunk, normalizer_guard);
         }
     }
 }
@@ -40,7 +38,7 @@ unsafe fn vert_convolution_into_one_row_u16<T: Pixel<Component = u16>>(
     let coeffs = coeffs_chunk.values;
     let max_y = y_start + coeffs.len() as u32;
     let dst_components = T::components_mut(dst_row);
-    let mut dst_ptr_u16 = dst_components.as_mut_ptr() as *mut u16;
+    let mut dst_ptr_u16 = dst_components.as_mut_ptr();
 
     /*
         |0    1    2    3    4    5    6    7   |
@@ -88,32 +86,32 @@ unsafe fn vert_convolution_into_one_row_u16<T: Pixel<Component = u16>>(
                 let coeff_i64x2 = _mm_set1_epi64x(two_coeffs[r] as i64);
                 for x in 0..2 {
                     let source = simd_utils::loadu_si128(s_rows[r], xx + x * 8);
-                    for i in 0..4 {
-                        let c_i64x2 = _mm_shuffle_epi8(source, c_shuffles[i]);
-                        sums[i][x] = _mm_add_epi64(sums[i][x], _mm_mul_epi32(c_i64x2, coeff_i64x2));
+                    for (sum, &c_shuffle) in sums.iter_mut().zip(&c_shuffles) {
+                        let c_i64x2 = _mm_shuffle_epi8(source, c_shuffle);
+                        sum[x] = _mm_add_epi64(sum[x], _mm_mul_epi32(c_i64x2, coeff_i64x2));
                     }
                 }
             }
             y += 2;
         }
 
-        if let Some(&k) = coeffs_reminder.get(0) {
+        if let Some(&k) = coeffs_reminder.first() {
             let s_row = src_img.get_row(y_start + y).unwrap();
             let components = T::components(s_row);
             let coeff_i64x2 = _mm_set1_epi64x(k as i64);
 
             for x in 0..2 {
                 let source = simd_utils::loadu_si128(components, xx + x * 8);
-                for i in 0..4 {
-                    let c_i64x2 = _mm_shuffle_epi8(source, c_shuffles[i]);
-                    sums[i][x] = _mm_add_epi64(sums[i][x], _mm_mul_epi32(c_i64x2, coeff_i64x2));
+                for (sum, &c_shuffle) in sums.iter_mut().zip(&c_shuffles) {
+                    let c_i64x2 = _mm_shuffle_epi8(source, c_shuffle);
+                    sum[x] = _mm_add_epi64(sum[x], _mm_mul_epi32(c_i64x2, coeff_i64x2));
                 }
             }
         }
 
         for x in 0..2 {
             for sum in sums {
-                _mm_storeu_si128((&mut c_buf).as_mut_ptr() as *mut __m128i, sum[x]);
+                _mm_storeu_si128(c_buf.as_mut_ptr() as *mut __m128i, sum[x]);
                 *dst_ptr_u16 = normalizer_guard.clip(c_buf[0]);
                 dst_ptr_u16 = dst_ptr_u16.add(1);
                 *dst_ptr_u16 = normalizer_guard.clip(c_buf[1]);
@@ -149,7 +147,7 @@ unsafe fn vert_convolution_into_one_row_u16<T: Pixel<Component = u16>>(
             y += 2;
         }
 
-        if let Some(&k) = coeffs_reminder.get(0) {
+        if let Some(&k) = coeffs_reminder.first() {
             let s_row = src_img.get_row(y_start + y).unwrap();
             let components = T::components(s_row);
             let coeff_i64x2 = _mm_set1_epi64x(k as i64);
@@ -165,7 +163,7 @@ unsafe fn vert_convolution_into_one_row_u16<T: Pixel<Component = u16>>(
             // sums[i] = _mm_and_si128(sums[i] , mask);
             // sums[i] = _mm_srl_epi64(sums[i] , precision_i64);
             // _mm_packus_epi32(sums[i] , sums[i] );
-            _mm_storeu_si128((&mut c_buf).as_mut_ptr() as *mut __m128i, sum);
+            _mm_storeu_si128(c_buf.as_mut_ptr() as *mut __m128i, sum);
             *dst_ptr_u16 = normalizer_guard.clip(c_buf[0]);
             dst_ptr_u16 = dst_ptr_u16.add(1);
             *dst_ptr_u16 = normalizer_guard.clip(c_buf[1]);
@@ -199,7 +197,7 @@ unsafe fn vert_convolution_into_one_row_u16<T: Pixel<Component = u16>>(
             y += 2;
         }
 
-        if let Some(&k) = coeffs_reminder.get(0) {
+        if let Some(&k) = coeffs_reminder.first() {
             let s_row = src_img.get_row(y_start + y).unwrap();
             let components = T::components(s_row);
             let coeff_i64x2 = _mm_set1_epi64x(k as i64);
@@ -211,12 +209,12 @@ unsafe fn vert_convolution_into_one_row_u16<T: Pixel<Component = u16>>(
             c23 = _mm_add_epi64(c23, _mm_mul_epi32(c_i64x2, coeff_i64x2));
         }
 
-        _mm_storeu_si128((&mut c_buf).as_mut_ptr() as *mut __m128i, c01);
+        _mm_storeu_si128(c_buf.as_mut_ptr() as *mut __m128i, c01);
         *dst_ptr_u16 = normalizer_guard.clip(c_buf[0]);
         dst_ptr_u16 = dst_ptr_u16.add(1);
         *dst_ptr_u16 = normalizer_guard.clip(c_buf[1]);
         dst_ptr_u16 = dst_ptr_u16.add(1);
-        _mm_storeu_si128((&mut c_buf).as_mut_ptr() as *mut __m128i, c23);
+        _mm_storeu_si128(c_buf.as_mut_ptr() as *mut __m128i, c23);
         *dst_ptr_u16 = normalizer_guard.clip(c_buf[0]);
         dst_ptr_u16 = dst_ptr_u16.add(1);
         *dst_ptr_u16 = normalizer_guard.clip(c_buf[1]);
@@ -1,7 +1,7 @@
 use std::arch::x86_64::*;
 
 use crate::convolution::optimisations::{CoefficientsI16Chunk, NormalizerGuard16};
-use crate::convolution::{optimisations, Coefficients};
+use crate::convolution::Coefficients;
 use crate::image_view::{TypedImageView, TypedImageViewMut};
 use crate::pixels::Pixel;
 use crate::simd_utils;
@@ -10,20 +10,17 @@ use crate::simd_utils;
 pub(crate) fn vert_convolution<T>(
     src_image: TypedImageView<T>,
     mut dst_image: TypedImageViewMut<T>,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) where
     T: Pixel<Component = u8>,
 {
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
-
-    let normalizer_guard = optimisations::NormalizerGuard16::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let normalizer_guard = coeffs.normalizer16();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
 
     let dst_rows = dst_image.iter_rows_mut();
     for (dst_row, coeffs_chunk) in dst_rows.zip(coefficients_chunks) {
         unsafe {
-            vert_convolution_into_one_row_u8(&src_image, dst_row, coeffs_chunk, &normalizer_guard);
+            vert_convolution_into_one_row_u8(&src_image, dst_row, coeffs_chunk, normalizer_guard);
         }
     }
 }
@@ -48,7 +45,7 @@ unsafe fn vert_convolution_into_one_row_u8<T>(
     let initial_256 = _mm256_set1_epi32(1 << (precision - 1));
 
     let mut x_in_bytes: usize = 0;
-    let dst_ptr_u8 = T::components_mut(dst_row).as_mut_ptr() as *mut u8;
+    let dst_ptr_u8 = T::components_mut(dst_row).as_mut_ptr();
 
     // 32 components in one register - 1 = 31
     while x_in_bytes < src_width.saturating_sub(31) {
@@ -217,7 +214,7 @@ unsafe fn vert_convolution_into_one_row_u8<T>(
 
         sss = _mm_packs_epi32(sss, sss);
         let dst_ptr_i32 = dst_ptr_u8.add(x_in_bytes) as *mut i32;
-        *dst_ptr_i32 = _mm_cvtsi128_si32(_mm_packus_epi16(sss, sss));
+        dst_ptr_i32.write_unaligned(_mm_cvtsi128_si32(_mm_packus_epi16(sss, sss)));
 
         x_in_bytes += 4;
     }
@@ -1,5 +1,5 @@
 use crate::convolution::optimisations::NormalizerGuard16;
-use crate::convolution::{optimisations, Coefficients};
+use crate::convolution::Coefficients;
 use crate::image_view::{TypedImageView, TypedImageViewMut};
 use crate::pixels::Pixel;
 
@@ -7,7 +7,7 @@ use crate::pixels::Pixel;
 pub(crate) fn vert_convolution<T>(
     src_image: TypedImageView<T>,
     mut dst_image: TypedImageViewMut<T>,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) where
     T: Pixel<Component = u8>,
 {
@@ -15,9 +15,8 @@ pub(crate) fn vert_convolution<T>(
     debug_assert_eq!(src_image.width(), dst_image.width());
     debug_assert_eq!(coeffs.bounds.len(), dst_image.height().get() as usize);
 
-    let (values, window_size, bounds) = (coeffs.values, coeffs.window_size, coeffs.bounds);
-    let normalizer_guard = optimisations::NormalizerGuard16::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds);
+    let normalizer_guard = coeffs.normalizer16();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
     let precision = normalizer_guard.precision();
     let initial = 1 << (precision - 1);
 
@@ -33,7 +32,7 @@ pub(crate) fn vert_convolution<T>(
         if !head.is_empty() {
             x_src = convolution_by_u8(
                 &src_image,
-                &normalizer_guard,
+                normalizer_guard,
                 initial,
                 head,
                 x_src,
@@ -48,9 +47,11 @@ pub(crate) fn vert_convolution<T>(
             let src_rows = src_image.iter_rows(first_y_src);
             for (&k, src_row) in ks.iter().zip(src_rows) {
                 let src_ptr = src_row.as_ptr() as *const u8;
+                // Rows of the source image may be aligned
+                // differently than rows of the destination image.
                 let src_chunk = unsafe {
                     let ptr = src_ptr.add(x_src) as *const u32;
-                    *ptr
+                    ptr.read_unaligned()
                 };
 
                 let components: [u8; 4] = src_chunk.to_le_bytes();
@@ -65,7 +66,7 @@ pub(crate) fn vert_convolution<T>(
         if !tail.is_empty() {
             convolution_by_u8(
                 &src_image,
-                &normalizer_guard,
+                normalizer_guard,
                 initial,
                 tail,
                 x_src,
@@ -94,7 +95,7 @@ where
         let src_rows = src_image.iter_rows(first_y_src);
         for (&k, src_row) in ks.iter().zip(src_rows) {
             let src_ptr = src_row.as_ptr() as *const u8;
-            let src_component = unsafe { *src_ptr.add(x_src as usize) };
+            let src_component = unsafe { *src_ptr.add(x_src) };
             ss += src_component as i32 * (k as i32);
         }
         *dst_component = unsafe { normalizer_guard.clip(ss) };
@@ -1,7 +1,7 @@
 use std::arch::x86_64::*;
 
 use crate::convolution::optimisations::{CoefficientsI16Chunk, NormalizerGuard16};
-use crate::convolution::{optimisations, Coefficients};
+use crate::convolution::Coefficients;
 use crate::image_view::{TypedImageView, TypedImageViewMut};
 use crate::pixels::Pixel;
 use crate::simd_utils;
@@ -10,18 +10,15 @@ use crate::simd_utils;
 pub(crate) fn vert_convolution<T: Pixel<Component = u8>>(
     src_image: TypedImageView<T>,
     mut dst_image: TypedImageViewMut<T>,
-    coeffs: Coefficients,
+    coeffs: &Coefficients,
 ) {
-    let (values, window_size, bounds_per_pixel) =
-        (coeffs.values, coeffs.window_size, coeffs.bounds);
-
-    let normalizer_guard = optimisations::NormalizerGuard16::new(values);
-    let coefficients_chunks = normalizer_guard.normalized_chunks(window_size, &bounds_per_pixel);
+    let normalizer_guard = coeffs.normalizer16();
+    let coefficients_chunks = normalizer_guard.normalized_chunks(coeffs);
 
     let dst_rows = dst_image.iter_rows_mut();
     for (dst_row, coeffs_chunk) in dst_rows.zip(coefficients_chunks) {
         unsafe {
-            vert_convolution_into_one_row_u8(&src_image, dst_row, coeffs_chunk, &normalizer_guard);
+            vert_convolution_into_one_row_u8(&src_image, dst_row, coeffs_chunk, normalizer_guard);
         }
     }
 }
@@ -39,7 +36,7 @@ pub(crate) unsafe fn vert_convolution_into_one_row_u8<T: Pixel<Component = u8>>(
     let coeffs = coeffs_chunk.values;
     let max_y = y_start + coeffs.len() as u32;
     let precision = normalizer_guard.precision();
-    let dst_ptr_u8 = T::components_mut(dst_row).as_mut_ptr() as *mut u8;
+    let dst_ptr_u8 = T::components_mut(dst_row).as_mut_ptr();
 
     let initial = _mm_set1_epi32(1 << (precision - 1));
 
@@ -248,7 +245,7 @@ pub(crate) unsafe fn vert_convolution_into_one_row_u8<T: Pixel<Component = u8>>(
 
         sss = _mm_packs_epi32(sss, sss);
         let dst_ptr = dst_ptr_u8.add(xx) as *mut i32;
-        *dst_ptr = _mm_cvtsi128_si32(_mm_packus_epi16(sss, sss));
+        dst_ptr.write_unaligned(_mm_cvtsi128_si32(_mm_packus_epi16(sss, sss)));
 
         xx += 4;
     }
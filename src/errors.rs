@@ -22,8 +22,34 @@ pub enum CropBoxError {
     PositionIsOutOfImageBoundaries,
     #[error("Size of the crop box is out of the image boundaries")]
     SizeIsOutOfImageBoundaries,
+    #[error("Width or height of the crop box is less or equal to zero")]
+    WidthOrHeightLessOrEqualToZero,
 }
 
 #[derive(Error, Debug, Clone, Copy)]
 #[error("Type of pixels of the source image is not equal to pixel type of the destination image.")]
 pub struct DifferentTypesOfPixelsError;
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum StreamingResizeError {
+    #[error("Type of pixels of rows is not equal to pixel type of the resizer")]
+    PixelTypeIsDifferent,
+    #[error("Width of rows don't match to width of the source image")]
+    InvalidRowSize,
+    #[error("Count of pushed rows is greater than height of the source image")]
+    TooManyRows,
+    #[error("Plan can't be executed by rows of the source image")]
+    UnsupportedPlan,
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum ResizePlanError {
+    #[error("Type of pixels of images is not equal to pixel type of the plan")]
+    PixelTypeIsDifferent,
+    #[error("Size or crop box of the source image don't match to the plan")]
+    SrcImageIsDifferent,
+    #[error("Size of the destination image don't match to the plan")]
+    DstImageIsDifferent,
+}
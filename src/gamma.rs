@@ -0,0 +1,112 @@
+//! Conversion of sRGB-encoded images into linear color space and back.
+use crate::alpha::AlphaMulDiv;
+use crate::color::mappers::srgb_mapper;
+use crate::convolution::Convolution;
+use crate::image::InnerImage;
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::nodata::NodataResample;
+use crate::pixels::{Pixel, U16x3, U16x4, U8x3, U8x4};
+use crate::reduce::BoxReduce;
+use crate::statistics::FootprintStatistic;
+use crate::CpuExtensions;
+
+/// Pixel with sRGB-encoded components that can be resized in linear color space.
+pub(crate) trait SrgbPixel: Pixel {
+    /// Type of pixel used to store linear values of components.
+    type Linear: Convolution + BoxReduce + NodataResample + FootprintStatistic;
+
+    /// Converts sRGB-encoded source image into linear image of the same size.
+    fn to_linear(
+        src_image: &TypedImageView<Self>,
+        dst_image: &mut InnerImage<Self::Linear>,
+        cpu_extensions: CpuExtensions,
+    );
+
+    /// Converts linear image into sRGB-encoded destination image of the same size.
+    fn from_linear(
+        src_image: &mut InnerImage<Self::Linear>,
+        dst_image: TypedImageViewMut<Self>,
+        cpu_extensions: CpuExtensions,
+    );
+}
+
+impl SrgbPixel for U8x3 {
+    type Linear = U16x3;
+
+    fn to_linear(
+        src_image: &TypedImageView<Self>,
+        dst_image: &mut InnerImage<Self::Linear>,
+        cpu_extensions: CpuExtensions,
+    ) {
+        srgb_mapper().forward_map_typed(*src_image, dst_image.dst_view(), false, cpu_extensions);
+    }
+
+    fn from_linear(
+        src_image: &mut InnerImage<Self::Linear>,
+        dst_image: TypedImageViewMut<Self>,
+        cpu_extensions: CpuExtensions,
+    ) {
+        srgb_mapper().backward_map_typed(src_image.src_view(), dst_image, false, cpu_extensions);
+    }
+}
+
+impl SrgbPixel for U8x4 {
+    type Linear = U16x4;
+
+    /// Alpha channel is stored linearly, so it is only widened to `u16`.
+    /// Color components of the result are multiplied by alpha.
+    fn to_linear(
+        src_image: &TypedImageView<Self>,
+        dst_image: &mut InnerImage<Self::Linear>,
+        cpu_extensions: CpuExtensions,
+    ) {
+        srgb_mapper().forward_map_typed(*src_image, dst_image.dst_view(), true, cpu_extensions);
+        U16x4::multiply_alpha_inplace(dst_image.dst_view(), cpu_extensions);
+    }
+
+    /// Color components of the source image are divided by alpha
+    /// before encoding.
+    fn from_linear(
+        src_image: &mut InnerImage<Self::Linear>,
+        dst_image: TypedImageViewMut<Self>,
+        cpu_extensions: CpuExtensions,
+    ) {
+        U16x4::divide_alpha_inplace(src_image.dst_view(), cpu_extensions);
+        srgb_mapper().backward_map_typed(src_image.src_view(), dst_image, true, cpu_extensions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use super::*;
+
+    #[test]
+    fn encoded_values_round_trip() {
+        let width = NonZeroU32::new(256).unwrap();
+        let height = NonZeroU32::new(1).unwrap();
+        // All possible values of components
+        let src_row: Vec<U8x3> = (0..=255u8).map(|v| U8x3([v, v, v])).collect();
+        let src_rows = [src_row.as_slice()];
+        let src_image = TypedImageView::new(width, height, &src_rows);
+
+        let mut cpu_extensions_vec = vec![CpuExtensions::None];
+        #[cfg(target_arch = "x86_64")]
+        {
+            cpu_extensions_vec.push(CpuExtensions::Sse4_1);
+            cpu_extensions_vec.push(CpuExtensions::Avx2);
+        }
+        for cpu_extensions in cpu_extensions_vec {
+            let mut linear_pixels = vec![U16x3([0; 3]); 256];
+            let mut linear_image = InnerImage::new(width, height, &mut linear_pixels);
+            U8x3::to_linear(&src_image, &mut linear_image, cpu_extensions);
+
+            let mut dst_row = vec![U8x3([0; 3]); 256];
+            let mut dst_rows = [dst_row.as_mut_slice()];
+            let dst_image = TypedImageViewMut::new(width, height, &mut dst_rows);
+            U8x3::from_linear(&mut linear_image, dst_image, cpu_extensions);
+            assert_eq!(dst_row, src_row, "{:?}", cpu_extensions);
+        }
+    }
+}
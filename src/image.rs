@@ -1,7 +1,9 @@
 use std::num::NonZeroU32;
 
-use crate::image_view::{ImageRows, ImageRowsMut, TypedImageView, TypedImageViewMut};
-use crate::pixels::{Pixel, PixelType, U16x3, U8x3, U8x4, F32, I32, U8};
+use crate::image_view::{CropBox, ImageRows, ImageRowsMut, TypedImageView, TypedImageViewMut};
+use crate::pixels::{
+    F32x3, F32x4, Pixel, PixelType, U16x3, U16x4, U8x2, U8x3, U8x4, F32, I32, U16, U8,
+};
 use crate::{ImageBufferError, ImageView, ImageViewMut};
 
 #[derive(Debug)]
@@ -24,11 +26,16 @@ impl<'a> Image<'a> {
     pub fn new(width: NonZeroU32, height: NonZeroU32, pixel_type: PixelType) -> Self {
         let pixels_count = (width.get() * height.get()) as usize;
         let pixels = match pixel_type {
+            PixelType::U8x2 => PixelsContainer::VecU8(vec![0; pixels_count * U8x2::size()]),
             PixelType::U8x3 => PixelsContainer::VecU8(vec![0; pixels_count * U8x3::size()]),
+            PixelType::U16 => PixelsContainer::VecU8(vec![0; pixels_count * U16::size()]),
             PixelType::U16x3 => PixelsContainer::VecU8(vec![0; pixels_count * U16x3::size()]),
+            PixelType::U16x4 => PixelsContainer::VecU8(vec![0; pixels_count * U16x4::size()]),
             PixelType::U8x4 => PixelsContainer::VecU8(vec![0; pixels_count * U8x4::size()]),
             PixelType::I32 => PixelsContainer::VecU8(vec![0; pixels_count * I32::size()]),
             PixelType::F32 => PixelsContainer::VecU8(vec![0; pixels_count * F32::size()]),
+            PixelType::F32x3 => PixelsContainer::VecU8(vec![0; pixels_count * F32x3::size()]),
+            PixelType::F32x4 => PixelsContainer::VecU8(vec![0; pixels_count * F32x4::size()]),
             PixelType::U8 => PixelsContainer::VecU8(vec![0; pixels_count]),
         };
         Self {
@@ -100,7 +107,7 @@ impl<'a> Image<'a> {
     #[inline(always)]
     pub fn buffer(&self) -> &[u8] {
         match &self.pixels {
-            PixelsContainer::MutU8(p) => *p,
+            PixelsContainer::MutU8(p) => p,
             PixelsContainer::VecU8(v) => v,
         }
     }
@@ -115,10 +122,19 @@ impl<'a> Image<'a> {
     }
 
     #[inline(always)]
-    pub fn view(&self) -> ImageView {
+    pub fn view(&self) -> ImageView<'_> {
         let buffer = self.buffer();
         let rows_count = self.height.get() as usize;
         let rows = match self.pixel_type {
+            PixelType::U8x2 => {
+                let pixels = unsafe { buffer.align_to::<U8x2>().1 };
+                ImageRows::U8x2(
+                    pixels
+                        .chunks_exact(self.width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U8x3 => {
                 let pixels = unsafe { buffer.align_to::<U8x3>().1 };
                 ImageRows::U8x3(
@@ -137,6 +153,15 @@ impl<'a> Image<'a> {
                         .collect(),
                 )
             }
+            PixelType::U16 => {
+                let pixels = unsafe { buffer.align_to::<U16>().1 };
+                ImageRows::U16(
+                    pixels
+                        .chunks_exact(self.width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U16x3 => {
                 let pixels = unsafe { buffer.align_to::<U16x3>().1 };
                 ImageRows::U16x3(
@@ -146,6 +171,15 @@ impl<'a> Image<'a> {
                         .collect(),
                 )
             }
+            PixelType::U16x4 => {
+                let pixels = unsafe { buffer.align_to::<U16x4>().1 };
+                ImageRows::U16x4(
+                    pixels
+                        .chunks_exact(self.width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::I32 => {
                 let pixels = unsafe { buffer.align_to::<I32>().1 };
                 ImageRows::I32(
@@ -164,6 +198,24 @@ impl<'a> Image<'a> {
                         .collect(),
                 )
             }
+            PixelType::F32x3 => {
+                let pixels = unsafe { buffer.align_to::<F32x3>().1 };
+                ImageRows::F32x3(
+                    pixels
+                        .chunks_exact(self.width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
+            PixelType::F32x4 => {
+                let pixels = unsafe { buffer.align_to::<F32x4>().1 };
+                ImageRows::F32x4(
+                    pixels
+                        .chunks_exact(self.width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U8 => {
                 let pixels = unsafe { buffer.align_to::<U8>().1 };
                 ImageRows::U8(
@@ -178,13 +230,22 @@ impl<'a> Image<'a> {
     }
 
     #[inline(always)]
-    pub fn view_mut(&mut self) -> ImageViewMut {
+    pub fn view_mut(&mut self) -> ImageViewMut<'_> {
         let pixel_type = self.pixel_type;
         let width = self.width;
         let height = self.height;
         let buffer = self.buffer_mut();
         let rows_count = height.get() as usize;
         let rows = match pixel_type {
+            PixelType::U8x2 => {
+                let pixels = unsafe { buffer.align_to_mut::<U8x2>().1 };
+                ImageRowsMut::U8x2(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U8x3 => {
                 let pixels = unsafe { buffer.align_to_mut::<U8x3>().1 };
                 ImageRowsMut::U8x3(
@@ -203,6 +264,15 @@ impl<'a> Image<'a> {
                         .collect(),
                 )
             }
+            PixelType::U16 => {
+                let pixels = unsafe { buffer.align_to_mut::<U16>().1 };
+                ImageRowsMut::U16(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U16x3 => {
                 let pixels = unsafe { buffer.align_to_mut::<U16x3>().1 };
                 ImageRowsMut::U16x3(
@@ -212,6 +282,15 @@ impl<'a> Image<'a> {
                         .collect(),
                 )
             }
+            PixelType::U16x4 => {
+                let pixels = unsafe { buffer.align_to_mut::<U16x4>().1 };
+                ImageRowsMut::U16x4(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::I32 => {
                 let pixels = unsafe { buffer.align_to_mut::<I32>().1 };
                 ImageRowsMut::I32(
@@ -230,6 +309,24 @@ impl<'a> Image<'a> {
                         .collect(),
                 )
             }
+            PixelType::F32x3 => {
+                let pixels = unsafe { buffer.align_to_mut::<F32x3>().1 };
+                ImageRowsMut::F32x3(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
+            PixelType::F32x4 => {
+                let pixels = unsafe { buffer.align_to_mut::<F32x4>().1 };
+                ImageRowsMut::F32x4(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U8 => {
                 let pixels = unsafe { buffer.align_to_mut::<U8>().1 };
                 ImageRowsMut::U8(
@@ -278,4 +375,21 @@ where
     pub fn dst_view<'s>(&'s mut self) -> TypedImageViewMut<'s, 'a, P> {
         TypedImageViewMut::new(self.width, self.height, self.rows.as_mut_slice())
     }
+
+    /// Returns image that consists of pixels of the region of this image.
+    pub fn region<'s>(&'s mut self, region: CropBox) -> InnerImage<'s, P> {
+        let (left, width) = (region.left as usize, region.width.get() as usize);
+        let rows = self
+            .rows
+            .iter_mut()
+            .skip(region.top as usize)
+            .take(region.height.get() as usize)
+            .map(|row| &mut row[left..left + width])
+            .collect();
+        InnerImage {
+            width: region.width,
+            height: region.height,
+            rows,
+        }
+    }
 }
@@ -2,7 +2,9 @@ use std::num::NonZeroU32;
 use std::slice;
 
 use crate::errors::{CropBoxError, ImageBufferError, ImageRowsError};
-use crate::pixels::{Pixel, PixelType, U16x3, U8x3, U8x4, F32, I32, U8};
+use crate::pixels::{
+    F32x3, F32x4, Pixel, PixelType, U16x3, U16x4, U8x2, U8x3, U8x4, F32, I32, U16, U8,
+};
 
 pub(crate) type RowMut<'a, 'b, T> = &'a mut &'b mut [T];
 pub(crate) type TwoRows<'a, T> = (&'a [T], &'a [T]);
@@ -15,7 +17,7 @@ pub(crate) type FourRowsMut<'a, 'b, T> = (
 );
 
 /// Parameters of crop box that may be used with [`ImageView`]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CropBox {
     pub left: u32,
     pub top: u32,
@@ -23,14 +25,145 @@ pub struct CropBox {
     pub height: NonZeroU32,
 }
 
+/// Parameters of crop box with fractional position and size
+/// that may be used with [`ImageView`].
+///
+/// Fractional bounds are used by resizer as is, without rounding
+/// to the whole pixels.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use fast_image_resize::{FilterType, FloatCropBox, Image, PixelType, ResizeAlg, Resizer};
+///
+/// let src_image = Image::new(
+///     NonZeroU32::new(200).unwrap(),
+///     NonZeroU32::new(100).unwrap(),
+///     PixelType::U8x3,
+/// );
+/// let mut dst_image = Image::new(
+///     NonZeroU32::new(50).unwrap(),
+///     NonZeroU32::new(25).unwrap(),
+///     PixelType::U8x3,
+/// );
+/// let mut src_view = src_image.view();
+/// src_view
+///     .set_float_crop_box(FloatCropBox {
+///         left: 10.5,
+///         top: 20.25,
+///         width: 100.3,
+///         height: 50.,
+///     })
+///     .unwrap();
+/// let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+/// resizer.resize(&src_view, &mut dst_image.view_mut()).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatCropBox {
+    pub left: f64,
+    pub top: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Mode of sampling of pixels near edges of crop box by convolution.
+///
+/// # Examples
+///
+/// Crop a sprite from a sprite sheet without bleeding of pixels
+/// of neighboring sprites:
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use fast_image_resize::{CropBox, CropMode, FilterType, Image, PixelType, ResizeAlg, Resizer};
+///
+/// let sprite_size = NonZeroU32::new(32).unwrap();
+/// let sprite_sheet = Image::new(
+///     NonZeroU32::new(128).unwrap(),
+///     sprite_size,
+///     PixelType::U8x4,
+/// );
+/// let icon_size = NonZeroU32::new(16).unwrap();
+/// let mut icon = Image::new(icon_size, icon_size, PixelType::U8x4);
+///
+/// let mut src_view = sprite_sheet.view();
+/// src_view
+///     .set_crop_box(CropBox {
+///         left: 64,
+///         top: 0,
+///         width: sprite_size,
+///         height: sprite_size,
+///     })
+///     .unwrap();
+/// src_view.set_crop_mode(CropMode::Boundary);
+/// let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+/// resizer.resize(&src_view, &mut icon.view_mut()).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CropMode {
+    /// Filter may use pixels of the source image
+    /// that are outside of the crop box.
+    #[default]
+    Surrounding,
+    /// Edges of the crop box are treated as edges of the image,
+    /// pixels outside of the crop box are never used.
+    /// Pixels partially covered by fractional crop box are
+    /// treated as pixels inside of the crop box.
+    Boundary,
+}
+
+impl From<CropBox> for FloatCropBox {
+    fn from(crop_box: CropBox) -> Self {
+        Self {
+            left: crop_box.left as f64,
+            top: crop_box.top as f64,
+            width: crop_box.width.get() as f64,
+            height: crop_box.height.get() as f64,
+        }
+    }
+}
+
+impl FloatCropBox {
+    pub(crate) fn new(width: NonZeroU32, height: NonZeroU32) -> Self {
+        Self {
+            left: 0.,
+            top: 0.,
+            width: width.get() as f64,
+            height: height.get() as f64,
+        }
+    }
+
+    /// Checks that crop box is placed inside of image with given size.
+    pub(crate) fn check(&self, width: NonZeroU32, height: NonZeroU32) -> Result<(), CropBoxError> {
+        let (width, height) = (width.get() as f64, height.get() as f64);
+        // Negated comparisons also catch NaN values.
+        if !(self.left >= 0. && self.top >= 0.) || self.left >= width || self.top >= height {
+            return Err(CropBoxError::PositionIsOutOfImageBoundaries);
+        }
+        if !(self.width > 0. && self.height > 0.) {
+            return Err(CropBoxError::WidthOrHeightLessOrEqualToZero);
+        }
+        if self.left + self.width > width || self.top + self.height > height {
+            return Err(CropBoxError::SizeIsOutOfImageBoundaries);
+        }
+        Ok(())
+    }
+}
+
 /// An immutable rows of image.
 #[derive(Debug, Clone)]
 pub enum ImageRows<'a> {
+    U8x2(Vec<&'a [U8x2]>),
     U8x3(Vec<&'a [U8x3]>),
     U8x4(Vec<&'a [U8x4]>),
+    U16(Vec<&'a [U16]>),
     U16x3(Vec<&'a [U16x3]>),
+    U16x4(Vec<&'a [U16x4]>),
     I32(Vec<&'a [I32]>),
     F32(Vec<&'a [F32]>),
+    F32x3(Vec<&'a [F32x3]>),
+    F32x4(Vec<&'a [F32x4]>),
     U8(Vec<&'a [U8]>),
 }
 
@@ -41,22 +174,32 @@ impl<'a> ImageRows<'a> {
         height: NonZeroU32,
     ) -> Result<(), ImageRowsError> {
         match self {
+            ImageRows::U8x2(rows) => check_rows_count_and_size(width, height, rows),
             ImageRows::U8x3(rows) => check_rows_count_and_size(width, height, rows),
             ImageRows::U8x4(rows) => check_rows_count_and_size(width, height, rows),
+            ImageRows::U16(rows) => check_rows_count_and_size(width, height, rows),
             ImageRows::U16x3(rows) => check_rows_count_and_size(width, height, rows),
+            ImageRows::U16x4(rows) => check_rows_count_and_size(width, height, rows),
             ImageRows::I32(rows) => check_rows_count_and_size(width, height, rows),
             ImageRows::F32(rows) => check_rows_count_and_size(width, height, rows),
+            ImageRows::F32x3(rows) => check_rows_count_and_size(width, height, rows),
+            ImageRows::F32x4(rows) => check_rows_count_and_size(width, height, rows),
             ImageRows::U8(rows) => check_rows_count_and_size(width, height, rows),
         }
     }
 
     pub fn pixel_type(&self) -> PixelType {
         match self {
+            Self::U8x2(_) => PixelType::U8x2,
             Self::U8x3(_) => PixelType::U8x3,
             Self::U8x4(_) => PixelType::U8x4,
+            Self::U16(_) => PixelType::U16,
             Self::U16x3(_) => PixelType::U16x3,
+            Self::U16x4(_) => PixelType::U16x4,
             Self::I32(_) => PixelType::I32,
             Self::F32(_) => PixelType::F32,
+            Self::F32x3(_) => PixelType::F32x3,
+            Self::F32x4(_) => PixelType::F32x4,
             Self::U8(_) => PixelType::U8,
         }
     }
@@ -65,11 +208,16 @@ impl<'a> ImageRows<'a> {
 /// A mutable rows of image.
 #[derive(Debug)]
 pub enum ImageRowsMut<'a> {
+    U8x2(Vec<&'a mut [U8x2]>),
     U8x3(Vec<&'a mut [U8x3]>),
     U8x4(Vec<&'a mut [U8x4]>),
+    U16(Vec<&'a mut [U16]>),
     U16x3(Vec<&'a mut [U16x3]>),
+    U16x4(Vec<&'a mut [U16x4]>),
     I32(Vec<&'a mut [I32]>),
     F32(Vec<&'a mut [F32]>),
+    F32x3(Vec<&'a mut [F32x3]>),
+    F32x4(Vec<&'a mut [F32x4]>),
     U8(Vec<&'a mut [U8]>),
 }
 
@@ -80,22 +228,32 @@ impl<'a> ImageRowsMut<'a> {
         height: NonZeroU32,
     ) -> Result<(), ImageRowsError> {
         match self {
+            Self::U8x2(rows) => check_rows_count_and_size(width, height, rows),
             Self::U8x3(rows) => check_rows_count_and_size(width, height, rows),
             Self::U8x4(rows) => check_rows_count_and_size(width, height, rows),
+            Self::U16(rows) => check_rows_count_and_size(width, height, rows),
             Self::U16x3(rows) => check_rows_count_and_size(width, height, rows),
+            Self::U16x4(rows) => check_rows_count_and_size(width, height, rows),
             Self::I32(rows) => check_rows_count_and_size(width, height, rows),
             Self::F32(rows) => check_rows_count_and_size(width, height, rows),
+            Self::F32x3(rows) => check_rows_count_and_size(width, height, rows),
+            Self::F32x4(rows) => check_rows_count_and_size(width, height, rows),
             Self::U8(rows) => check_rows_count_and_size(width, height, rows),
         }
     }
 
     pub fn pixel_type(&self) -> PixelType {
         match self {
+            Self::U8x2(_) => PixelType::U8x2,
             Self::U8x3(_) => PixelType::U8x3,
             Self::U8x4(_) => PixelType::U8x4,
+            Self::U16(_) => PixelType::U16,
             Self::U16x3(_) => PixelType::U16x3,
+            Self::U16x4(_) => PixelType::U16x4,
             Self::I32(_) => PixelType::I32,
             Self::F32(_) => PixelType::F32,
+            Self::F32x3(_) => PixelType::F32x3,
+            Self::F32x4(_) => PixelType::F32x4,
             Self::U8(_) => PixelType::U8,
         }
     }
@@ -106,7 +264,8 @@ impl<'a> ImageRowsMut<'a> {
 pub struct ImageView<'a> {
     width: NonZeroU32,
     height: NonZeroU32,
-    crop_box: CropBox,
+    crop_box: FloatCropBox,
+    crop_mode: CropMode,
     rows: ImageRows<'a>,
 }
 
@@ -120,12 +279,8 @@ impl<'a> ImageView<'a> {
         Ok(Self {
             width,
             height,
-            crop_box: CropBox {
-                left: 0,
-                top: 0,
-                width,
-                height,
-            },
+            crop_box: FloatCropBox::new(width, height),
+            crop_mode: CropMode::Surrounding,
             rows,
         })
     }
@@ -142,6 +297,15 @@ impl<'a> ImageView<'a> {
         }
         let rows_count = height.get() as usize;
         let rows = match pixel_type {
+            PixelType::U8x2 => {
+                let pixels = align_buffer_to(buffer)?;
+                ImageRows::U8x2(
+                    pixels
+                        .chunks_exact(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U8x3 => {
                 let pixels = align_buffer_to(buffer)?;
                 ImageRows::U8x3(
@@ -160,6 +324,15 @@ impl<'a> ImageView<'a> {
                         .collect(),
                 )
             }
+            PixelType::U16 => {
+                let pixels = align_buffer_to(buffer)?;
+                ImageRows::U16(
+                    pixels
+                        .chunks_exact(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U16x3 => {
                 let pixels = align_buffer_to(buffer)?;
                 ImageRows::U16x3(
@@ -169,6 +342,15 @@ impl<'a> ImageView<'a> {
                         .collect(),
                 )
             }
+            PixelType::U16x4 => {
+                let pixels = align_buffer_to(buffer)?;
+                ImageRows::U16x4(
+                    pixels
+                        .chunks_exact(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::I32 => {
                 let pixels = align_buffer_to(buffer)?;
                 ImageRows::I32(
@@ -187,6 +369,24 @@ impl<'a> ImageView<'a> {
                         .collect(),
                 )
             }
+            PixelType::F32x3 => {
+                let pixels = align_buffer_to(buffer)?;
+                ImageRows::F32x3(
+                    pixels
+                        .chunks_exact(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
+            PixelType::F32x4 => {
+                let pixels = align_buffer_to(buffer)?;
+                ImageRows::F32x4(
+                    pixels
+                        .chunks_exact(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U8 => {
                 let pixels = align_buffer_to(buffer)?;
                 ImageRows::U8(
@@ -200,12 +400,8 @@ impl<'a> ImageView<'a> {
         Ok(Self {
             width,
             height,
-            crop_box: CropBox {
-                left: 0,
-                top: 0,
-                width,
-                height,
-            },
+            crop_box: FloatCropBox::new(width, height),
+            crop_mode: CropMode::Surrounding,
             rows,
         })
     }
@@ -225,24 +421,60 @@ impl<'a> ImageView<'a> {
         self.height
     }
 
-    #[inline(always)]
+    /// Returns the smallest crop box with integer position and size
+    /// that contains the crop box of the view.
+    #[inline]
     pub fn crop_box(&self) -> CropBox {
+        let crop_box = self.crop_box;
+        let left = crop_box.left.floor() as u32;
+        let top = crop_box.top.floor() as u32;
+        // Too small size of the crop box may be lost by rounding,
+        // but the crop box always covers at least one pixel.
+        let right = ((crop_box.left + crop_box.width).ceil() as u32)
+            .max(left + 1)
+            .min(self.width.get());
+        let bottom = ((crop_box.top + crop_box.height).ceil() as u32)
+            .max(top + 1)
+            .min(self.height.get());
+        CropBox {
+            left,
+            top,
+            width: NonZeroU32::new(right - left).unwrap(),
+            height: NonZeroU32::new(bottom - top).unwrap(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn float_crop_box(&self) -> FloatCropBox {
         self.crop_box
     }
 
+    /// Set a crop box.
+    ///
+    /// Use [ImageView::set_crop_mode] to select whether convolution may use
+    /// pixels outside of the crop box.
     pub fn set_crop_box(&mut self, crop_box: CropBox) -> Result<(), CropBoxError> {
-        if crop_box.left >= self.width.get() || crop_box.top >= self.height.get() {
-            return Err(CropBoxError::PositionIsOutOfImageBoundaries);
-        }
-        let right = crop_box.left + crop_box.width.get();
-        let bottom = crop_box.top + crop_box.height.get();
-        if right > self.width.get() || bottom > self.height.get() {
-            return Err(CropBoxError::SizeIsOutOfImageBoundaries);
-        }
+        self.set_float_crop_box(crop_box.into())
+    }
+
+    /// Set a crop box with fractional position and size.
+    pub fn set_float_crop_box(&mut self, crop_box: FloatCropBox) -> Result<(), CropBoxError> {
+        crop_box.check(self.width, self.height)?;
         self.crop_box = crop_box;
         Ok(())
     }
 
+    #[inline(always)]
+    pub fn crop_mode(&self) -> CropMode {
+        self.crop_mode
+    }
+
+    /// Set mode of sampling of pixels near edges of crop box.
+    /// By default, it is [CropMode::Surrounding].
+    pub fn set_crop_mode(&mut self, crop_mode: CropMode) {
+        self.crop_mode = crop_mode;
+    }
+
     /// Set a crop box to resize the source image into the
     /// aspect ratio of destination image without distortions.
     ///
@@ -305,7 +537,20 @@ impl<'a> ImageView<'a> {
         .unwrap();
     }
 
-    pub(crate) fn u8x3_image(&self) -> Option<TypedImageView<U8x3>> {
+    pub(crate) fn u8x2_image(&self) -> Option<TypedImageView<'_, '_, U8x2>> {
+        if let ImageRows::U8x2(ref rows) = self.rows {
+            Some(TypedImageView {
+                width: self.width,
+                height: self.height,
+                crop_box: self.crop_box,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn u8x3_image(&self) -> Option<TypedImageView<'_, '_, U8x3>> {
         if let ImageRows::U8x3(ref rows) = self.rows {
             Some(TypedImageView {
                 width: self.width,
@@ -318,7 +563,7 @@ impl<'a> ImageView<'a> {
         }
     }
 
-    pub(crate) fn u8x4_image(&self) -> Option<TypedImageView<U8x4>> {
+    pub(crate) fn u8x4_image(&self) -> Option<TypedImageView<'_, '_, U8x4>> {
         if let ImageRows::U8x4(ref rows) = self.rows {
             Some(TypedImageView {
                 width: self.width,
@@ -331,7 +576,20 @@ impl<'a> ImageView<'a> {
         }
     }
 
-    pub(crate) fn u16x3_image(&self) -> Option<TypedImageView<U16x3>> {
+    pub(crate) fn u16_image(&self) -> Option<TypedImageView<'_, '_, U16>> {
+        if let ImageRows::U16(ref rows) = self.rows {
+            Some(TypedImageView {
+                width: self.width,
+                height: self.height,
+                crop_box: self.crop_box,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn u16x3_image(&self) -> Option<TypedImageView<'_, '_, U16x3>> {
         if let ImageRows::U16x3(ref rows) = self.rows {
             Some(TypedImageView {
                 width: self.width,
@@ -344,7 +602,20 @@ impl<'a> ImageView<'a> {
         }
     }
 
-    pub(crate) fn i32_image(&self) -> Option<TypedImageView<I32>> {
+    pub(crate) fn u16x4_image(&self) -> Option<TypedImageView<'_, '_, U16x4>> {
+        if let ImageRows::U16x4(ref rows) = self.rows {
+            Some(TypedImageView {
+                width: self.width,
+                height: self.height,
+                crop_box: self.crop_box,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn i32_image(&self) -> Option<TypedImageView<'_, '_, I32>> {
         if let ImageRows::I32(ref rows) = self.rows {
             Some(TypedImageView {
                 width: self.width,
@@ -357,7 +628,7 @@ impl<'a> ImageView<'a> {
         }
     }
 
-    pub(crate) fn f32_image(&self) -> Option<TypedImageView<F32>> {
+    pub(crate) fn f32_image(&self) -> Option<TypedImageView<'_, '_, F32>> {
         if let ImageRows::F32(ref rows) = self.rows {
             Some(TypedImageView {
                 width: self.width,
@@ -370,7 +641,33 @@ impl<'a> ImageView<'a> {
         }
     }
 
-    pub(crate) fn u8_image(&self) -> Option<TypedImageView<U8>> {
+    pub(crate) fn f32x3_image(&self) -> Option<TypedImageView<'_, '_, F32x3>> {
+        if let ImageRows::F32x3(ref rows) = self.rows {
+            Some(TypedImageView {
+                width: self.width,
+                height: self.height,
+                crop_box: self.crop_box,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn f32x4_image(&self) -> Option<TypedImageView<'_, '_, F32x4>> {
+        if let ImageRows::F32x4(ref rows) = self.rows {
+            Some(TypedImageView {
+                width: self.width,
+                height: self.height,
+                crop_box: self.crop_box,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn u8_image(&self) -> Option<TypedImageView<'_, '_, U8>> {
         if let ImageRows::U8(ref rows) = self.rows {
             Some(TypedImageView {
                 width: self.width,
@@ -385,13 +682,14 @@ impl<'a> ImageView<'a> {
 }
 
 /// Generic immutable image view.
+#[derive(Clone, Copy)]
 pub(crate) struct TypedImageView<'a, 'b, P>
 where
     P: Pixel,
 {
     width: NonZeroU32,
     height: NonZeroU32,
-    crop_box: CropBox,
+    crop_box: FloatCropBox,
     rows: &'a [&'b [P]],
 }
 
@@ -403,12 +701,7 @@ where
         Self {
             width,
             height,
-            crop_box: CropBox {
-                left: 0,
-                top: 0,
-                width,
-                height,
-            },
+            crop_box: FloatCropBox::new(width, height),
             rows,
         }
     }
@@ -424,10 +717,27 @@ where
     }
 
     #[inline(always)]
-    pub fn crop_box(&self) -> CropBox {
+    pub fn crop_box(&self) -> FloatCropBox {
         self.crop_box
     }
 
+    /// Caller must guarantee that crop box is placed inside of the image.
+    #[inline(always)]
+    pub(crate) fn set_crop_box(&mut self, crop_box: FloatCropBox) {
+        self.crop_box = crop_box;
+    }
+
+    /// Returns view of `height` rows of the image starting from `start_y`.
+    /// Crop box of the result covers the whole view.
+    ///
+    /// Caller must guarantee that `start_y + height <= self.height()`.
+    #[inline(always)]
+    pub(crate) fn rows_view(&self, start_y: u32, height: NonZeroU32) -> Self {
+        let start_y = start_y as usize;
+        let rows = &self.rows[start_y..start_y + height.get() as usize];
+        Self::new(self.width, height, rows)
+    }
+
     #[inline(always)]
     pub(crate) fn iter_4_rows<'s>(
         &'s self,
@@ -436,7 +746,7 @@ where
     ) -> impl Iterator<Item = FourRows<'b, P>> + 's {
         let start_y = start_y as usize;
         let max_y = max_y.min(self.height.get()) as usize;
-        let rows = self.rows.get(start_y..max_y).unwrap_or_else(|| &[]);
+        let rows = self.rows.get(start_y..max_y).unwrap_or(&[]);
         rows.chunks_exact(4).map(|rows| match *rows {
             [r0, r1, r2, r3] => (r0, r1, r2, r3),
             _ => unreachable!(),
@@ -451,7 +761,7 @@ where
     ) -> impl Iterator<Item = TwoRows<'b, P>> + 's {
         let start_y = start_y as usize;
         let max_y = max_y.min(self.height.get()) as usize;
-        let rows = self.rows.get(start_y..max_y).unwrap_or_else(|| &[]);
+        let rows = self.rows.get(start_y..max_y).unwrap_or(&[]);
         rows.chunks_exact(2).map(|rows| match *rows {
             [r0, r1] => (r0, r1),
             _ => unreachable!(),
@@ -461,7 +771,7 @@ where
     #[inline(always)]
     pub(crate) fn iter_rows<'s>(&'s self, start_y: u32) -> impl Iterator<Item = &'b [P]> + 's {
         let start_y = start_y as usize;
-        let rows = self.rows.get(start_y..).unwrap_or_else(|| &[]);
+        let rows = self.rows.get(start_y..).unwrap_or(&[]);
         rows.iter().copied()
     }
 
@@ -522,6 +832,15 @@ impl<'a> ImageViewMut<'a> {
         }
         let rows_count = height.get() as usize;
         let rows = match pixel_type {
+            PixelType::U8x2 => {
+                let pixels = align_buffer_to_mut(buffer)?;
+                ImageRowsMut::U8x2(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U8x3 => {
                 let pixels = align_buffer_to_mut(buffer)?;
                 ImageRowsMut::U8x3(
@@ -540,6 +859,15 @@ impl<'a> ImageViewMut<'a> {
                         .collect(),
                 )
             }
+            PixelType::U16 => {
+                let pixels = align_buffer_to_mut(buffer)?;
+                ImageRowsMut::U16(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U16x3 => {
                 let pixels = align_buffer_to_mut(buffer)?;
                 ImageRowsMut::U16x3(
@@ -549,6 +877,15 @@ impl<'a> ImageViewMut<'a> {
                         .collect(),
                 )
             }
+            PixelType::U16x4 => {
+                let pixels = align_buffer_to_mut(buffer)?;
+                ImageRowsMut::U16x4(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::I32 => {
                 let pixels = align_buffer_to_mut(buffer)?;
                 ImageRowsMut::I32(
@@ -567,6 +904,24 @@ impl<'a> ImageViewMut<'a> {
                         .collect(),
                 )
             }
+            PixelType::F32x3 => {
+                let pixels = align_buffer_to_mut(buffer)?;
+                ImageRowsMut::F32x3(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
+            PixelType::F32x4 => {
+                let pixels = align_buffer_to_mut(buffer)?;
+                ImageRowsMut::F32x4(
+                    pixels
+                        .chunks_exact_mut(width.get() as usize)
+                        .take(rows_count)
+                        .collect(),
+                )
+            }
             PixelType::U8 => {
                 let pixels = align_buffer_to_mut(buffer)?;
                 ImageRowsMut::U8(
@@ -599,6 +954,18 @@ impl<'a> ImageViewMut<'a> {
         self.height
     }
 
+    pub(crate) fn u8x2_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, U8x2>> {
+        if let ImageRowsMut::U8x2(rows) = &mut self.rows {
+            Some(TypedImageViewMut {
+                width: self.width,
+                height: self.height,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn u8x3_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, U8x3>> {
         if let ImageRowsMut::U8x3(rows) = &mut self.rows {
             Some(TypedImageViewMut {
@@ -623,6 +990,18 @@ impl<'a> ImageViewMut<'a> {
         }
     }
 
+    pub(crate) fn u16_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, U16>> {
+        if let ImageRowsMut::U16(rows) = &mut self.rows {
+            Some(TypedImageViewMut {
+                width: self.width,
+                height: self.height,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn u16x3_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, U16x3>> {
         if let ImageRowsMut::U16x3(rows) = &mut self.rows {
             Some(TypedImageViewMut {
@@ -635,6 +1014,18 @@ impl<'a> ImageViewMut<'a> {
         }
     }
 
+    pub(crate) fn u16x4_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, U16x4>> {
+        if let ImageRowsMut::U16x4(rows) = &mut self.rows {
+            Some(TypedImageViewMut {
+                width: self.width,
+                height: self.height,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn i32_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, I32>> {
         if let ImageRowsMut::I32(rows) = &mut self.rows {
             Some(TypedImageViewMut {
@@ -659,6 +1050,30 @@ impl<'a> ImageViewMut<'a> {
         }
     }
 
+    pub(crate) fn f32x3_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, F32x3>> {
+        if let ImageRowsMut::F32x3(rows) = &mut self.rows {
+            Some(TypedImageViewMut {
+                width: self.width,
+                height: self.height,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn f32x4_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, F32x4>> {
+        if let ImageRowsMut::F32x4(rows) = &mut self.rows {
+            Some(TypedImageViewMut {
+                width: self.width,
+                height: self.height,
+                rows,
+            })
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn u8_image<'s>(&'s mut self) -> Option<TypedImageViewMut<'s, 'a, U8>> {
         if let ImageRowsMut::U8(rows) = &mut self.rows {
             Some(TypedImageViewMut {
@@ -705,7 +1120,7 @@ where
     }
 
     #[inline(always)]
-    pub fn iter_rows_mut(&mut self) -> slice::IterMut<&'b mut [P]> {
+    pub fn iter_rows_mut(&mut self) -> slice::IterMut<'_, &'b mut [P]> {
         self.rows.iter_mut()
     }
 
@@ -721,6 +1136,23 @@ where
     pub fn get_row_mut<'s>(&'s mut self, y: u32) -> Option<RowMut<'s, 'b, P>> {
         self.rows.get_mut(y as usize)
     }
+
+    /// Splits the image into parts with given height.
+    /// The last part may have a smaller height.
+    #[cfg(feature = "rayon")]
+    #[inline(always)]
+    pub(crate) fn split_by_height<'s>(
+        &'s mut self,
+        height: NonZeroU32,
+    ) -> impl Iterator<Item = TypedImageViewMut<'s, 'b, P>> {
+        let width = self.width;
+        self.rows
+            .chunks_mut(height.get() as usize)
+            .map(move |rows| {
+                let height = NonZeroU32::new(rows.len() as u32).unwrap();
+                TypedImageViewMut::new(width, height, rows)
+            })
+    }
 }
 
 fn check_rows_count_and_size<T>(
@@ -1,20 +1,32 @@
 #![doc = include_str!("../README.md")]
 
 pub use alpha::{MulDiv, MulDivImageError, MulDivImagesError};
-pub use convolution::FilterType;
+pub use convolution::{EdgeMode, Filter, FilterType, SamplingGrid};
 pub use errors::*;
-pub use image_view::{CropBox, ImageRows, ImageRowsMut, ImageView, ImageViewMut};
+pub use image_view::{
+    CropBox, CropMode, FloatCropBox, ImageRows, ImageRowsMut, ImageView, ImageViewMut,
+};
 pub use pixels::PixelType;
+pub use plan::ResizePlan;
 pub use resizer::{CpuExtensions, ResizeAlg, Resizer};
+pub use streaming::StreamingResizer;
 
 pub use crate::image::Image;
 
 mod alpha;
+pub mod color;
 mod convolution;
 mod errors;
+mod gamma;
 mod image;
 mod image_view;
+mod nodata;
 pub mod pixels;
+mod plan;
+mod reduce;
 mod resizer;
 #[cfg(target_arch = "x86_64")]
 mod simd_utils;
+mod statistics;
+mod streaming;
+mod threading;
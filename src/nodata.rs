@@ -0,0 +1,346 @@
+//! Resampling of images with missing samples marked by the nodata value.
+//!
+//! Components equal to the nodata value are excluded from convolution
+//! and reducing, weights of remaining components are renormalized.
+use crate::convolution::Coefficients;
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::Pixel;
+
+/// Component of pixel that may be equal to the nodata value.
+pub(crate) trait Component: Copy {
+    fn to_f64(self) -> f64;
+
+    /// Converts value into the component with rounding and saturation.
+    fn from_f64(value: f64) -> Self;
+
+    /// Bit pattern of the component which is the same for equal components
+    /// and for all `NaN` values.
+    fn class_bits(self) -> u32;
+}
+
+macro_rules! integer_component_impl {
+    ($($type:ty),+) => {
+        $(
+            impl Component for $type {
+                #[inline(always)]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline(always)]
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $type
+                }
+
+                #[inline(always)]
+                fn class_bits(self) -> u32 {
+                    self as u32
+                }
+            }
+        )+
+    };
+}
+
+integer_component_impl!(u8, u16, i32);
+
+impl Component for f32 {
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline(always)]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    #[inline(always)]
+    fn class_bits(self) -> u32 {
+        if self.is_nan() {
+            f32::NAN.to_bits()
+        } else if self == 0. {
+            // Negative zero is equal to positive one.
+            0
+        } else {
+            self.to_bits()
+        }
+    }
+}
+
+#[inline(always)]
+pub(crate) fn is_nodata(value: f64, nodata: f64) -> bool {
+    value == nodata || (value.is_nan() && nodata.is_nan())
+}
+
+pub(crate) trait NodataResample
+where
+    Self: Pixel + Send + Sync,
+{
+    /// Convolves the source image with excluding of components equal
+    /// to the nodata value.
+    ///
+    /// Bounds of vertical coefficients must be shifted by `y_first`
+    /// if horizontal coefficients are given.
+    fn nodata_convolution(
+        src_image: TypedImageView<Self>,
+        dst_image: TypedImageViewMut<Self>,
+        y_first: u32,
+        coeffs: (Option<&Coefficients>, Option<&Coefficients>),
+        nodata: f64,
+    );
+
+    /// Reduces the source image by given factors with averaging of
+    /// components that are not equal to the nodata value.
+    fn nodata_box_reduce(
+        src_image: TypedImageView<Self>,
+        dst_image: TypedImageViewMut<Self>,
+        factors: (u32, u32),
+        nodata: f64,
+    );
+}
+
+impl<P> NodataResample for P
+where
+    P: Pixel + Send + Sync,
+    P::Component: Component,
+{
+    fn nodata_convolution(
+        src_image: TypedImageView<Self>,
+        dst_image: TypedImageViewMut<Self>,
+        y_first: u32,
+        coeffs: (Option<&Coefficients>, Option<&Coefficients>),
+        nodata: f64,
+    ) {
+        convolution(src_image, dst_image, y_first, coeffs, nodata);
+    }
+
+    fn nodata_box_reduce(
+        src_image: TypedImageView<Self>,
+        dst_image: TypedImageViewMut<Self>,
+        factors: (u32, u32),
+        nodata: f64,
+    ) {
+        box_reduce(src_image, dst_image, factors, nodata);
+    }
+}
+
+/// Weighted sums of valid components of rows of the image
+/// and sums of their weights.
+struct WeightedRows {
+    /// Count of components in a row
+    row_len: usize,
+    sums: Vec<f64>,
+    weights: Vec<f64>,
+}
+
+impl WeightedRows {
+    fn new(row_len: usize, rows_count: usize) -> Self {
+        Self {
+            row_len,
+            sums: vec![0.; row_len * rows_count],
+            weights: vec![0.; row_len * rows_count],
+        }
+    }
+
+    fn row(&self, y: usize) -> (&[f64], &[f64]) {
+        let range = y * self.row_len..(y + 1) * self.row_len;
+        (&self.sums[range.clone()], &self.weights[range])
+    }
+
+    fn row_mut(&mut self, y: usize) -> (&mut [f64], &mut [f64]) {
+        let range = y * self.row_len..(y + 1) * self.row_len;
+        (&mut self.sums[range.clone()], &mut self.weights[range])
+    }
+}
+
+/// Minimal ratio of the total weight of valid taps to the total absolute
+/// weight of the window. Weights of filters with negative lobes may
+/// almost cancel each other, so renormalization by their sum would
+/// amplify values of few valid taps.
+const MIN_VALID_WEIGHT_RATIO: f64 = 0.1;
+
+/// Relative tolerance of comparison of weights with the minimal weight.
+/// Coefficients of filters are rounded, so windows with exactly 10% of
+/// valid weight must not lose their value because of rounding errors.
+const WEIGHT_TOLERANCE: f64 = 1e-9;
+
+/// Returns `true` if the total weight of valid taps is enough to
+/// renormalize the window with given total absolute weight.
+#[inline(always)]
+fn is_enough_weight(weight: f64, abs_weight: f64) -> bool {
+    weight.abs() >= MIN_VALID_WEIGHT_RATIO * abs_weight * (1. - WEIGHT_TOLERANCE)
+}
+
+/// Returns sums of absolute values of coefficients of every chunk.
+fn abs_weights(coeffs: Option<&Coefficients>, len: usize) -> Vec<f64> {
+    match coeffs {
+        Some(coeffs) => coeffs
+            .get_chunks()
+            .iter()
+            .map(|chunk| chunk.values.iter().map(|w| w.abs()).sum())
+            .collect(),
+        None => vec![1.; len],
+    }
+}
+
+/// Convolution is done in two passes. The horizontal pass keeps weighted
+/// sums of valid components and sums of their weights separately,
+/// so the vertical pass renormalizes the result by the total weight of
+/// valid taps of the two-dimensional window.
+///
+/// Components are set to the nodata value if the total weight of valid
+/// taps is too small relative to the total absolute weight of the window.
+fn convolution<P>(
+    src_image: TypedImageView<P>,
+    mut dst_image: TypedImageViewMut<P>,
+    y_first: u32,
+    coeffs: (Option<&Coefficients>, Option<&Coefficients>),
+    nodata: f64,
+) where
+    P: Pixel,
+    P::Component: Component,
+{
+    let (horiz_coeffs, vert_coeffs) = coeffs;
+    let cc = P::components_count();
+    // Bounds of vertical coefficients are relative to the first used row
+    // only if the horizontal pass is required.
+    let bounds_offset = match horiz_coeffs {
+        Some(_) => 0,
+        None => y_first,
+    };
+    let rows_count = match vert_coeffs {
+        Some(coeffs) => {
+            let last_bound = coeffs.bounds.last().unwrap();
+            (last_bound.start + last_bound.size - bounds_offset) as usize
+        }
+        None => dst_image.height().get() as usize,
+    };
+    let row_len = match horiz_coeffs {
+        Some(coeffs) => coeffs.bounds.len() * cc,
+        None => src_image.width().get() as usize * cc,
+    };
+
+    let horiz_chunks = horiz_coeffs.map(|coeffs| coeffs.get_chunks());
+    let mut rows = WeightedRows::new(row_len, rows_count);
+    let src_rows = src_image.iter_rows(y_first).take(rows_count);
+    for (y, src_row) in src_rows.enumerate() {
+        let src_row = P::components(src_row);
+        let (sums, weights) = rows.row_mut(y);
+        match &horiz_chunks {
+            Some(chunks) => {
+                let dst_pixels = sums.chunks_exact_mut(cc).zip(weights.chunks_exact_mut(cc));
+                for (chunk, (sums, weights)) in chunks.iter().zip(dst_pixels) {
+                    let first = chunk.start as usize * cc;
+                    let pixels = src_row[first..].chunks_exact(cc);
+                    for (&w, pixel) in chunk.values.iter().zip(pixels) {
+                        for c in 0..cc {
+                            let value = pixel[c].to_f64();
+                            if !is_nodata(value, nodata) {
+                                sums[c] += w * value;
+                                weights[c] += w;
+                            }
+                        }
+                    }
+                }
+            }
+            None => {
+                for ((sum, weight), &component) in sums.iter_mut().zip(weights).zip(src_row) {
+                    let value = component.to_f64();
+                    if !is_nodata(value, nodata) {
+                        *sum = value;
+                        *weight = 1.;
+                    }
+                }
+            }
+        }
+    }
+
+    let vert_chunks = vert_coeffs.map(|coeffs| coeffs.get_chunks());
+    let horiz_abs_weights = abs_weights(horiz_coeffs, row_len / cc);
+    let vert_abs_weights = abs_weights(vert_coeffs, dst_image.height().get() as usize);
+    let mut dst_sums = vec![0.; row_len];
+    let mut dst_weights = vec![0.; row_len];
+    for (y, dst_row) in dst_image.iter_rows_mut().enumerate() {
+        let (sums, weights) = match &vert_chunks {
+            Some(chunks) => {
+                let chunk = chunks[y];
+                dst_sums.fill(0.);
+                dst_weights.fill(0.);
+                let first = (chunk.start - bounds_offset) as usize;
+                for (i, &w) in chunk.values.iter().enumerate() {
+                    let (sums, weights) = rows.row(first + i);
+                    for (dst_sum, &sum) in dst_sums.iter_mut().zip(sums) {
+                        *dst_sum += w * sum;
+                    }
+                    for (dst_weight, &weight) in dst_weights.iter_mut().zip(weights) {
+                        *dst_weight += w * weight;
+                    }
+                }
+                (dst_sums.as_slice(), dst_weights.as_slice())
+            }
+            None => rows.row(y),
+        };
+        let dst_pixels = P::components_mut(dst_row).chunks_exact_mut(cc);
+        let src_pixels = sums.chunks_exact(cc).zip(weights.chunks_exact(cc));
+        for ((dst_pixel, (sums, weights)), &horiz_abs_weight) in
+            dst_pixels.zip(src_pixels).zip(&horiz_abs_weights)
+        {
+            let abs_weight = horiz_abs_weight * vert_abs_weights[y];
+            for ((component, &sum), &weight) in dst_pixel.iter_mut().zip(sums).zip(weights) {
+                *component = if is_enough_weight(weight, abs_weight) {
+                    Component::from_f64(sum / weight)
+                } else {
+                    Component::from_f64(nodata)
+                };
+            }
+        }
+    }
+}
+
+/// Components are set to the nodata value if valid components are less
+/// than 10% of the block, like by convolution with the `Box` filter.
+fn box_reduce<P>(
+    src_image: TypedImageView<P>,
+    mut dst_image: TypedImageViewMut<P>,
+    factors: (u32, u32),
+    nodata: f64,
+) where
+    P: Pixel,
+    P::Component: Component,
+{
+    let (factor_x, factor_y) = (factors.0 as usize, factors.1);
+    let block_size = factor_x as f64 * factor_y as f64;
+    let cc = P::components_count();
+    let row_len = dst_image.width().get() as usize * cc;
+    let mut sums = vec![0.; row_len];
+    let mut counts = vec![0u32; row_len];
+
+    for (dst_y, dst_row) in dst_image.iter_rows_mut().enumerate() {
+        sums.fill(0.);
+        counts.fill(0);
+        let src_rows = src_image
+            .iter_rows(dst_y as u32 * factor_y)
+            .take(factor_y as usize);
+        for src_row in src_rows {
+            let src_row = P::components(src_row);
+            for (x, pixel) in src_row.chunks_exact(cc).enumerate() {
+                let dst_x = x / factor_x * cc;
+                for c in 0..cc {
+                    let value = pixel[c].to_f64();
+                    if !is_nodata(value, nodata) {
+                        sums[dst_x + c] += value;
+                        counts[dst_x + c] += 1;
+                    }
+                }
+            }
+        }
+        let dst_components = P::components_mut(dst_row);
+        for ((component, &sum), &count) in dst_components.iter_mut().zip(&sums).zip(&counts) {
+            *component = if count > 0 && is_enough_weight(count as f64, block_size) {
+                Component::from_f64(sum / count as f64)
+            } else {
+                Component::from_f64(nodata)
+            };
+        }
+    }
+}
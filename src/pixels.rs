@@ -5,19 +5,29 @@ use std::slice;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum PixelType {
+    U8x2,
     U8x3,
     U8x4,
+    U16,
     U16x3,
+    U16x4,
     I32,
     F32,
+    F32x3,
+    F32x4,
     U8,
 }
 
 impl PixelType {
     pub(crate) fn size(&self) -> usize {
         match self {
+            Self::U8x2 => 2,
             Self::U8x3 => 3,
+            Self::U16 => 2,
             Self::U16x3 => 6,
+            Self::U16x4 => 8,
+            Self::F32x3 => 12,
+            Self::F32x4 => 16,
             Self::U8 => 1,
             _ => 4,
         }
@@ -26,11 +36,16 @@ impl PixelType {
     /// Returns `true` if given buffer is aligned by the alignment of pixel.
     pub(crate) fn is_aligned(&self, buffer: &[u8]) -> bool {
         match self {
+            Self::U8x2 => unsafe { buffer.align_to::<U8x2>().0.is_empty() },
             Self::U8x3 => unsafe { buffer.align_to::<U8x3>().0.is_empty() },
             Self::U8x4 => unsafe { buffer.align_to::<U8x4>().0.is_empty() },
+            Self::U16 => unsafe { buffer.align_to::<U16>().0.is_empty() },
             Self::U16x3 => unsafe { buffer.align_to::<U16x3>().0.is_empty() },
+            Self::U16x4 => unsafe { buffer.align_to::<U16x4>().0.is_empty() },
             Self::I32 => unsafe { buffer.align_to::<I32>().0.is_empty() },
             Self::F32 => unsafe { buffer.align_to::<F32>().0.is_empty() },
+            Self::F32x3 => unsafe { buffer.align_to::<F32x3>().0.is_empty() },
+            Self::F32x4 => unsafe { buffer.align_to::<F32x4>().0.is_empty() },
             Self::U8 => true,
         }
     }
@@ -98,6 +113,14 @@ macro_rules! pixel_struct {
 }
 
 pixel_struct!(U8, u8, u8, 1, PixelType::U8, "One byte per pixel");
+pixel_struct!(
+    U8x2,
+    u16,
+    u8,
+    2,
+    PixelType::U8x2,
+    "Two bytes per pixel (e.g. LA)"
+);
 pixel_struct!(
     U8x3,
     [u8; 3],
@@ -114,6 +137,14 @@ pixel_struct!(
     PixelType::U8x4,
     "Four bytes per pixel (RGBA, RGBx, CMYK and other)"
 );
+pixel_struct!(
+    U16,
+    u16,
+    u16,
+    1,
+    PixelType::U16,
+    "One `u16` component per pixel (e.g. L16)"
+);
 pixel_struct!(
     U16x3,
     [u16; 3],
@@ -122,6 +153,14 @@ pixel_struct!(
     PixelType::U16x3,
     "Three `u16` components per pixel (e.g. RGB)"
 );
+pixel_struct!(
+    U16x4,
+    [u16; 4],
+    u16,
+    4,
+    PixelType::U16x4,
+    "Four `u16` components per pixel (e.g. RGBA)"
+);
 pixel_struct!(
     I32,
     i32,
@@ -138,3 +177,19 @@ pixel_struct!(
     PixelType::F32,
     "One `f32` component per pixel"
 );
+pixel_struct!(
+    F32x3,
+    [f32; 3],
+    f32,
+    3,
+    PixelType::F32x3,
+    "Three `f32` components per pixel (e.g. RGB)"
+);
+pixel_struct!(
+    F32x4,
+    [f32; 4],
+    f32,
+    4,
+    PixelType::F32x4,
+    "Four `f32` components per pixel (e.g. RGBA)"
+);
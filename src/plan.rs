@@ -0,0 +1,726 @@
+use std::num::NonZeroU32;
+
+use crate::convolution::{
+    self, Bound, Coefficients, CoefficientsOptions, EdgeMode, FilterType, SamplingGrid,
+};
+use crate::errors::CropBoxError;
+use crate::image_view::{CropBox, CropMode, FloatCropBox};
+use crate::pixels::PixelType;
+use crate::reduce;
+use crate::statistics::Statistic;
+use crate::ResizeAlg;
+
+/// Maximal value of the blur factor. Bigger values only make the filter
+/// flatter, because its radius is clamped by the size of the image.
+const MAX_BLUR: f64 = 1000.;
+
+/// Precomputed data required to resize images with the same
+/// type of pixels, sizes and crop box of the source image,
+/// size of the destination image, algorithm of resizing and options
+/// of resizing (blur factor, edge mode, sampling grid, crop mode
+/// and nodata value).
+///
+/// The plan caches coefficients of convolution and its normalized
+/// integer representation, so it may be used to resize many images
+/// (e.g. frames of video) without recomputing them.
+/// The plan is immutable and may be shared between threads.
+/// Use [Resizer::resize_with_plan](crate::Resizer::resize_with_plan)
+/// to execute the plan.
+#[derive(Debug, Clone)]
+pub struct ResizePlan {
+    pub(crate) algorithm: ResizeAlg,
+    pub(crate) pixel_type: PixelType,
+    pub(crate) src_width: NonZeroU32,
+    pub(crate) src_height: NonZeroU32,
+    pub(crate) crop_box: FloatCropBox,
+    pub(crate) dst_width: NonZeroU32,
+    pub(crate) dst_height: NonZeroU32,
+    pub(crate) options: CoefficientsOptions,
+    pub(crate) crop_mode: CropMode,
+    pub(crate) nodata: Option<f64>,
+    pub(crate) steps: PlanSteps,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum PlanSteps {
+    Nearest,
+    Convolution(ConvolutionPlan),
+    SuperSampling {
+        /// Size of the temporary image that is created by
+        /// nearest resampling of the source image.
+        tmp_size: Option<(NonZeroU32, NonZeroU32)>,
+        convolution: ConvolutionPlan,
+    },
+    Reduce(ReducePlan),
+    ReduceAndConvolution {
+        /// Reducing of the source image into the temporary image.
+        reduce: ReducePlan,
+        convolution: ConvolutionPlan,
+    },
+    Footprint {
+        statistic: Statistic,
+        footprints: Footprints,
+    },
+}
+
+/// Ranges of columns and rows of the source image covered
+/// by pixels of the destination image.
+#[derive(Debug, Clone)]
+pub(crate) struct Footprints {
+    pub columns: Vec<Bound>,
+    pub rows: Vec<Bound>,
+}
+
+/// Reducing of the region of the source image by integer factors
+/// with averaging of blocks of pixels.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReducePlan {
+    pub src_region: CropBox,
+    /// Horizontal and vertical factors of reducing
+    pub factors: (u32, u32),
+}
+
+/// Kernels of horizontal and vertical passes of convolution.
+#[derive(Debug, Clone, Copy)]
+enum Kernels<'a> {
+    /// Horizontal and vertical filters
+    Filters(&'a FilterType, &'a FilterType),
+    /// Coefficients are equal to areas of pixels of the source image
+    /// covered by pixels of the destination image.
+    Area,
+}
+
+/// Coefficients of passes of convolution.
+#[derive(Debug, Clone)]
+pub(crate) struct ConvolutionPlan {
+    pub horiz_coeffs: Option<Coefficients>,
+    /// Bounds of vertical coefficients are shifted by `y_first`
+    /// if the horizontal pass is required.
+    pub vert_coeffs: Option<Coefficients>,
+    /// First used row in the source image
+    pub y_first: u32,
+    /// Count of columns and rows that the source image must be
+    /// extended by from both sides in `EdgeMode::Wrap` mode.
+    pub wrap_padding: (u32, u32),
+    /// Region of the source image that is used as the whole image
+    /// in `CropMode::Boundary` mode.
+    pub src_region: Option<CropBox>,
+}
+
+impl ResizePlan {
+    /// Creates plan to resize image with given type of pixels, size and
+    /// crop box into image with given size.
+    pub fn new(
+        algorithm: ResizeAlg,
+        pixel_type: PixelType,
+        src_width: NonZeroU32,
+        src_height: NonZeroU32,
+        crop_box: FloatCropBox,
+        dst_width: NonZeroU32,
+        dst_height: NonZeroU32,
+    ) -> Result<Self, CropBoxError> {
+        crop_box.check(src_width, src_height)?;
+
+        let mut plan = Self {
+            algorithm,
+            pixel_type,
+            src_width,
+            src_height,
+            crop_box,
+            dst_width,
+            dst_height,
+            options: Default::default(),
+            crop_mode: CropMode::Surrounding,
+            nodata: None,
+            steps: PlanSteps::Nearest,
+        };
+        plan.steps = plan.build_steps();
+        Ok(plan)
+    }
+
+    /// Returns plan with given blur factor of convolution filters.
+    ///
+    /// Blur factor stretches (`blur > 1`) or shrinks (`blur < 1`) support
+    /// and argument of the filter. Values greater than 1 reduce aliasing
+    /// and make the result softer, values less than 1 make the result sharper.
+    /// Non-finite and non-positive values are replaced by 1,
+    /// values greater than 1000 are replaced by 1000.
+    /// Image is convolved with the filter even if its size isn't changed.
+    /// Blur factor doesn't affect `ResizeAlg::Nearest` and `ResizeAlg::Area`.
+    pub fn with_blur(self, blur: f64) -> Self {
+        let options = CoefficientsOptions {
+            blur,
+            ..self.options
+        };
+        let crop_mode = self.crop_mode;
+        self.with_options(options, crop_mode)
+    }
+
+    /// Returns plan with given mode of handling of filter's taps
+    /// that are outside of the source image.
+    /// Edge mode doesn't affect `ResizeAlg::Nearest`, `ResizeAlg::Area`,
+    /// `ResizeAlg::Mode`, `ResizeAlg::Min` and `ResizeAlg::Max`.
+    pub fn with_edge_mode(self, edge_mode: EdgeMode) -> Self {
+        let options = CoefficientsOptions {
+            edge_mode,
+            ..self.options
+        };
+        let crop_mode = self.crop_mode;
+        self.with_options(options, crop_mode)
+    }
+
+    /// Returns plan with given mapping of positions of pixels of
+    /// the destination image into positions in the source image.
+    /// Sampling grid doesn't affect `ResizeAlg::Area`.
+    pub fn with_sampling_grid(self, sampling_grid: SamplingGrid) -> Self {
+        let options = CoefficientsOptions {
+            sampling_grid,
+            ..self.options
+        };
+        let crop_mode = self.crop_mode;
+        self.with_options(options, crop_mode)
+    }
+
+    /// Returns plan with given mode of sampling of pixels near edges
+    /// of the crop box. It must be equal to the crop mode of source images.
+    /// Crop mode doesn't affect `ResizeAlg::Nearest`.
+    pub fn with_crop_mode(self, crop_mode: CropMode) -> Self {
+        let options = self.options;
+        self.with_options(options, crop_mode)
+    }
+
+    /// Returns plan with given value of components of pixels
+    /// that marks missing samples of the source image.
+    ///
+    /// Components equal to the nodata value are excluded from
+    /// convolution, reducing and statistics of footprints, weights of
+    /// remaining components are renormalized for every pixel of
+    /// the destination image.
+    /// If all components in the window of the filter are missing or
+    /// the total weight of valid components is less than 10% of
+    /// the total absolute weight of the window, the component of
+    /// the destination image is set to the nodata value. Windows with
+    /// exactly 10% of valid weight are renormalized. Reducing by integer
+    /// factors uses the same threshold for the count of valid components
+    /// of the block. The threshold isn't configurable.
+    /// `NaN` matches any `NaN` component of images with `f32` components.
+    ///
+    /// Resizing with the nodata value doesn't use SIMD-instructions.
+    /// Nodata value doesn't affect `ResizeAlg::Nearest`.
+    /// Plans with the nodata value are not supported by `StreamingResizer`.
+    pub fn with_nodata(mut self, nodata: Option<f64>) -> Self {
+        self.nodata = nodata;
+        self
+    }
+
+    pub(crate) fn with_options(
+        mut self,
+        mut options: CoefficientsOptions,
+        crop_mode: CropMode,
+    ) -> Self {
+        if !(options.blur.is_finite() && options.blur > 0.) {
+            options.blur = 1.;
+        }
+        options.blur = options.blur.min(MAX_BLUR);
+        if options != self.options || crop_mode != self.crop_mode {
+            self.options = options;
+            self.crop_mode = crop_mode;
+            self.steps = self.build_steps();
+        }
+        self
+    }
+
+    fn build_steps(&self) -> PlanSteps {
+        let (dst_width, dst_height) = (self.dst_width, self.dst_height);
+        let crop_box = self.crop_box;
+        match &self.algorithm {
+            ResizeAlg::Nearest => PlanSteps::Nearest,
+            ResizeAlg::Convolution(filter_type) => PlanSteps::Convolution(
+                self.src_convolution_plan(Kernels::Filters(filter_type, filter_type)),
+            ),
+            ResizeAlg::SeparateConvolution {
+                horizontal,
+                vertical,
+            } => PlanSteps::Convolution(
+                self.src_convolution_plan(Kernels::Filters(horizontal, vertical)),
+            ),
+            ResizeAlg::SuperSampling(filter_type, multiplicity) => {
+                let multiplicity = *multiplicity;
+                let width_scale = crop_box.width as f32 / dst_width.get() as f32;
+                let height_scale = crop_box.height as f32 / dst_height.get() as f32;
+                // It makes sense to resize the image in two steps only if the image
+                // size is greater than the required size by multiplicity times.
+                let factor = width_scale.min(height_scale) / multiplicity as f32;
+                if factor > 1.2 {
+                    // The temporary image will be about ``multiplicity`` times larger
+                    // than required.
+                    let tmp_width =
+                        NonZeroU32::new((crop_box.width as f32 / factor).round() as u32).unwrap();
+                    let tmp_height =
+                        NonZeroU32::new((crop_box.height as f32 / factor).round() as u32).unwrap();
+                    let tmp_crop_box = FloatCropBox::new(tmp_width, tmp_height);
+                    PlanSteps::SuperSampling {
+                        tmp_size: Some((tmp_width, tmp_height)),
+                        convolution: ConvolutionPlan::new(
+                            tmp_width,
+                            tmp_height,
+                            tmp_crop_box,
+                            dst_width,
+                            dst_height,
+                            Kernels::Filters(filter_type, filter_type),
+                            self.options,
+                        ),
+                    }
+                } else {
+                    // There is no point in doing the resizing in two steps.
+                    PlanSteps::SuperSampling {
+                        tmp_size: None,
+                        convolution: self
+                            .src_convolution_plan(Kernels::Filters(filter_type, filter_type)),
+                    }
+                }
+            }
+            ResizeAlg::Reduce(filter_type, gap) => self.reduce_steps(filter_type, *gap),
+            ResizeAlg::Area => PlanSteps::Convolution(self.src_convolution_plan(Kernels::Area)),
+            ResizeAlg::Mode => self.footprint_steps(Statistic::Mode),
+            ResizeAlg::Min => self.footprint_steps(Statistic::Min),
+            ResizeAlg::Max => self.footprint_steps(Statistic::Max),
+        }
+    }
+
+    /// Returns steps of computing of the statistic of pixels of the source
+    /// image covered by the `Box` filter of every pixel of the destination image.
+    fn footprint_steps(&self, statistic: Statistic) -> PlanSteps {
+        let crop_box = self.crop_box;
+        let region = match self.crop_mode {
+            CropMode::Surrounding => CropBox {
+                left: 0,
+                top: 0,
+                width: self.src_width,
+                height: self.src_height,
+            },
+            CropMode::Boundary => self.covering_region(0., 0.),
+        };
+        // Taps of the filter outside of the image are ignored.
+        let options = CoefficientsOptions {
+            edge_mode: EdgeMode::Renormalize,
+            ..self.options
+        };
+        let (filter_fn, filter_support) = convolution::get_filter_func(&FilterType::Box);
+        let footprints = |offset: u32, size: NonZeroU32, in0: f64, in_length: f64, out_size| {
+            let in0 = in0 - offset as f64;
+            let coeffs = convolution::precompute_coefficients(
+                size,
+                in0,
+                in0 + in_length,
+                out_size,
+                filter_fn.as_ref(),
+                filter_support,
+                options,
+            );
+            coeffs
+                .get_chunks()
+                .iter()
+                .map(|chunk| {
+                    // Pixels with zero weights are not covered by the filter.
+                    let first = chunk.values.iter().position(|&w| w != 0.).unwrap_or(0);
+                    let last = chunk.values.iter().rposition(|&w| w != 0.).unwrap_or(0);
+                    Bound {
+                        start: offset + chunk.start + first as u32,
+                        size: (last - first + 1) as u32,
+                    }
+                })
+                .collect()
+        };
+        PlanSteps::Footprint {
+            statistic,
+            footprints: Footprints {
+                columns: footprints(
+                    region.left,
+                    region.width,
+                    crop_box.left,
+                    crop_box.width,
+                    self.dst_width,
+                ),
+                rows: footprints(
+                    region.top,
+                    region.height,
+                    crop_box.top,
+                    crop_box.height,
+                    self.dst_height,
+                ),
+            },
+        }
+    }
+
+    fn reduce_steps(&self, filter_type: &FilterType, gap: f64) -> PlanSteps {
+        let crop_box = self.crop_box;
+        let gap = if gap >= 1. && gap.is_finite() {
+            gap
+        } else {
+            1.
+        };
+        let factor = |size: f64, dst_size: NonZeroU32| {
+            ((size / dst_size.get() as f64 / gap) as u32).clamp(1, reduce::MAX_FACTOR)
+        };
+        let factors = (
+            factor(crop_box.width, self.dst_width),
+            factor(crop_box.height, self.dst_height),
+        );
+        if factors == (1, 1) {
+            // There is no point in reducing the image.
+            let kernels = Kernels::Filters(filter_type, filter_type);
+            return PlanSteps::Convolution(self.src_convolution_plan(kernels));
+        }
+        if let Some(reduce) = self.exact_reduce_plan(factors) {
+            // The reduced image has the size of the destination image.
+            return PlanSteps::Reduce(reduce);
+        }
+
+        // Only the region of the source image that is used
+        // by the convolution is reduced.
+        let (factor_x, factor_y) = factors;
+        let src_region = match (self.crop_mode, self.options.edge_mode) {
+            (CropMode::Boundary, _) => self.covering_region(0., 0.),
+            // Taps outside the image are wrapped around to the opposite edge.
+            (CropMode::Surrounding, EdgeMode::Wrap) => {
+                self.covering_region(f64::INFINITY, f64::INFINITY)
+            }
+            (CropMode::Surrounding, _) => {
+                let (_, support) = convolution::get_filter_func(filter_type);
+                let margin = |size: f64, dst_size: NonZeroU32, factor: u32| {
+                    let scale = (size / dst_size.get() as f64).max(factor as f64);
+                    (support * scale * self.options.blur).ceil() + factor as f64
+                };
+                self.covering_region(
+                    margin(crop_box.width, self.dst_width, factor_x),
+                    margin(crop_box.height, self.dst_height, factor_y),
+                )
+            }
+        };
+        let reduce = ReducePlan {
+            src_region,
+            factors,
+        };
+
+        let (tmp_width, tmp_height) = reduce.dst_size();
+        let tmp_crop_box = FloatCropBox {
+            left: (crop_box.left - src_region.left as f64) / factor_x as f64,
+            top: (crop_box.top - src_region.top as f64) / factor_y as f64,
+            width: crop_box.width / factor_x as f64,
+            height: crop_box.height / factor_y as f64,
+        };
+        PlanSteps::ReduceAndConvolution {
+            reduce,
+            convolution: ConvolutionPlan::new(
+                tmp_width,
+                tmp_height,
+                tmp_crop_box,
+                self.dst_width,
+                self.dst_height,
+                Kernels::Filters(filter_type, filter_type),
+                self.options,
+            ),
+        }
+    }
+
+    /// Returns plan of reducing of the crop box by given factors
+    /// if the reduced image has the size of the destination image.
+    fn exact_reduce_plan(&self, factors: (u32, u32)) -> Option<ReducePlan> {
+        let crop_box = self.crop_box;
+        let is_exact = |position: f64, size: f64, factor: u32, dst_size: NonZeroU32| {
+            position.fract() == 0. && size == factor as f64 * dst_size.get() as f64
+        };
+        let is_exact = is_exact(crop_box.left, crop_box.width, factors.0, self.dst_width)
+            && is_exact(crop_box.top, crop_box.height, factors.1, self.dst_height);
+        is_exact.then(|| ReducePlan {
+            src_region: CropBox {
+                left: crop_box.left as u32,
+                top: crop_box.top as u32,
+                width: NonZeroU32::new(crop_box.width as u32).unwrap(),
+                height: NonZeroU32::new(crop_box.height as u32).unwrap(),
+            },
+            factors,
+        })
+    }
+
+    /// Returns region of the source image with pixels covered by the crop box
+    /// extended by given margins.
+    fn covering_region(&self, margin_x: f64, margin_y: f64) -> CropBox {
+        let crop_box = self.crop_box;
+        let left = (crop_box.left - margin_x).floor().max(0.) as u32;
+        let top = (crop_box.top - margin_y).floor().max(0.) as u32;
+        // Region always covers at least one pixel, even if too small
+        // size of the crop box is lost by rounding.
+        let right = ((crop_box.left + crop_box.width + margin_x).ceil() as u32)
+            .max(left + 1)
+            .min(self.src_width.get());
+        let bottom = ((crop_box.top + crop_box.height + margin_y).ceil() as u32)
+            .max(top + 1)
+            .min(self.src_height.get());
+        CropBox {
+            left,
+            top,
+            width: NonZeroU32::new(right - left).unwrap(),
+            height: NonZeroU32::new(bottom - top).unwrap(),
+        }
+    }
+
+    /// Returns region of the source image with pixels that are read
+    /// while resizing.
+    pub(crate) fn used_src_region(&self) -> CropBox {
+        let whole_image = CropBox {
+            left: 0,
+            top: 0,
+            width: self.src_width,
+            height: self.src_height,
+        };
+        match &self.steps {
+            PlanSteps::Nearest | PlanSteps::Footprint { .. } => whole_image,
+            PlanSteps::Convolution(convolution)
+            | PlanSteps::SuperSampling {
+                tmp_size: None,
+                convolution,
+            } => convolution.used_src_region(whole_image),
+            PlanSteps::SuperSampling { .. } => self.covering_region(0., 0.),
+            PlanSteps::Reduce(reduce) | PlanSteps::ReduceAndConvolution { reduce, .. } => {
+                reduce.src_region
+            }
+        }
+    }
+
+    /// Returns plan of convolution of the source image.
+    fn src_convolution_plan(&self, kernels: Kernels) -> ConvolutionPlan {
+        let crop_box = self.crop_box;
+        let region = match self.crop_mode {
+            CropMode::Surrounding => None,
+            CropMode::Boundary => Some(self.covering_region(0., 0.))
+                .filter(|r| r.width != self.src_width || r.height != self.src_height),
+        };
+        match region {
+            Some(region) => {
+                // The region is used as the whole source image.
+                let region_crop_box = FloatCropBox {
+                    left: crop_box.left - region.left as f64,
+                    top: crop_box.top - region.top as f64,
+                    ..crop_box
+                };
+                let mut plan = ConvolutionPlan::new(
+                    region.width,
+                    region.height,
+                    region_crop_box,
+                    self.dst_width,
+                    self.dst_height,
+                    kernels,
+                    self.options,
+                );
+                plan.src_region = Some(region);
+                plan
+            }
+            None => ConvolutionPlan::new(
+                self.src_width,
+                self.src_height,
+                crop_box,
+                self.dst_width,
+                self.dst_height,
+                kernels,
+                self.options,
+            ),
+        }
+    }
+
+    #[inline(always)]
+    pub fn algorithm(&self) -> &ResizeAlg {
+        &self.algorithm
+    }
+
+    #[inline(always)]
+    pub fn pixel_type(&self) -> PixelType {
+        self.pixel_type
+    }
+
+    #[inline(always)]
+    pub fn src_width(&self) -> NonZeroU32 {
+        self.src_width
+    }
+
+    #[inline(always)]
+    pub fn src_height(&self) -> NonZeroU32 {
+        self.src_height
+    }
+
+    #[inline(always)]
+    pub fn crop_box(&self) -> FloatCropBox {
+        self.crop_box
+    }
+
+    #[inline(always)]
+    pub fn dst_width(&self) -> NonZeroU32 {
+        self.dst_width
+    }
+
+    #[inline(always)]
+    pub fn dst_height(&self) -> NonZeroU32 {
+        self.dst_height
+    }
+
+    #[inline(always)]
+    pub fn blur(&self) -> f64 {
+        self.options.blur
+    }
+
+    #[inline(always)]
+    pub fn edge_mode(&self) -> EdgeMode {
+        self.options.edge_mode
+    }
+
+    #[inline(always)]
+    pub fn sampling_grid(&self) -> SamplingGrid {
+        self.options.sampling_grid
+    }
+
+    #[inline(always)]
+    pub fn crop_mode(&self) -> CropMode {
+        self.crop_mode
+    }
+
+    #[inline(always)]
+    pub fn nodata(&self) -> Option<f64> {
+        self.nodata
+    }
+}
+
+impl ReducePlan {
+    /// Returns size of the reduced image.
+    pub fn dst_size(&self) -> (NonZeroU32, NonZeroU32) {
+        let (factor_x, factor_y) = self.factors;
+        let width = self.src_region.width.get().div_ceil(factor_x);
+        let height = self.src_region.height.get().div_ceil(factor_y);
+        (
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+        )
+    }
+}
+
+impl ConvolutionPlan {
+    /// Returns region of the source image with pixels covered
+    /// by coefficients of convolution.
+    fn used_src_region(&self, whole_image: CropBox) -> CropBox {
+        let image = self.src_region.unwrap_or(whole_image);
+        if self.wrap_padding != (0, 0) {
+            // Taps outside the image are wrapped around to the opposite edge.
+            return image;
+        }
+        let span = |bounds: &[Bound]| {
+            let start = bounds.iter().map(|b| b.start).min().unwrap_or(0);
+            let end = bounds.iter().map(|b| b.start + b.size).max().unwrap_or(0);
+            (start, end)
+        };
+        let (left, right) = match &self.horiz_coeffs {
+            Some(coeffs) => span(&coeffs.bounds),
+            None => (0, image.width.get()),
+        };
+        let (top, bottom) = match (&self.horiz_coeffs, &self.vert_coeffs) {
+            // Bounds of vertical coefficients are shifted by `y_first`.
+            (Some(_), Some(coeffs)) => (self.y_first, self.y_first + span(&coeffs.bounds).1),
+            (None, Some(coeffs)) => span(&coeffs.bounds),
+            (_, None) => (0, image.height.get()),
+        };
+        match (
+            NonZeroU32::new(right.saturating_sub(left)),
+            NonZeroU32::new(bottom.saturating_sub(top)),
+        ) {
+            (Some(width), Some(height)) => CropBox {
+                left: image.left + left,
+                top: image.top + top,
+                width,
+                height,
+            },
+            _ => image,
+        }
+    }
+
+    fn new(
+        src_width: NonZeroU32,
+        src_height: NonZeroU32,
+        crop_box: FloatCropBox,
+        dst_width: NonZeroU32,
+        dst_height: NonZeroU32,
+        kernels: Kernels,
+        options: CoefficientsOptions,
+    ) -> Self {
+        let coefficients = |in_size: NonZeroU32,
+                            in0: f64,
+                            in1: f64,
+                            out_size: NonZeroU32,
+                            filter: Option<&FilterType>| match filter {
+            Some(filter) => {
+                let (filter_fn, filter_support) = convolution::get_filter_func(filter);
+                convolution::precompute_coefficients(
+                    in_size,
+                    in0,
+                    in1,
+                    out_size,
+                    filter_fn.as_ref(),
+                    filter_support,
+                    options,
+                )
+            }
+            None => convolution::precompute_area_coefficients(in_size, in0, in1, out_size),
+        };
+        let (horiz_filter, vert_filter) = match kernels {
+            Kernels::Filters(horizontal, vertical) => (Some(horizontal), Some(vertical)),
+            Kernels::Area => (None, None),
+        };
+
+        // Filter stretched or shrunk by the blur factor changes the image
+        // even if its size isn't changed.
+        let blurred = options.blur != 1. && horiz_filter.is_some();
+        let need_horizontal =
+            blurred || dst_width != src_width || crop_box.width != src_width.get() as f64;
+        let need_vertical =
+            blurred || dst_height != src_height || crop_box.height != src_height.get() as f64;
+
+        let mut vert_coeffs = need_vertical.then(|| {
+            coefficients(
+                src_height,
+                crop_box.top,
+                crop_box.top + crop_box.height,
+                dst_height,
+                vert_filter,
+            )
+        });
+        // First used row in the source image
+        let y_first = vert_coeffs.as_ref().map_or(0, |c| c.bounds[0].start);
+
+        let horiz_coeffs = need_horizontal.then(|| {
+            coefficients(
+                src_width,
+                crop_box.left,
+                crop_box.left + crop_box.width,
+                dst_width,
+                horiz_filter,
+            )
+        });
+        if let (Some(_), Some(vert_coeffs)) = (&horiz_coeffs, &mut vert_coeffs) {
+            // Shift bounds for vertical pass over the temporary image
+            vert_coeffs
+                .bounds
+                .iter_mut()
+                .for_each(|b| b.start -= y_first);
+        }
+
+        // Coefficients of area never reach pixels outside of the image.
+        let padding = |c: &Option<Coefficients>| c.as_ref().map_or(0, |c| c.wrap_padding);
+        let wrap_padding = (padding(&horiz_coeffs), padding(&vert_coeffs));
+
+        Self {
+            horiz_coeffs,
+            vert_coeffs,
+            y_first,
+            wrap_padding,
+            src_region: None,
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::arch::x86_64::*;
+
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::{Pixel, U16x3};
+use crate::simd_utils;
+
+use super::native::{self, Divider, Divider16};
+use super::sse4;
+
+/// Adds four vectors of `u32` values to 32 sums.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn add_32_components(sums: &mut [u32], values: [__m256i; 4]) {
+    let sums_ptr = sums.as_mut_ptr() as *mut __m256i;
+    for (i, values) in values.into_iter().enumerate() {
+        let ptr = sums_ptr.add(i);
+        _mm256_storeu_si256(ptr, _mm256_add_epi32(_mm256_loadu_si256(ptr), values));
+    }
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn accumulate_u8(sums: &mut [u32], components: &[u8]) {
+    let mut sums_chunks = sums.chunks_exact_mut(32);
+    let components_chunks = components.chunks_exact(32);
+    let components_remainder = components_chunks.remainder();
+
+    for (sums, components) in (&mut sums_chunks).zip(components_chunks) {
+        let source1 = simd_utils::loadu_si128(components, 0);
+        let source2 = simd_utils::loadu_si128(components, 16);
+        let values = [
+            _mm256_cvtepu8_epi32(source1),
+            _mm256_cvtepu8_epi32(_mm_srli_si128::<8>(source1)),
+            _mm256_cvtepu8_epi32(source2),
+            _mm256_cvtepu8_epi32(_mm_srli_si128::<8>(source2)),
+        ];
+        add_32_components(sums, values);
+    }
+
+    native::accumulate(sums_chunks.into_remainder(), components_remainder);
+}
+
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn accumulate_u16(sums: &mut [u32], components: &[u16]) {
+    let mut sums_chunks = sums.chunks_exact_mut(32);
+    let components_chunks = components.chunks_exact(32);
+    let components_remainder = components_chunks.remainder();
+
+    for (sums, components) in (&mut sums_chunks).zip(components_chunks) {
+        let values = [
+            _mm256_cvtepu16_epi32(simd_utils::loadu_si128(components, 0)),
+            _mm256_cvtepu16_epi32(simd_utils::loadu_si128(components, 8)),
+            _mm256_cvtepu16_epi32(simd_utils::loadu_si128(components, 16)),
+            _mm256_cvtepu16_epi32(simd_utils::loadu_si128(components, 24)),
+        ];
+        add_32_components(sums, values);
+    }
+
+    native::accumulate(sums_chunks.into_remainder(), components_remainder);
+}
+
+/// Divides 16-bit values with rounding to the nearest integer.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn divide_round_epi16(sums: __m256i, divider: Divider16) -> __m256i {
+    let x = _mm256_add_epi16(sums, _mm256_set1_epi16(divider.half));
+    let t = _mm256_mulhi_epu16(x, _mm256_set1_epi16(divider.multiplier));
+    let q = _mm256_add_epi16(t, _mm256_srli_epi16::<1>(_mm256_sub_epi16(x, t)));
+    _mm256_srl_epi16(q, _mm_cvtsi32_si128(divider.shift))
+}
+
+/// Reduces image with `u8` components of pixels by 2 horizontally
+/// and by `factor_y` vertically.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn reduce_x2_u8<P, const CC: usize>(
+    src_image: TypedImageView<P>,
+    mut dst_image: TypedImageViewMut<P>,
+    factor_y: u32,
+) where
+    P: Pixel<Component = u8>,
+{
+    // Every 128-bit lane is processed like in SSE4.1 version.
+    let (src_step, shuffle_mask) = sse4::pairs_shuffle_mask_u8::<CC>();
+    let shuffle_mask = _mm256_set_m128i(shuffle_mask, shuffle_mask);
+    let dst_step = src_step / 2;
+    let ones = _mm256_set1_epi8(1);
+    let mut src_rows: Vec<&[u8]> = Vec::with_capacity(factor_y as usize);
+
+    for (dst_y, dst_row) in dst_image.iter_rows_mut().enumerate() {
+        src_rows.clear();
+        src_rows.extend(
+            src_image
+                .iter_rows(dst_y as u32 * factor_y)
+                .take(factor_y as usize)
+                .map(P::components),
+        );
+        let dst_row = P::components_mut(dst_row);
+        let divider = Divider16::new(2 * src_rows.len() as u32);
+        let src_len = src_rows[0].len();
+
+        let (mut src_x, mut dst_x) = (0, 0);
+        while src_x + src_step + 16 <= src_len && dst_x + dst_step + 8 <= dst_row.len() {
+            let mut sums = _mm256_setzero_si256();
+            for src_row in src_rows.iter() {
+                let pixels = _mm256_set_m128i(
+                    simd_utils::loadu_si128(src_row, src_x + src_step),
+                    simd_utils::loadu_si128(src_row, src_x),
+                );
+                let pixels = _mm256_shuffle_epi8(pixels, shuffle_mask);
+                sums = _mm256_add_epi16(sums, _mm256_maddubs_epi16(pixels, ones));
+            }
+            let result = divide_round_epi16(sums, divider);
+            let result = _mm256_packus_epi16(result, result);
+            let dst_ptr = dst_row.get_unchecked_mut(dst_x..).as_mut_ptr() as *mut __m128i;
+            _mm_storel_epi64(dst_ptr, _mm256_castsi256_si128(result));
+            let dst_ptr =
+                dst_row.get_unchecked_mut(dst_x + dst_step..).as_mut_ptr() as *mut __m128i;
+            _mm_storel_epi64(dst_ptr, _mm256_extracti128_si256::<1>(result));
+            src_x += 2 * src_step;
+            dst_x += 2 * dst_step;
+        }
+
+        native::reduce_row::<u8, CC>(&src_rows, dst_row, 2, dst_x / CC);
+    }
+}
+
+/// Reduces image with `U16x3` pixels by 2 horizontally
+/// and by `factor_y` vertically.
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn reduce_x2_u16x3(
+    src_image: TypedImageView<U16x3>,
+    mut dst_image: TypedImageViewMut<U16x3>,
+    factor_y: u32,
+) {
+    let mut src_rows: Vec<&[u16]> = Vec::with_capacity(factor_y as usize);
+
+    for (dst_y, dst_row) in dst_image.iter_rows_mut().enumerate() {
+        src_rows.clear();
+        src_rows.extend(
+            src_image
+                .iter_rows(dst_y as u32 * factor_y)
+                .take(factor_y as usize)
+                .map(U16x3::components),
+        );
+        let dst_row = U16x3::components_mut(dst_row);
+        let divider = Divider::new(2 * src_rows.len() as u32);
+        let src_len = src_rows[0].len();
+
+        let (mut src_x, mut dst_x) = (0, 0);
+        // Two pairs of pixels are processed at once. Four components
+        // are loaded from the second pixel of every pair,
+        // so at least one component must follow the pairs.
+        while src_x + 13 <= src_len {
+            let mut sums = _mm256_setzero_si256();
+            for src_row in src_rows.iter() {
+                let first = _mm_unpacklo_epi64(
+                    simd_utils::loadl_epi64(src_row, src_x),
+                    simd_utils::loadl_epi64(src_row, src_x + 6),
+                );
+                let second = _mm_unpacklo_epi64(
+                    simd_utils::loadl_epi64(src_row, src_x + 3),
+                    simd_utils::loadl_epi64(src_row, src_x + 9),
+                );
+                let pairs =
+                    _mm256_add_epi32(_mm256_cvtepu16_epi32(first), _mm256_cvtepu16_epi32(second));
+                sums = _mm256_add_epi32(sums, pairs);
+            }
+            let sums = [
+                _mm256_extract_epi32::<0>(sums),
+                _mm256_extract_epi32::<1>(sums),
+                _mm256_extract_epi32::<2>(sums),
+                _mm256_extract_epi32::<4>(sums),
+                _mm256_extract_epi32::<5>(sums),
+                _mm256_extract_epi32::<6>(sums),
+            ];
+            let dst = dst_row.get_unchecked_mut(dst_x..dst_x + 6);
+            for (component, sum) in dst.iter_mut().zip(sums) {
+                *component = divider.divide_round(sum as u32) as u16;
+            }
+            src_x += 12;
+            dst_x += 6;
+        }
+
+        native::reduce_row::<u16, 3>(&src_rows, dst_row, 2, dst_x / 3);
+    }
+}
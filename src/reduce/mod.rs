@@ -0,0 +1,99 @@
+//! Reducing of images by integer factors with averaging of blocks of pixels.
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::{F32x3, F32x4, Pixel, U16x3, U16x4, U8x2, U8x3, U8x4, F32, I32, U16, U8};
+use crate::CpuExtensions;
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+mod native;
+#[cfg(target_arch = "x86_64")]
+mod sse4;
+
+/// Max value of factor of reducing.
+///
+/// It guarantees that sums of components of `u16` pixels
+/// in a block are less than 2^30.
+pub(crate) const MAX_FACTOR: u32 = 128;
+
+pub(crate) trait BoxReduce
+where
+    Self: Pixel + Send + Sync,
+{
+    /// Reduces the source image by given horizontal and vertical factors.
+    /// Every pixel of the destination image is an average of a block
+    /// of pixels of the source image.
+    ///
+    /// Size of the destination image must be equal to the size of
+    /// the source image divided by factors and rounded up, so the blocks
+    /// from the last column and row may be smaller than others.
+    fn box_reduce(
+        src_image: TypedImageView<Self>,
+        dst_image: TypedImageViewMut<Self>,
+        factors: (u32, u32),
+        cpu_extensions: CpuExtensions,
+    );
+}
+
+macro_rules! box_reduce_impl {
+    ($pixel:ty, $accumulate:ident, $($reduce_x2:tt)+) => {
+        impl BoxReduce for $pixel {
+            fn box_reduce(
+                src_image: TypedImageView<Self>,
+                dst_image: TypedImageViewMut<Self>,
+                factors: (u32, u32),
+                cpu_extensions: CpuExtensions,
+            ) {
+                let (factor_x, factor_y) = factors;
+                match cpu_extensions {
+                    #[cfg(target_arch = "x86_64")]
+                    CpuExtensions::Avx2 if factor_x == 2 => unsafe {
+                        avx2::$($reduce_x2)+(src_image, dst_image, factor_y)
+                    },
+                    #[cfg(target_arch = "x86_64")]
+                    CpuExtensions::Avx2 => native::box_reduce(
+                        src_image,
+                        dst_image,
+                        factors,
+                        |sums, components| unsafe { avx2::$accumulate(sums, components) },
+                    ),
+                    #[cfg(target_arch = "x86_64")]
+                    CpuExtensions::Sse4_1 if factor_x == 2 => unsafe {
+                        sse4::$($reduce_x2)+(src_image, dst_image, factor_y)
+                    },
+                    #[cfg(target_arch = "x86_64")]
+                    CpuExtensions::Sse4_1 => native::box_reduce(
+                        src_image,
+                        dst_image,
+                        factors,
+                        |sums, components| unsafe { sse4::$accumulate(sums, components) },
+                    ),
+                    _ => native::box_reduce(src_image, dst_image, factors, native::accumulate),
+                }
+            }
+        }
+    };
+    ($pixel:ty) => {
+        impl BoxReduce for $pixel {
+            fn box_reduce(
+                src_image: TypedImageView<Self>,
+                dst_image: TypedImageViewMut<Self>,
+                factors: (u32, u32),
+                _cpu_extensions: CpuExtensions,
+            ) {
+                native::box_reduce(src_image, dst_image, factors, native::accumulate);
+            }
+        }
+    };
+}
+
+box_reduce_impl!(U8, accumulate_u8, reduce_x2_u8::<U8, 1>);
+box_reduce_impl!(U8x3, accumulate_u8, reduce_x2_u8::<U8x3, 3>);
+box_reduce_impl!(U8x4, accumulate_u8, reduce_x2_u8::<U8x4, 4>);
+box_reduce_impl!(U16x3, accumulate_u16, reduce_x2_u16x3);
+box_reduce_impl!(U8x2);
+box_reduce_impl!(U16);
+box_reduce_impl!(U16x4);
+box_reduce_impl!(I32);
+box_reduce_impl!(F32);
+box_reduce_impl!(F32x3);
+box_reduce_impl!(F32x4);
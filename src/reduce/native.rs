@@ -0,0 +1,251 @@
+use std::ops::AddAssign;
+
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::Pixel;
+
+use super::MAX_FACTOR;
+
+/// Component of pixel that can be averaged.
+pub(crate) trait Component: Copy {
+    /// Type of sums of components.
+    type Sum: Copy + Default + AddAssign;
+
+    fn to_sum(self) -> Self::Sum;
+
+    fn average(sum: Self::Sum, divider: Divider) -> Self;
+}
+
+impl Component for u8 {
+    type Sum = u32;
+
+    #[inline(always)]
+    fn to_sum(self) -> u32 {
+        self as u32
+    }
+
+    #[inline(always)]
+    fn average(sum: u32, divider: Divider) -> Self {
+        divider.divide_round(sum) as u8
+    }
+}
+
+impl Component for u16 {
+    type Sum = u32;
+
+    #[inline(always)]
+    fn to_sum(self) -> u32 {
+        self as u32
+    }
+
+    #[inline(always)]
+    fn average(sum: u32, divider: Divider) -> Self {
+        divider.divide_round(sum) as u16
+    }
+}
+
+impl Component for i32 {
+    type Sum = i64;
+
+    #[inline(always)]
+    fn to_sum(self) -> i64 {
+        self as i64
+    }
+
+    #[inline(always)]
+    fn average(sum: i64, divider: Divider) -> Self {
+        (sum as f64 / divider.count as f64).round() as i32
+    }
+}
+
+impl Component for f32 {
+    type Sum = f64;
+
+    #[inline(always)]
+    fn to_sum(self) -> f64 {
+        self as f64
+    }
+
+    #[inline(always)]
+    fn average(sum: f64, divider: Divider) -> Self {
+        (sum / divider.count as f64) as f32
+    }
+}
+
+/// Divides unsigned sums by count of summed values with rounding
+/// to the nearest integer.
+///
+/// Division is replaced by multiplication and shift (Granlund and Montgomery),
+/// which is exact for all dividends less than 2^30.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Divider {
+    count: u32,
+    multiplier: u64,
+    shift: u32,
+}
+
+impl Divider {
+    pub fn new(count: u32) -> Self {
+        // ceil(log2(count))
+        let log2 = u32::BITS - (count - 1).leading_zeros();
+        let shift = 30 + log2;
+        let multiplier = (1u64 << shift) / count as u64 + 1;
+        Self {
+            count,
+            multiplier,
+            shift,
+        }
+    }
+
+    /// Caller must guarantee that `sum + count / 2` is less than 2^30.
+    #[inline(always)]
+    pub fn divide_round(self, sum: u32) -> u32 {
+        let dividend = (sum + self.count / 2) as u64;
+        ((dividend * self.multiplier) >> self.shift) as u32
+    }
+}
+
+/// Divides unsigned 16-bit sums by count of summed values with rounding
+/// to the nearest integer with help of SIMD-instructions.
+///
+/// Division is replaced by `t = mulhi(x, multiplier)` and
+/// `(t + ((x - t) >> 1)) >> shift` (Granlund and Montgomery),
+/// which is exact for all 16-bit dividends.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Divider16 {
+    pub half: i16,
+    pub multiplier: i16,
+    pub shift: i32,
+}
+
+impl Divider16 {
+    /// `count` must be in range `2..=256`.
+    pub fn new(count: u32) -> Self {
+        debug_assert!((2..=256).contains(&count));
+        // ceil(log2(count))
+        let log2 = u32::BITS - (count - 1).leading_zeros();
+        let multiplier = (1 << 16) * ((1 << log2) - count) / count + 1;
+        Self {
+            half: (count / 2) as i16,
+            multiplier: multiplier as u16 as i16,
+            shift: log2 as i32 - 1,
+        }
+    }
+}
+
+#[inline(always)]
+pub(crate) fn accumulate<C: Component>(sums: &mut [C::Sum], components: &[C]) {
+    for (sum, &component) in sums.iter_mut().zip(components) {
+        *sum += component.to_sum();
+    }
+}
+
+/// Reduces the image with help of `accumulate` function that adds
+/// components of row of the source image to sums of components.
+#[inline(always)]
+pub(crate) fn box_reduce<P, F>(
+    src_image: TypedImageView<P>,
+    dst_image: TypedImageViewMut<P>,
+    factors: (u32, u32),
+    accumulate: F,
+) where
+    P: Pixel,
+    P::Component: Component,
+    F: Fn(&mut [<P::Component as Component>::Sum], &[P::Component]),
+{
+    let (factor_x, factor_y) = factors;
+    debug_assert!(factor_x <= MAX_FACTOR && factor_y <= MAX_FACTOR);
+    debug_assert_eq!(
+        dst_image.width().get(),
+        src_image.width().get().div_ceil(factor_x)
+    );
+    debug_assert_eq!(
+        dst_image.height().get(),
+        src_image.height().get().div_ceil(factor_y)
+    );
+
+    match P::components_count() {
+        1 => reduce_rows::<P, F, 1>(src_image, dst_image, factors, accumulate),
+        2 => reduce_rows::<P, F, 2>(src_image, dst_image, factors, accumulate),
+        3 => reduce_rows::<P, F, 3>(src_image, dst_image, factors, accumulate),
+        _ => reduce_rows::<P, F, 4>(src_image, dst_image, factors, accumulate),
+    }
+}
+
+#[inline(always)]
+fn reduce_rows<P, F, const CC: usize>(
+    src_image: TypedImageView<P>,
+    mut dst_image: TypedImageViewMut<P>,
+    factors: (u32, u32),
+    accumulate: F,
+) where
+    P: Pixel,
+    P::Component: Component,
+    F: Fn(&mut [<P::Component as Component>::Sum], &[P::Component]),
+{
+    let (factor_x, factor_y) = factors;
+    let src_width = src_image.width().get();
+    let src_height = src_image.height().get();
+    let block_len = factor_x as usize * CC;
+    // Width of blocks from the last column
+    let last_width = src_width - (dst_image.width().get() - 1) * factor_x;
+    let mut sums = vec![Default::default(); src_width as usize * CC];
+
+    for (dst_y, dst_row) in dst_image.iter_rows_mut().enumerate() {
+        let first_y = dst_y as u32 * factor_y;
+        let rows_count = factor_y.min(src_height - first_y);
+        sums.fill(Default::default());
+        for src_row in src_image.iter_rows(first_y).take(rows_count as usize) {
+            accumulate(&mut sums, P::components(src_row));
+        }
+
+        let divider = Divider::new(factor_x * rows_count);
+        let last_divider = Divider::new(last_width * rows_count);
+        let dst_pixels = P::components_mut(dst_row).chunks_exact_mut(CC);
+        for (dst_pixel, block) in dst_pixels.zip(sums.chunks(block_len)) {
+            let mut pixel_sums = [<P::Component as Component>::Sum::default(); CC];
+            for block_pixel in block.chunks_exact(CC) {
+                for c in 0..CC {
+                    pixel_sums[c] += block_pixel[c];
+                }
+            }
+            let divider = if block.len() == block_len {
+                divider
+            } else {
+                last_divider
+            };
+            for c in 0..CC {
+                dst_pixel[c] = Component::average(pixel_sums[c], divider);
+            }
+        }
+    }
+}
+
+/// Reduces pixels of the destination row starting from `first_x` with
+/// averaging of blocks of `factor_x` pixels from given rows of the source image.
+#[inline]
+pub(crate) fn reduce_row<C: Component, const CC: usize>(
+    src_rows: &[&[C]],
+    dst_row: &mut [C],
+    factor_x: usize,
+    first_x: usize,
+) {
+    let src_width = src_rows[0].len() / CC;
+    let dst_pixels = dst_row.chunks_exact_mut(CC).enumerate().skip(first_x);
+    for (x, dst_pixel) in dst_pixels {
+        let block_start = x * factor_x;
+        let block_width = factor_x.min(src_width - block_start);
+        let mut pixel_sums = [C::Sum::default(); CC];
+        for src_row in src_rows {
+            let block = &src_row[block_start * CC..(block_start + block_width) * CC];
+            for block_pixel in block.chunks_exact(CC) {
+                for c in 0..CC {
+                    pixel_sums[c] += block_pixel[c].to_sum();
+                }
+            }
+        }
+        let divider = Divider::new((block_width * src_rows.len()) as u32);
+        for c in 0..CC {
+            dst_pixel[c] = Component::average(pixel_sums[c], divider);
+        }
+    }
+}
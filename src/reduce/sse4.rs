@@ -0,0 +1,186 @@
+use std::arch::x86_64::*;
+
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::pixels::{Pixel, U16x3};
+use crate::simd_utils;
+
+use super::native::{self, Divider, Divider16};
+
+/// Adds four vectors of `u32` values to 16 sums.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn add_16_components(sums: &mut [u32], values: [__m128i; 4]) {
+    let sums_ptr = sums.as_mut_ptr() as *mut __m128i;
+    for (i, values) in values.into_iter().enumerate() {
+        let ptr = sums_ptr.add(i);
+        _mm_storeu_si128(ptr, _mm_add_epi32(_mm_loadu_si128(ptr), values));
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn accumulate_u8(sums: &mut [u32], components: &[u8]) {
+    let mut sums_chunks = sums.chunks_exact_mut(16);
+    let components_chunks = components.chunks_exact(16);
+    let components_remainder = components_chunks.remainder();
+
+    for (sums, components) in (&mut sums_chunks).zip(components_chunks) {
+        let source = simd_utils::loadu_si128(components, 0);
+        let values = [
+            _mm_cvtepu8_epi32(source),
+            _mm_cvtepu8_epi32(_mm_srli_si128::<4>(source)),
+            _mm_cvtepu8_epi32(_mm_srli_si128::<8>(source)),
+            _mm_cvtepu8_epi32(_mm_srli_si128::<12>(source)),
+        ];
+        add_16_components(sums, values);
+    }
+
+    native::accumulate(sums_chunks.into_remainder(), components_remainder);
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn accumulate_u16(sums: &mut [u32], components: &[u16]) {
+    let mut sums_chunks = sums.chunks_exact_mut(16);
+    let components_chunks = components.chunks_exact(16);
+    let components_remainder = components_chunks.remainder();
+
+    for (sums, components) in (&mut sums_chunks).zip(components_chunks) {
+        let source1 = simd_utils::loadu_si128(components, 0);
+        let source2 = simd_utils::loadu_si128(components, 8);
+        let values = [
+            _mm_cvtepu16_epi32(source1),
+            _mm_cvtepu16_epi32(_mm_srli_si128::<8>(source1)),
+            _mm_cvtepu16_epi32(source2),
+            _mm_cvtepu16_epi32(_mm_srli_si128::<8>(source2)),
+        ];
+        add_16_components(sums, values);
+    }
+
+    native::accumulate(sums_chunks.into_remainder(), components_remainder);
+}
+
+/// Divides 16-bit values with rounding to the nearest integer.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn divide_round_epi16(sums: __m128i, divider: Divider16) -> __m128i {
+    let x = _mm_add_epi16(sums, _mm_set1_epi16(divider.half));
+    let t = _mm_mulhi_epu16(x, _mm_set1_epi16(divider.multiplier));
+    let q = _mm_add_epi16(t, _mm_srli_epi16::<1>(_mm_sub_epi16(x, t)));
+    _mm_srl_epi16(q, _mm_cvtsi32_si128(divider.shift))
+}
+
+/// Returns count of components loaded by one 16-byte load
+/// and mask to shuffle them so that components of every pair of pixels
+/// are placed side by side.
+#[inline]
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn pairs_shuffle_mask_u8<const CC: usize>() -> (usize, __m128i) {
+    match CC {
+        1 => (
+            16,
+            _mm_set_epi8(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0),
+        ),
+        3 => (
+            12,
+            _mm_set_epi8(-1, -1, -1, -1, 11, 8, 10, 7, 9, 6, 5, 2, 4, 1, 3, 0),
+        ),
+        _ => (
+            16,
+            _mm_set_epi8(15, 11, 14, 10, 13, 9, 12, 8, 7, 3, 6, 2, 5, 1, 4, 0),
+        ),
+    }
+}
+
+/// Reduces image with `u8` components of pixels by 2 horizontally
+/// and by `factor_y` vertically.
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn reduce_x2_u8<P, const CC: usize>(
+    src_image: TypedImageView<P>,
+    mut dst_image: TypedImageViewMut<P>,
+    factor_y: u32,
+) where
+    P: Pixel<Component = u8>,
+{
+    let (src_step, shuffle_mask) = pairs_shuffle_mask_u8::<CC>();
+    let dst_step = src_step / 2;
+    let ones = _mm_set1_epi8(1);
+    let mut src_rows: Vec<&[u8]> = Vec::with_capacity(factor_y as usize);
+
+    for (dst_y, dst_row) in dst_image.iter_rows_mut().enumerate() {
+        src_rows.clear();
+        src_rows.extend(
+            src_image
+                .iter_rows(dst_y as u32 * factor_y)
+                .take(factor_y as usize)
+                .map(P::components),
+        );
+        let dst_row = P::components_mut(dst_row);
+        let divider = Divider16::new(2 * src_rows.len() as u32);
+        let src_len = src_rows[0].len();
+
+        let (mut src_x, mut dst_x) = (0, 0);
+        while src_x + 16 <= src_len && dst_x + 8 <= dst_row.len() {
+            let mut sums = _mm_setzero_si128();
+            for src_row in src_rows.iter() {
+                let pixels = simd_utils::loadu_si128(src_row, src_x);
+                let pixels = _mm_shuffle_epi8(pixels, shuffle_mask);
+                sums = _mm_add_epi16(sums, _mm_maddubs_epi16(pixels, ones));
+            }
+            let result = divide_round_epi16(sums, divider);
+            let dst_ptr = dst_row.get_unchecked_mut(dst_x..).as_mut_ptr() as *mut __m128i;
+            _mm_storel_epi64(dst_ptr, _mm_packus_epi16(result, result));
+            src_x += src_step;
+            dst_x += dst_step;
+        }
+
+        native::reduce_row::<u8, CC>(&src_rows, dst_row, 2, dst_x / CC);
+    }
+}
+
+/// Reduces image with `U16x3` pixels by 2 horizontally
+/// and by `factor_y` vertically.
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn reduce_x2_u16x3(
+    src_image: TypedImageView<U16x3>,
+    mut dst_image: TypedImageViewMut<U16x3>,
+    factor_y: u32,
+) {
+    let mut src_rows: Vec<&[u16]> = Vec::with_capacity(factor_y as usize);
+
+    for (dst_y, dst_row) in dst_image.iter_rows_mut().enumerate() {
+        src_rows.clear();
+        src_rows.extend(
+            src_image
+                .iter_rows(dst_y as u32 * factor_y)
+                .take(factor_y as usize)
+                .map(U16x3::components),
+        );
+        let dst_row = U16x3::components_mut(dst_row);
+        let divider = Divider::new(2 * src_rows.len() as u32);
+        let src_len = src_rows[0].len();
+
+        let (mut src_x, mut dst_x) = (0, 0);
+        // Four components are loaded from the second pixel of pair,
+        // so at least one component must follow the pair.
+        while src_x + 7 <= src_len {
+            let mut sums = _mm_setzero_si128();
+            for src_row in src_rows.iter() {
+                let first = _mm_cvtepu16_epi32(simd_utils::loadl_epi64(src_row, src_x));
+                let second = _mm_cvtepu16_epi32(simd_utils::loadl_epi64(src_row, src_x + 3));
+                sums = _mm_add_epi32(sums, _mm_add_epi32(first, second));
+            }
+            let sums = [
+                _mm_extract_epi32::<0>(sums),
+                _mm_extract_epi32::<1>(sums),
+                _mm_extract_epi32::<2>(sums),
+            ];
+            let dst = dst_row.get_unchecked_mut(dst_x..dst_x + 3);
+            for (component, sum) in dst.iter_mut().zip(sums) {
+                *component = divider.divide_round(sum as u32) as u16;
+            }
+            src_x += 6;
+            dst_x += 3;
+        }
+
+        native::reduce_row::<u16, 3>(&src_rows, dst_row, 2, dst_x / 3);
+    }
+}
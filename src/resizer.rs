@@ -1,10 +1,16 @@
 use std::num::NonZeroU32;
 
-use crate::convolution::{self, Convolution, FilterType};
-use crate::errors::DifferentTypesOfPixelsError;
+use crate::convolution::{CoefficientsOptions, Convolution, EdgeMode, FilterType, SamplingGrid};
+use crate::errors::{DifferentTypesOfPixelsError, ResizePlanError};
+use crate::gamma::SrgbPixel;
 use crate::image::InnerImage;
-use crate::image_view::{ImageView, ImageViewMut, TypedImageView, TypedImageViewMut};
+use crate::image_view::{CropBox, ImageView, ImageViewMut, TypedImageView, TypedImageViewMut};
+use crate::nodata::NodataResample;
 use crate::pixels::{Pixel, PixelType};
+use crate::plan::{ConvolutionPlan, PlanSteps, ReducePlan, ResizePlan};
+use crate::reduce::BoxReduce;
+use crate::statistics::FootprintStatistic;
+use crate::threading;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CpuExtensions {
@@ -39,6 +45,54 @@ pub enum ResizeAlg {
     Nearest,
     Convolution(FilterType),
     SuperSampling(FilterType, u8),
+    /// Convolution with different filters for horizontal and vertical passes.
+    SeparateConvolution {
+        horizontal: FilterType,
+        vertical: FilterType,
+    },
+    /// Convolution after reducing of the source image by integer factors
+    /// with averaging of blocks of pixels (like `reducing_gap` of Pillow).
+    ///
+    /// Factors of reducing are chosen so that the reduced image is
+    /// at least `gap` times larger than the destination image.
+    /// Values of `gap` less than 1 are replaced by 1.
+    /// Unlike `SuperSampling`, reducing doesn't skip pixels of
+    /// the source image, so fine patterns are not aliased.
+    /// Convolution is skipped if the reduced image has the size of
+    /// the destination image (e.g. halving with `gap` equal to 1).
+    ///
+    /// `Convolution(FilterType::Box)` is never replaced by reducing
+    /// automatically. Its horizontal and vertical passes are rounded
+    /// separately, so its results may differ by one from rounded averages
+    /// of blocks. Use `Reduce(FilterType::Box, 1.0)` for exact integer
+    /// factors (e.g. halving of frames or generating of mipmaps).
+    Reduce(FilterType, f64),
+    /// Averaging of pixels of the source image with weights equal to
+    /// areas of pixels covered by pixels of the destination image
+    /// (like `INTER_AREA` of OpenCV).
+    ///
+    /// Unlike convolution with `FilterType::Box`, pixels of the source image
+    /// partially covered by the destination pixel are taken with fractional
+    /// weights. Upscaling is done with the same coverage weights, so
+    /// it looks like the nearest-neighbor resampling with linear
+    /// interpolation on boundaries between pixels.
+    Area,
+    /// The most frequent pixel of the footprint of the destination pixel
+    /// in the source image. It is suitable for categorical rasters
+    /// (e.g. segmentation masks and land-cover maps) which can't be averaged.
+    ///
+    /// Footprint consists of pixels of the source image covered by
+    /// the `Box` filter of convolution, so it depends on the blur factor
+    /// and the sampling grid. Ties are resolved in favor of the pixel
+    /// that is met first while reading the footprint row by row.
+    /// All `NaN` values of a component are considered equal.
+    Mode,
+    /// Minimum of every component of pixels of the footprint
+    /// of the destination pixel in the source image (see `ResizeAlg::Mode`).
+    Min,
+    /// Maximum of every component of pixels of the footprint
+    /// of the destination pixel in the source image (see `ResizeAlg::Mode`).
+    Max,
 }
 
 impl Default for ResizeAlg {
@@ -47,13 +101,79 @@ impl Default for ResizeAlg {
     }
 }
 
-/// Methods of this structure used to resize images.
+/// Buffers used by convolution.
 #[derive(Default, Debug, Clone)]
+struct ConvolutionBuffers {
+    /// Buffer for the result of horizontal pass.
+    temp: Vec<u8>,
+    /// Buffer for the source image extended in `EdgeMode::Wrap` mode.
+    wrapped_src: Vec<u8>,
+}
+
+/// Methods of this structure used to resize images.
+#[derive(Debug, Clone)]
 pub struct Resizer {
     pub algorithm: ResizeAlg,
+    /// If `true`, components of `U8x3` and `U8x4` images are treated as
+    /// sRGB-encoded values and resized in linear color space.
+    ///
+    /// In this mode the fourth component of `U8x4` image is treated as
+    /// alpha-channel. Source image must not be multiplied by alpha
+    /// and destination image will not be multiplied by alpha too.
+    /// Images with other types of pixels are resized as usual.
+    ///
+    /// Gamma correction isn't used if [Resizer::nodata] is set, because
+    /// the nodata value marks sRGB-encoded components of the source image.
+    /// Algorithms `Nearest`, `Mode`, `Min` and `Max` don't mix pixels,
+    /// so they don't need gamma correction too.
+    pub gamma_correction: bool,
+    /// Blur factor of convolution filters, `1.0` by default.
+    ///
+    /// Values greater than 1 stretch support of the filter and
+    /// reduce aliasing, values less than 1 make the result sharper.
+    /// Invalid and huge values are replaced as described in
+    /// [ResizePlan::with_blur].
+    pub blur: f64,
+    /// Mode of handling of filter's taps that are outside of the source image,
+    /// `EdgeMode::Renormalize` by default.
+    pub edge_mode: EdgeMode,
+    /// Mapping of positions of pixels of the destination image into
+    /// positions in the source image, `SamplingGrid::HalfPixel` by default.
+    pub sampling_grid: SamplingGrid,
+    /// Value of components of pixels that marks missing samples
+    /// of the source image (e.g. `NaN` or `-9999`), `None` by default.
+    ///
+    /// Missing samples are excluded from resizing and weights of valid
+    /// samples are renormalized. Components of the destination image are
+    /// set to the nodata value if the total weight of valid samples is
+    /// less than 10% of the total absolute weight of the filter's window
+    /// (windows with exactly 10% are still renormalized).
+    /// `StreamingResizer` doesn't support resizing with the nodata value.
+    /// See [ResizePlan::with_nodata] for details.
+    pub nodata: Option<f64>,
     cpu_extensions: CpuExtensions,
-    convolution_buffer: Vec<u8>,
+    convolution_buffers: ConvolutionBuffers,
     super_sampling_buffer: Vec<u8>,
+    linear_src_buffer: Vec<u8>,
+    linear_dst_buffer: Vec<u8>,
+}
+
+impl Default for Resizer {
+    fn default() -> Self {
+        Self {
+            algorithm: Default::default(),
+            gamma_correction: false,
+            blur: 1.0,
+            edge_mode: EdgeMode::Renormalize,
+            sampling_grid: SamplingGrid::HalfPixel,
+            nodata: None,
+            cpu_extensions: Default::default(),
+            convolution_buffers: Default::default(),
+            super_sampling_buffer: Vec::new(),
+            linear_src_buffer: Vec::new(),
+            linear_dst_buffer: Vec::new(),
+        }
+    }
 }
 
 impl Resizer {
@@ -82,101 +202,321 @@ impl Resizer {
         if src_image.pixel_type() != dst_image.pixel_type() {
             return Err(DifferentTypesOfPixelsError);
         }
-        match src_image.pixel_type() {
+        let plan = ResizePlan::new(
+            self.algorithm,
+            src_image.pixel_type(),
+            src_image.width(),
+            src_image.height(),
+            src_image.float_crop_box(),
+            dst_image.width(),
+            dst_image.height(),
+        )
+        .expect("crop box of image view is always inside of the image")
+        .with_options(
+            CoefficientsOptions {
+                blur: self.blur,
+                edge_mode: self.edge_mode,
+                sampling_grid: self.sampling_grid,
+            },
+            src_image.crop_mode(),
+        )
+        .with_nodata(self.nodata);
+        self.resize_by_plan(&plan, src_image, dst_image);
+        Ok(())
+    }
+
+    /// Resize source image to the size of destination image with help of
+    /// precomputed plan and save the result to the latter's pixel buffer.
+    ///
+    /// Algorithm of resizing and its options are taken from the plan,
+    /// the fields [Resizer::algorithm], [Resizer::blur], [Resizer::edge_mode],
+    /// [Resizer::sampling_grid] and [Resizer::nodata] are ignored.
+    /// Type of pixels, sizes and crop box of images must match to the plan.
+    pub fn resize_with_plan(
+        &mut self,
+        plan: &ResizePlan,
+        src_image: &ImageView,
+        dst_image: &mut ImageViewMut,
+    ) -> Result<(), ResizePlanError> {
+        if src_image.pixel_type() != plan.pixel_type || dst_image.pixel_type() != plan.pixel_type {
+            return Err(ResizePlanError::PixelTypeIsDifferent);
+        }
+        if src_image.width() != plan.src_width
+            || src_image.height() != plan.src_height
+            || src_image.float_crop_box() != plan.crop_box
+            || src_image.crop_mode() != plan.crop_mode
+        {
+            return Err(ResizePlanError::SrcImageIsDifferent);
+        }
+        if dst_image.width() != plan.dst_width || dst_image.height() != plan.dst_height {
+            return Err(ResizePlanError::DstImageIsDifferent);
+        }
+        self.resize_by_plan(plan, src_image, dst_image);
+        Ok(())
+    }
+
+    /// Caller must guarantee that images match to the plan.
+    fn resize_by_plan(
+        &mut self,
+        plan: &ResizePlan,
+        src_image: &ImageView,
+        dst_image: &mut ImageViewMut,
+    ) {
+        match plan.pixel_type {
+            PixelType::U8x2 => {
+                if let Some(src_rows) = src_image.u8x2_image() {
+                    if let Some(dst_rows) = dst_image.u8x2_image() {
+                        self.resize_inner(plan, src_rows, dst_rows);
+                    }
+                }
+            }
             PixelType::U8x3 => {
                 if let Some(src_rows) = src_image.u8x3_image() {
                     if let Some(dst_rows) = dst_image.u8x3_image() {
-                        self.resize_inner(src_rows, dst_rows);
+                        self.resize_srgb_inner(plan, src_rows, dst_rows);
                     }
                 }
             }
             PixelType::U8x4 => {
                 if let Some(src_rows) = src_image.u8x4_image() {
                     if let Some(dst_rows) = dst_image.u8x4_image() {
-                        self.resize_inner(src_rows, dst_rows);
+                        self.resize_srgb_inner(plan, src_rows, dst_rows);
+                    }
+                }
+            }
+            PixelType::U16 => {
+                if let Some(src_rows) = src_image.u16_image() {
+                    if let Some(dst_rows) = dst_image.u16_image() {
+                        self.resize_inner(plan, src_rows, dst_rows);
                     }
                 }
             }
             PixelType::U16x3 => {
                 if let Some(src_rows) = src_image.u16x3_image() {
                     if let Some(dst_rows) = dst_image.u16x3_image() {
-                        self.resize_inner(src_rows, dst_rows);
+                        self.resize_inner(plan, src_rows, dst_rows);
+                    }
+                }
+            }
+            PixelType::U16x4 => {
+                if let Some(src_rows) = src_image.u16x4_image() {
+                    if let Some(dst_rows) = dst_image.u16x4_image() {
+                        self.resize_inner(plan, src_rows, dst_rows);
                     }
                 }
             }
             PixelType::I32 => {
                 if let Some(src_rows) = src_image.i32_image() {
                     if let Some(dst_rows) = dst_image.i32_image() {
-                        self.resize_inner(src_rows, dst_rows);
+                        self.resize_inner(plan, src_rows, dst_rows);
                     }
                 }
             }
             PixelType::F32 => {
                 if let Some(src_rows) = src_image.f32_image() {
                     if let Some(dst_rows) = dst_image.f32_image() {
-                        self.resize_inner(src_rows, dst_rows);
+                        self.resize_inner(plan, src_rows, dst_rows);
+                    }
+                }
+            }
+            PixelType::F32x3 => {
+                if let Some(src_rows) = src_image.f32x3_image() {
+                    if let Some(dst_rows) = dst_image.f32x3_image() {
+                        self.resize_inner(plan, src_rows, dst_rows);
+                    }
+                }
+            }
+            PixelType::F32x4 => {
+                if let Some(src_rows) = src_image.f32x4_image() {
+                    if let Some(dst_rows) = dst_image.f32x4_image() {
+                        self.resize_inner(plan, src_rows, dst_rows);
                     }
                 }
             }
             PixelType::U8 => {
                 if let Some(src_rows) = src_image.u8_image() {
                     if let Some(dst_rows) = dst_image.u8_image() {
-                        self.resize_inner(src_rows, dst_rows);
+                        self.resize_inner(plan, src_rows, dst_rows);
                     }
                 }
             }
         }
-        Ok(())
     }
 
-    fn resize_inner<P>(&mut self, src_image: TypedImageView<P>, dst_image: TypedImageViewMut<P>)
-    where
-        P: Convolution,
+    fn resize_inner<P>(
+        &mut self,
+        plan: &ResizePlan,
+        src_image: TypedImageView<P>,
+        dst_image: TypedImageViewMut<P>,
+    ) where
+        P: Convolution + BoxReduce + NodataResample + FootprintStatistic,
     {
-        match self.algorithm {
-            ResizeAlg::Nearest => resample_nearest(src_image, dst_image),
-            ResizeAlg::Convolution(filter_type) => {
-                let convolution_buffer = &mut self.convolution_buffer;
+        let nodata = plan.nodata;
+        match &plan.steps {
+            PlanSteps::Nearest => {
+                resample_nearest(src_image, dst_image, plan.options.sampling_grid)
+            }
+            PlanSteps::Convolution(convolution) => {
+                let convolution_buffers = &mut self.convolution_buffers;
                 resample_convolution(
                     src_image,
                     dst_image,
-                    filter_type,
+                    convolution,
                     self.cpu_extensions,
-                    convolution_buffer,
+                    nodata,
+                    convolution_buffers,
                 )
             }
-            ResizeAlg::SuperSampling(filter_type, multiplicity) => {
-                let convolution_buffer = &mut self.convolution_buffer;
-                let super_sampling_buffer = &mut self.super_sampling_buffer;
-                resample_super_sampling(
+            PlanSteps::SuperSampling {
+                tmp_size: Some((tmp_width, tmp_height)),
+                convolution,
+            } => {
+                // First step is resizing the source image by fastest algorithm.
+                // The temporary image will be about ``multiplicity`` times larger
+                // than required.
+                let mut tmp_img = get_temp_image_from_buffer(
+                    &mut self.super_sampling_buffer,
+                    *tmp_width,
+                    *tmp_height,
+                );
+                resample_nearest(src_image, tmp_img.dst_view(), SamplingGrid::HalfPixel);
+                // Second step is resizing the temporary image with a convolution.
+                resample_convolution(
+                    tmp_img.src_view(),
+                    dst_image,
+                    convolution,
+                    self.cpu_extensions,
+                    nodata,
+                    &mut self.convolution_buffers,
+                )
+            }
+            PlanSteps::SuperSampling {
+                tmp_size: None,
+                convolution,
+            } => {
+                // There is no point in doing the resizing in two steps.
+                // We immediately resize the original image with a convolution.
+                resample_convolution(
                     src_image,
                     dst_image,
-                    filter_type,
-                    multiplicity,
+                    convolution,
                     self.cpu_extensions,
-                    super_sampling_buffer,
-                    convolution_buffer,
+                    nodata,
+                    &mut self.convolution_buffers,
                 )
             }
+            PlanSteps::Reduce(reduce) => {
+                resample_reduce(src_image, dst_image, reduce, self.cpu_extensions, nodata)
+            }
+            PlanSteps::ReduceAndConvolution {
+                reduce,
+                convolution,
+            } => {
+                // First step is reducing of the source image
+                // by integer factors.
+                let (tmp_width, tmp_height) = reduce.dst_size();
+                let mut tmp_img = get_temp_image_from_buffer(
+                    &mut self.super_sampling_buffer,
+                    tmp_width,
+                    tmp_height,
+                );
+                resample_reduce(
+                    src_image,
+                    tmp_img.dst_view(),
+                    reduce,
+                    self.cpu_extensions,
+                    nodata,
+                );
+                // Second step is resizing the reduced image with a convolution.
+                resample_convolution(
+                    tmp_img.src_view(),
+                    dst_image,
+                    convolution,
+                    self.cpu_extensions,
+                    nodata,
+                    &mut self.convolution_buffers,
+                )
+            }
+            PlanSteps::Footprint {
+                statistic,
+                footprints,
+            } => P::footprint_statistic(src_image, dst_image, footprints, *statistic, nodata),
         }
     }
 
+    fn resize_srgb_inner<P>(
+        &mut self,
+        plan: &ResizePlan,
+        src_image: TypedImageView<P>,
+        dst_image: TypedImageViewMut<P>,
+    ) where
+        P: Convolution + BoxReduce + NodataResample + FootprintStatistic + SrgbPixel,
+    {
+        if !self.gamma_correction
+            || plan.nodata.is_some()
+            || matches!(plan.steps, PlanSteps::Nearest | PlanSteps::Footprint { .. })
+        {
+            self.resize_inner(plan, src_image, dst_image);
+            return;
+        }
+        // Buffers are temporary moved out of `self` because
+        // `resize_inner()` needs the mutable reference to `self`.
+        let mut src_buffer = std::mem::take(&mut self.linear_src_buffer);
+        let mut dst_buffer = std::mem::take(&mut self.linear_dst_buffer);
+
+        // Only pixels that are read by the plan are converted
+        // into linear color space.
+        let region = plan.used_src_region();
+        let region_rows = get_region_rows(&src_image, region);
+        let src_region = TypedImageView::new(region.width, region.height, &region_rows);
+        let mut linear_src_image =
+            get_temp_image_from_buffer(&mut src_buffer, src_image.width(), src_image.height());
+        P::to_linear(
+            &src_region,
+            &mut linear_src_image.region(region),
+            self.cpu_extensions,
+        );
+        let mut linear_dst_image =
+            get_temp_image_from_buffer(&mut dst_buffer, dst_image.width(), dst_image.height());
+
+        let mut linear_src_view = linear_src_image.src_view();
+        linear_src_view.set_crop_box(src_image.crop_box());
+        self.resize_inner(plan, linear_src_view, linear_dst_image.dst_view());
+        P::from_linear(&mut linear_dst_image, dst_image, self.cpu_extensions);
+
+        self.linear_src_buffer = src_buffer;
+        self.linear_dst_buffer = dst_buffer;
+    }
+
     /// Returns the size of internal buffers used to store the results of
     /// intermediate resizing steps.
     pub fn size_of_internal_buffers(&self) -> usize {
-        (self.convolution_buffer.capacity() + self.super_sampling_buffer.capacity())
+        (self.convolution_buffers.temp.capacity()
+            + self.convolution_buffers.wrapped_src.capacity()
+            + self.super_sampling_buffer.capacity()
+            + self.linear_src_buffer.capacity()
+            + self.linear_dst_buffer.capacity())
             * std::mem::size_of::<u8>()
     }
 
     /// Deallocates the internal buffers used to store the results of
     /// intermediate resizing steps.
     pub fn reset_internal_buffers(&mut self) {
-        if self.convolution_buffer.capacity() > 0 {
-            self.convolution_buffer = Vec::new();
+        if self.convolution_buffers.temp.capacity() > 0 {
+            self.convolution_buffers.temp = Vec::new();
+        }
+        if self.convolution_buffers.wrapped_src.capacity() > 0 {
+            self.convolution_buffers.wrapped_src = Vec::new();
         }
         if self.super_sampling_buffer.capacity() > 0 {
             self.super_sampling_buffer = Vec::new();
         }
+        if self.linear_src_buffer.capacity() > 0 {
+            self.linear_src_buffer = Vec::new();
+        }
+        if self.linear_dst_buffer.capacity() > 0 {
+            self.linear_dst_buffer = Vec::new();
+        }
     }
 
     #[inline(always)]
@@ -192,39 +532,52 @@ impl Resizer {
     }
 }
 
-fn get_temp_image_from_buffer<P: Pixel>(
+pub(crate) fn get_temp_image_from_buffer<P: Pixel>(
     buffer: &mut Vec<u8>,
     width: NonZeroU32,
     height: NonZeroU32,
-) -> InnerImage<P> {
+) -> InnerImage<'_, P> {
     let pixels_count = (width.get() * height.get()) as usize;
+    InnerImage::new(
+        width,
+        height,
+        get_temp_pixels_from_buffer(buffer, pixels_count),
+    )
+}
+
+pub(crate) fn get_temp_pixels_from_buffer<P: Pixel>(
+    buffer: &mut Vec<u8>,
+    pixels_count: usize,
+) -> &mut [P] {
     // Add pixel size as gap for alignment of resulted buffer.
     let buf_size = pixels_count * P::size() + P::size();
     if buffer.len() < buf_size {
         buffer.resize(buf_size, 0);
     }
     let pixels = unsafe { buffer.align_to_mut::<P>().1 };
-    InnerImage::new(width, height, &mut pixels[0..pixels_count])
+    &mut pixels[0..pixels_count]
 }
 
-fn resample_nearest<P>(src_image: TypedImageView<P>, mut dst_image: TypedImageViewMut<P>)
-where
+fn resample_nearest<P>(
+    src_image: TypedImageView<P>,
+    mut dst_image: TypedImageViewMut<P>,
+    sampling_grid: SamplingGrid,
+) where
     P: Pixel,
 {
     let crop_box = src_image.crop_box();
     let dst_width = dst_image.width().get();
-    let x_scale = crop_box.width.get() as f64 / dst_width as f64;
-    let y_scale = crop_box.height.get() as f64 / dst_image.height().get() as f64;
+    let (x_in_start, x_scale) =
+        sampling_grid.nearest_samples(crop_box.left, crop_box.width, dst_width);
+    let (y_in_start, y_scale) =
+        sampling_grid.nearest_samples(crop_box.top, crop_box.height, dst_image.height().get());
 
     // Pretabulate horizontal pixel positions
-    let x_in_start = crop_box.left as f64 + x_scale * 0.5;
-    let max_src_x = src_image.width().get() as usize;
+    let max_src_x = src_image.width().get() as usize - 1;
     let x_in_tab: Vec<usize> = (0..dst_width)
         .map(|x| ((x_in_start + x_scale * x as f64) as usize).min(max_src_x))
         .collect();
 
-    let y_in_start = crop_box.top as f64 + y_scale * 0.5;
-
     let src_rows =
         src_image.iter_rows_with_step(y_in_start, y_scale, dst_image.height().get() as usize);
     let dst_rows = dst_image.iter_rows_mut();
@@ -239,123 +592,139 @@ where
 fn resample_convolution<P>(
     src_image: TypedImageView<P>,
     dst_image: TypedImageViewMut<P>,
-    filter_type: FilterType,
+    plan: &ConvolutionPlan,
     cpu_extensions: CpuExtensions,
-    temp_buffer: &mut Vec<u8>,
+    nodata: Option<f64>,
+    buffers: &mut ConvolutionBuffers,
 ) where
-    P: Convolution,
+    P: Convolution + NodataResample,
 {
-    let crop_box = src_image.crop_box();
-    let dst_width = dst_image.width();
-    let dst_height = dst_image.height();
-    let (filter_fn, filter_support) = convolution::get_filter_func(filter_type);
-
-    let need_horizontal = dst_width != src_image.width() || crop_box.width != src_image.width();
-    let need_vertical = dst_height != src_image.height() || crop_box.height != src_image.height();
-
-    let mut vert_coeffs = convolution::precompute_coefficients(
-        src_image.height(),
-        crop_box.top as f64,
-        crop_box.top as f64 + crop_box.height.get() as f64,
-        dst_height,
-        filter_fn,
-        filter_support,
-    );
-
-    if need_horizontal {
-        let horiz_coeffs = convolution::precompute_coefficients(
-            src_image.width(),
-            crop_box.left as f64,
-            crop_box.left as f64 + crop_box.width.get() as f64,
-            dst_width,
-            filter_fn,
-            filter_support,
-        );
-
-        // First used row in the source image
-        let y_first = vert_coeffs.bounds[0].start;
-
-        if need_vertical {
-            // Last used row in the source image
+    let region_rows: Vec<&[P]>;
+    let src_image = match plan.src_region {
+        Some(region) => {
+            region_rows = get_region_rows(&src_image, region);
+            TypedImageView::new(region.width, region.height, &region_rows)
+        }
+        None => src_image,
+    };
+    let wrapped_rows;
+    let src_image = match plan.wrap_padding {
+        (0, 0) => src_image,
+        (pad_x, pad_y) => {
+            wrapped_rows = get_wrapped_rows(&src_image, pad_x, pad_y, &mut buffers.wrapped_src);
+            TypedImageView::new(
+                NonZeroU32::new(src_image.width().get() + 2 * pad_x).unwrap(),
+                NonZeroU32::new(src_image.height().get() + 2 * pad_y).unwrap(),
+                &wrapped_rows,
+            )
+        }
+    };
+    if let Some(nodata) = nodata {
+        if plan.horiz_coeffs.is_some() || plan.vert_coeffs.is_some() {
+            let coeffs = (plan.horiz_coeffs.as_ref(), plan.vert_coeffs.as_ref());
+            P::nodata_convolution(src_image, dst_image, plan.y_first, coeffs, nodata);
+        }
+        return;
+    }
+    match (&plan.horiz_coeffs, &plan.vert_coeffs) {
+        (Some(horiz_coeffs), Some(vert_coeffs)) => {
+            // Bounds of vertical coefficients are already shifted
+            // to the start of the temporary image.
             let last_y_bound = vert_coeffs.bounds.last().unwrap();
-            let y_last = last_y_bound.start + last_y_bound.size;
-
-            let temp_height = NonZeroU32::new(y_last - y_first).unwrap();
-            let mut temp_image = get_temp_image_from_buffer(temp_buffer, dst_width, temp_height);
-            P::horiz_convolution(
+            let temp_height = NonZeroU32::new(last_y_bound.start + last_y_bound.size).unwrap();
+            let mut temp_image =
+                get_temp_image_from_buffer(&mut buffers.temp, dst_image.width(), temp_height);
+            threading::horiz_convolution(
                 src_image,
                 temp_image.dst_view(),
-                y_first,
+                plan.y_first,
                 horiz_coeffs,
                 cpu_extensions,
             );
-
-            // Shift bounds for vertical pass
-            vert_coeffs
-                .bounds
-                .iter_mut()
-                .for_each(|b| b.start -= y_first);
-            P::vert_convolution(
+            threading::vert_convolution(
                 temp_image.src_view(),
                 dst_image,
                 vert_coeffs,
                 cpu_extensions,
             );
-        } else {
-            P::horiz_convolution(src_image, dst_image, y_first, horiz_coeffs, cpu_extensions);
         }
-    } else if need_vertical {
-        P::vert_convolution(src_image, dst_image, vert_coeffs, cpu_extensions);
+        (Some(horiz_coeffs), None) => {
+            threading::horiz_convolution(
+                src_image,
+                dst_image,
+                plan.y_first,
+                horiz_coeffs,
+                cpu_extensions,
+            );
+        }
+        (None, Some(vert_coeffs)) => {
+            threading::vert_convolution(src_image, dst_image, vert_coeffs, cpu_extensions);
+        }
+        (None, None) => {}
     }
 }
 
-fn resample_super_sampling<P>(
+/// Returns rows of the region of the image.
+fn get_region_rows<'a, P: Pixel>(
+    src_image: &TypedImageView<'_, 'a, P>,
+    region: CropBox,
+) -> Vec<&'a [P]> {
+    let (left, width) = (region.left as usize, region.width.get() as usize);
+    src_image
+        .iter_rows(region.top)
+        .take(region.height.get() as usize)
+        .map(|row| &row[left..left + width])
+        .collect()
+}
+
+fn resample_reduce<P>(
     src_image: TypedImageView<P>,
     dst_image: TypedImageViewMut<P>,
-    filter_type: FilterType,
-    multiplicity: u8,
+    plan: &ReducePlan,
     cpu_extensions: CpuExtensions,
-    temp_buffer: &mut Vec<u8>,
-    convolution_temp_buffer: &mut Vec<u8>,
+    nodata: Option<f64>,
 ) where
-    P: Convolution,
+    P: BoxReduce + NodataResample,
 {
-    let crop_box = src_image.crop_box();
-    let dst_width = dst_image.width().get();
-    let dst_height = dst_image.height().get();
-    let width_scale = crop_box.width.get() as f32 / dst_width as f32;
-    let height_scale = crop_box.height.get() as f32 / dst_height as f32;
-    // It makes sense to resize the image in two steps only if the image
-    // size is greater than the required size by multiplicity times.
-    let factor = width_scale.min(height_scale) / multiplicity as f32;
-    if factor > 1.2 {
-        // First step is resizing the source image by fastest algorithm.
-        // The temporary image will be about ``multiplicity`` times larger
-        // than required.
-        let tmp_width =
-            NonZeroU32::new((crop_box.width.get() as f32 / factor).round() as u32).unwrap();
-        let tmp_height =
-            NonZeroU32::new((crop_box.height.get() as f32 / factor).round() as u32).unwrap();
+    let region = plan.src_region;
+    let region_rows = get_region_rows(&src_image, region);
+    let src_image = TypedImageView::new(region.width, region.height, &region_rows);
+    match nodata {
+        Some(nodata) => P::nodata_box_reduce(src_image, dst_image, plan.factors, nodata),
+        None => threading::box_reduce(src_image, dst_image, plan.factors, cpu_extensions),
+    }
+}
 
-        let mut tmp_img = get_temp_image_from_buffer(temp_buffer, tmp_width, tmp_height);
-        resample_nearest(src_image, tmp_img.dst_view());
-        // Second step is resizing the temporary image with a convolution.
-        resample_convolution(
-            tmp_img.src_view(),
-            dst_image,
-            filter_type,
-            cpu_extensions,
-            convolution_temp_buffer,
+/// Returns rows of the image extended by `pad_x` columns and `pad_y` rows
+/// from both sides with pixels from the opposite edges of the image.
+///
+/// Columns are copied into the buffer, additional rows refer to existing ones.
+fn get_wrapped_rows<'a, P: Pixel>(
+    src_image: &TypedImageView<'_, 'a, P>,
+    pad_x: u32,
+    pad_y: u32,
+    buffer: &'a mut Vec<u8>,
+) -> Vec<&'a [P]> {
+    let width = src_image.width().get() as i64;
+    let height = src_image.height().get() as i64;
+    let rows: Vec<&[P]> = if pad_x > 0 {
+        let pad_x = pad_x as i64;
+        let mut image = get_temp_image_from_buffer::<P>(
+            buffer,
+            NonZeroU32::new((width + 2 * pad_x) as u32).unwrap(),
+            src_image.height(),
         );
+        for (src_row, dst_row) in src_image.iter_rows(0).zip(image.dst_view().iter_rows_mut()) {
+            for (x, pixel) in dst_row.iter_mut().enumerate() {
+                *pixel = src_row[(x as i64 - pad_x).rem_euclid(width) as usize];
+            }
+        }
+        image.src_view().iter_rows(0).collect()
     } else {
-        // There is no point in doing the resizing in two steps.
-        // We immediately resize the original image with a convolution.
-        resample_convolution(
-            src_image,
-            dst_image,
-            filter_type,
-            cpu_extensions,
-            convolution_temp_buffer,
-        );
-    }
+        src_image.iter_rows(0).collect()
+    };
+    let pad_y = pad_y as i64;
+    (-pad_y..height + pad_y)
+        .map(|y| rows[y.rem_euclid(height) as usize])
+        .collect()
 }
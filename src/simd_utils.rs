@@ -1,5 +1,4 @@
 use std::arch::x86_64::*;
-use std::intrinsics::transmute;
 
 use crate::pixels::{U8x3, U8x4};
 
@@ -20,7 +19,7 @@ pub unsafe fn loadl_epi64<T>(buf: &[T], index: usize) -> __m128i {
 
 #[inline(always)]
 pub unsafe fn mm_cvtepu8_epi32(buf: &[U8x4], index: usize) -> __m128i {
-    let v: i32 = transmute(buf.get_unchecked(index).0);
+    let v = buf.get_unchecked(index).0 as i32;
     _mm_cvtepu8_epi32(_mm_cvtsi32_si128(v))
 }
 
@@ -34,21 +33,23 @@ pub unsafe fn mm_cvtepu8_epi32_u8x3(buf: &[U8x3], index: usize) -> __m128i {
 #[inline(always)]
 pub unsafe fn mm_cvtepu8_epi32_from_u8(buf: &[u8], index: usize) -> __m128i {
     let ptr = buf.get_unchecked(index..).as_ptr() as *const i32;
-    _mm_cvtepu8_epi32(_mm_cvtsi32_si128(*ptr))
+    _mm_cvtepu8_epi32(_mm_cvtsi32_si128(ptr.read_unaligned()))
 }
 
 #[inline(always)]
 pub unsafe fn mm_cvtsi32_si128_from_u8(buf: &[u8], index: usize) -> __m128i {
     let ptr = buf.get_unchecked(index..).as_ptr() as *const i32;
-    _mm_cvtsi32_si128(*ptr)
+    _mm_cvtsi32_si128(ptr.read_unaligned())
 }
 
 #[inline(always)]
 pub unsafe fn ptr_i16_to_set1_epi32(buf: &[i16], index: usize) -> __m128i {
-    _mm_set1_epi32(*(buf.get_unchecked(index..).as_ptr() as *const i32))
+    let ptr = buf.get_unchecked(index..).as_ptr() as *const i32;
+    _mm_set1_epi32(ptr.read_unaligned())
 }
 
 #[inline(always)]
 pub unsafe fn ptr_i16_to_256set1_epi32(buf: &[i16], index: usize) -> __m256i {
-    _mm256_set1_epi32(*(buf.get_unchecked(index..).as_ptr() as *const i32))
+    let ptr = buf.get_unchecked(index..).as_ptr() as *const i32;
+    _mm256_set1_epi32(ptr.read_unaligned())
 }
@@ -0,0 +1,153 @@
+//! Statistics of pixels of the source image covered by pixels of
+//! the destination image (footprints). They are suitable for categorical
+//! rasters (e.g. segmentation masks) which can't be averaged.
+use std::slice;
+
+use crate::convolution::Bound;
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::nodata::{self, Component};
+use crate::pixels::Pixel;
+use crate::plan::Footprints;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Statistic {
+    /// The most frequent pixel of the footprint
+    Mode,
+    /// Minimum of every component of pixels of the footprint
+    Min,
+    /// Maximum of every component of pixels of the footprint
+    Max,
+}
+
+pub(crate) trait FootprintStatistic
+where
+    Self: Pixel + Send + Sync,
+{
+    /// Sets every pixel of the destination image to the statistic of
+    /// pixels of its footprint in the source image.
+    ///
+    /// Components equal to the nodata value are excluded. If there are
+    /// no valid pixels in the footprint, the pixel of the destination
+    /// image is set to the nodata value.
+    fn footprint_statistic(
+        src_image: TypedImageView<Self>,
+        dst_image: TypedImageViewMut<Self>,
+        footprints: &Footprints,
+        statistic: Statistic,
+        nodata: Option<f64>,
+    );
+}
+
+impl<P> FootprintStatistic for P
+where
+    P: Pixel + Send + Sync,
+    P::Component: Component + PartialOrd,
+{
+    fn footprint_statistic(
+        src_image: TypedImageView<Self>,
+        mut dst_image: TypedImageViewMut<Self>,
+        footprints: &Footprints,
+        statistic: Statistic,
+        nodata: Option<f64>,
+    ) {
+        let is_valid = |component: P::Component| match nodata {
+            Some(value) => !nodata::is_nodata(component.to_f64(), value),
+            None => true,
+        };
+        let max_size = |bounds: &[Bound]| bounds.iter().map(|b| b.size).max().unwrap_or(0);
+        let max_rows = max_size(&footprints.rows) as usize;
+        let max_columns = max_size(&footprints.columns) as usize;
+        // Buffers are allocated once and reused by all pixels of
+        // the destination image.
+        // Rows of the source image covered by the current row of footprints
+        let mut src_rows: Vec<&[P]> = Vec::with_capacity(max_rows);
+        // Valid pixels of the footprint with bits of their components
+        // and their indexes in the footprint
+        let mut classes: Vec<([u32; 4], usize, P)> = match statistic {
+            Statistic::Mode => Vec::with_capacity(max_rows * max_columns),
+            _ => Vec::new(),
+        };
+        // Minimums or maximums of components
+        let mut extremes: Vec<Option<P::Component>> = vec![None; P::components_count()];
+
+        let dst_rows = dst_image.iter_rows_mut();
+        for (dst_row, rows) in dst_rows.zip(&footprints.rows) {
+            src_rows.clear();
+            src_rows.extend(src_image.iter_rows(rows.start).take(rows.size as usize));
+            for (dst_pixel, columns) in dst_row.iter_mut().zip(&footprints.columns) {
+                let range = columns.start as usize..(columns.start + columns.size) as usize;
+                let pixels = src_rows.iter().flat_map(|row| &row[range.clone()]);
+                match statistic {
+                    Statistic::Mode => {
+                        classes.clear();
+                        for (index, pixel) in pixels.enumerate() {
+                            let components = P::components(slice::from_ref(pixel));
+                            if !components.iter().all(|&c| is_valid(c)) {
+                                continue;
+                            }
+                            let mut bits = [0u32; 4];
+                            for (b, &c) in bits.iter_mut().zip(components) {
+                                *b = c.class_bits();
+                            }
+                            classes.push((bits, index, *pixel));
+                        }
+                        // Equal pixels become adjacent and ordered by their indexes.
+                        classes.sort_unstable_by_key(|&(bits, index, _)| (bits, index));
+                        let mut mode: Option<(usize, usize, P)> = None;
+                        let mut start = 0;
+                        while start < classes.len() {
+                            let (bits, index, pixel) = classes[start];
+                            let count = classes[start..].iter().take_while(|c| c.0 == bits).count();
+                            // Ties are resolved in favor of the pixel that is met first.
+                            let is_better = match mode {
+                                None => true,
+                                Some((mode_count, mode_index, _)) => {
+                                    count > mode_count
+                                        || (count == mode_count && index < mode_index)
+                                }
+                            };
+                            if is_better {
+                                mode = Some((count, index, pixel));
+                            }
+                            start += count;
+                        }
+                        match (mode, nodata) {
+                            (Some((_, _, pixel)), _) => *dst_pixel = pixel,
+                            (None, Some(nodata)) => P::components_mut(slice::from_mut(dst_pixel))
+                                .fill(Component::from_f64(nodata)),
+                            // Footprint without nodata value always has pixels.
+                            (None, None) => {}
+                        }
+                    }
+                    Statistic::Min | Statistic::Max => {
+                        extremes.fill(None);
+                        for pixel in pixels {
+                            let components = P::components(slice::from_ref(pixel));
+                            for (extreme, &component) in extremes.iter_mut().zip(components) {
+                                if !is_valid(component) {
+                                    continue;
+                                }
+                                let is_better = match (*extreme, statistic) {
+                                    (None, _) => true,
+                                    (Some(value), Statistic::Min) => component < value,
+                                    (Some(value), _) => component > value,
+                                };
+                                if is_better {
+                                    *extreme = Some(component);
+                                }
+                            }
+                        }
+                        let dst_components = P::components_mut(slice::from_mut(dst_pixel));
+                        for (component, &extreme) in dst_components.iter_mut().zip(&extremes) {
+                            *component = match (extreme, nodata) {
+                                (Some(value), _) => value,
+                                (None, Some(nodata)) => Component::from_f64(nodata),
+                                (None, None) => *component,
+                            };
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
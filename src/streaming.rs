@@ -0,0 +1,366 @@
+use std::num::NonZeroU32;
+use std::ops::Range;
+use std::slice;
+
+use crate::convolution::{Bound, Coefficients, Convolution, FilterType};
+use crate::image_view::{CropBox, FloatCropBox, TypedImageView, TypedImageViewMut};
+use crate::pixels::PixelType;
+use crate::plan::PlanSteps;
+use crate::resizer::{get_temp_image_from_buffer, get_temp_pixels_from_buffer};
+use crate::{CpuExtensions, ImageView, ResizeAlg, ResizePlan, StreamingResizeError};
+
+/// Resizer that receives rows of the source image incrementally and
+/// emits rows of the destination image as soon as they are ready.
+///
+/// Only the rows required by the vertical pass of convolution are kept
+/// in memory, so the whole source image and the full-height temporary
+/// image are never allocated. Result of resizing is equal to the result of
+/// [Resizer](crate::Resizer) with the same plan of resizing
+/// (see [StreamingResizer::from_plan]).
+#[derive(Debug, Clone)]
+pub struct StreamingResizer {
+    pixel_type: PixelType,
+    src_width: NonZeroU32,
+    src_height: NonZeroU32,
+    dst_width: NonZeroU32,
+    dst_height: NonZeroU32,
+    cpu_extensions: CpuExtensions,
+    /// Region of the source image that is used as the whole image
+    /// by convolution.
+    src_region: CropBox,
+    horiz_coeffs: Option<Coefficients>,
+    /// Rows of the source image used by every row of the destination
+    /// image and coefficients of its vertical pass. It is empty
+    /// if the vertical pass isn't required.
+    vert_rows: Vec<(Bound, Coefficients)>,
+    /// Rows of the source image used by convolution.
+    used_rows: Range<u32>,
+    /// Height of the ring buffer with horizontally convolved rows.
+    ring_height: NonZeroU32,
+    ring_buffer: Vec<u8>,
+    dst_row_buffer: Vec<u8>,
+    pushed_rows: u32,
+    emitted_rows: u32,
+}
+
+impl StreamingResizer {
+    /// Creates instance of `StreamingResizer` for images with given
+    /// type of pixels and sizes.
+    ///
+    /// Use [StreamingResizer::from_plan] to resize images with a crop box
+    /// or options of convolution (blur factor, edge mode, sampling grid).
+    ///
+    /// By default, instance of `StreamingResizer` created with best CPU-extensions
+    /// provided by your CPU. You can change this by use method
+    /// [StreamingResizer::set_cpu_extensions].
+    pub fn new(
+        filter_type: FilterType,
+        pixel_type: PixelType,
+        src_width: NonZeroU32,
+        src_height: NonZeroU32,
+        dst_width: NonZeroU32,
+        dst_height: NonZeroU32,
+    ) -> Self {
+        let plan = ResizePlan::new(
+            ResizeAlg::Convolution(filter_type),
+            pixel_type,
+            src_width,
+            src_height,
+            FloatCropBox::new(src_width, src_height),
+            dst_width,
+            dst_height,
+        )
+        .expect("crop box of the whole image is always inside of the image");
+        Self::from_plan(&plan).expect("plan of convolution with default options is supported")
+    }
+
+    /// Creates instance of `StreamingResizer` that resizes images
+    /// by given plan.
+    ///
+    /// The plan must resize images by convolution in one step
+    /// (e.g. `ResizeAlg::Convolution` or `ResizeAlg::Area`) without
+    /// the nodata value. `EdgeMode::Wrap` mode is supported only if
+    /// taps of filters don't leave the source image, because wrapped
+    /// taps refer to rows from the opposite edge of the image.
+    /// All rows of the source image must be pushed, including rows
+    /// outside of the crop box of the plan.
+    pub fn from_plan(plan: &ResizePlan) -> Result<Self, StreamingResizeError> {
+        let convolution = match &plan.steps {
+            PlanSteps::Convolution(convolution)
+            | PlanSteps::SuperSampling {
+                tmp_size: None,
+                convolution,
+            } => convolution,
+            _ => return Err(StreamingResizeError::UnsupportedPlan),
+        };
+        if plan.nodata.is_some() || convolution.wrap_padding != (0, 0) {
+            return Err(StreamingResizeError::UnsupportedPlan);
+        }
+        let src_region = convolution.src_region.unwrap_or(CropBox {
+            left: 0,
+            top: 0,
+            width: plan.src_width,
+            height: plan.src_height,
+        });
+        // Bounds of vertical coefficients are shifted by `y_first`
+        // if the horizontal pass is required.
+        let rows_offset = match convolution.horiz_coeffs {
+            Some(_) => src_region.top + convolution.y_first,
+            None => src_region.top,
+        };
+        let vert_rows: Vec<(Bound, Coefficients)> = convolution
+            .vert_coeffs
+            .iter()
+            .flat_map(|coeffs| {
+                coeffs.bounds.iter().enumerate().map(move |(y, &bound)| {
+                    // Rows of the source view start from the first row of the bound.
+                    let mut row_coeffs = coeffs.part(y, 1);
+                    row_coeffs.bounds[0].start = 0;
+                    let bound = Bound {
+                        start: rows_offset + bound.start,
+                        size: bound.size,
+                    };
+                    (bound, row_coeffs)
+                })
+            })
+            .collect();
+        let used_rows = match (vert_rows.first(), vert_rows.last()) {
+            (Some((first, _)), Some((last, _))) => first.start..last.start + last.size,
+            _ => src_region.top..src_region.top + src_region.height.get(),
+        };
+        let ring_height = vert_rows
+            .iter()
+            .map(|(bound, _)| bound.size)
+            .max()
+            .and_then(NonZeroU32::new)
+            .unwrap_or_else(|| NonZeroU32::new(1).unwrap());
+
+        Ok(Self {
+            pixel_type: plan.pixel_type,
+            src_width: plan.src_width,
+            src_height: plan.src_height,
+            dst_width: plan.dst_width,
+            dst_height: plan.dst_height,
+            cpu_extensions: Default::default(),
+            src_region,
+            horiz_coeffs: convolution.horiz_coeffs.clone(),
+            vert_rows,
+            used_rows,
+            ring_height,
+            ring_buffer: Vec::new(),
+            dst_row_buffer: Vec::new(),
+            pushed_rows: 0,
+            emitted_rows: 0,
+        })
+    }
+
+    #[inline(always)]
+    pub fn pixel_type(&self) -> PixelType {
+        self.pixel_type
+    }
+
+    #[inline(always)]
+    pub fn dst_width(&self) -> NonZeroU32 {
+        self.dst_width
+    }
+
+    #[inline(always)]
+    pub fn dst_height(&self) -> NonZeroU32 {
+        self.dst_height
+    }
+
+    /// Returns `true` if all rows of the destination image have been emitted.
+    #[inline(always)]
+    pub fn is_finished(&self) -> bool {
+        self.emitted_rows == self.dst_height.get()
+    }
+
+    #[inline(always)]
+    pub fn cpu_extensions(&self) -> CpuExtensions {
+        self.cpu_extensions
+    }
+
+    /// # Safety
+    /// This is unsafe because this method allows you to set a CPU-extensions
+    /// that is not actually supported by your CPU.
+    pub unsafe fn set_cpu_extensions(&mut self, extensions: CpuExtensions) {
+        self.cpu_extensions = extensions;
+    }
+
+    /// Pushes next rows of the source image and calls `callback` for
+    /// every finished row of the destination image.
+    ///
+    /// `callback` receives index and buffer of the destination row.
+    /// Rows are emitted in order from top to bottom.
+    /// Crop box of `src_rows` is ignored, all its rows are pushed.
+    pub fn push_rows<F>(
+        &mut self,
+        src_rows: &ImageView,
+        mut callback: F,
+    ) -> Result<(), StreamingResizeError>
+    where
+        F: FnMut(u32, &[u8]),
+    {
+        if src_rows.pixel_type() != self.pixel_type {
+            return Err(StreamingResizeError::PixelTypeIsDifferent);
+        }
+        if src_rows.width() != self.src_width {
+            return Err(StreamingResizeError::InvalidRowSize);
+        }
+        if src_rows.height().get() > self.src_height.get() - self.pushed_rows {
+            return Err(StreamingResizeError::TooManyRows);
+        }
+        match self.pixel_type {
+            PixelType::U8x2 => {
+                if let Some(src) = src_rows.u8x2_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::U8x3 => {
+                if let Some(src) = src_rows.u8x3_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::U8x4 => {
+                if let Some(src) = src_rows.u8x4_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::U16 => {
+                if let Some(src) = src_rows.u16_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::U16x3 => {
+                if let Some(src) = src_rows.u16x3_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::U16x4 => {
+                if let Some(src) = src_rows.u16x4_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::I32 => {
+                if let Some(src) = src_rows.i32_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::F32 => {
+                if let Some(src) = src_rows.f32_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::F32x3 => {
+                if let Some(src) = src_rows.f32x3_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::F32x4 => {
+                if let Some(src) = src_rows.f32x4_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+            PixelType::U8 => {
+                if let Some(src) = src_rows.u8_image() {
+                    self.push_rows_inner(src, &mut callback);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn push_rows_inner<P, F>(&mut self, src_image: TypedImageView<P>, callback: &mut F)
+    where
+        P: Convolution,
+        F: FnMut(u32, &[u8]),
+    {
+        let one = NonZeroU32::new(1).unwrap();
+        let ring_height = self.ring_height.get();
+        // Every row of the ring buffer is stored twice, so rows used
+        // by a row of the destination image are always adjacent.
+        let mut ring_image = get_temp_image_from_buffer::<P>(
+            &mut self.ring_buffer,
+            self.dst_width,
+            NonZeroU32::new(2 * ring_height).unwrap(),
+        );
+        let mut dst_row = get_temp_pixels_from_buffer::<P>(
+            &mut self.dst_row_buffer,
+            self.dst_width.get() as usize,
+        );
+        let left = self.src_region.left as usize;
+        let right = left + self.src_region.width.get() as usize;
+
+        for src_row in src_image.iter_rows(0) {
+            let src_y = self.pushed_rows;
+            self.pushed_rows += 1;
+
+            // Horizontal pass of the pushed row into the ring buffer.
+            if self.used_rows.contains(&src_y) {
+                let src_row = &src_row[left..right];
+                let mut ring_view = ring_image.dst_view();
+                let mut ring_rows = ring_view.iter_rows_mut();
+                let ring_row = ring_rows.nth((src_y % ring_height) as usize);
+                let ring_row_copy = ring_rows.nth(ring_height as usize - 1);
+                if let (Some(ring_row), Some(ring_row_copy)) = (ring_row, ring_row_copy) {
+                    match &self.horiz_coeffs {
+                        Some(coeffs) => {
+                            let src_rows = [src_row];
+                            let src_view =
+                                TypedImageView::new(self.src_region.width, one, &src_rows);
+                            let dst_view = TypedImageViewMut::new(
+                                self.dst_width,
+                                one,
+                                slice::from_mut(ring_row),
+                            );
+                            P::horiz_convolution(
+                                src_view,
+                                dst_view,
+                                0,
+                                coeffs,
+                                self.cpu_extensions,
+                            );
+                        }
+                        None => ring_row.copy_from_slice(src_row),
+                    }
+                    ring_row_copy.copy_from_slice(ring_row);
+                }
+            }
+
+            // Vertical pass for all destination rows that can be finished.
+            let ring_view = ring_image.src_view();
+            while self.emitted_rows < self.dst_height.get() {
+                let dst_y = self.emitted_rows;
+                if self.vert_rows.is_empty() {
+                    let src_y = self.src_region.top + dst_y;
+                    if src_y >= self.pushed_rows {
+                        break;
+                    }
+                    if let Some(row) = ring_view.get_row(src_y % ring_height) {
+                        callback(dst_y, pixels_as_bytes(row));
+                    }
+                    self.emitted_rows += 1;
+                    continue;
+                }
+                let (bound, coeffs) = &self.vert_rows[dst_y as usize];
+                if bound.start + bound.size > self.pushed_rows {
+                    break;
+                }
+                let src_view = ring_view.rows_view(
+                    bound.start % ring_height,
+                    NonZeroU32::new(bound.size).unwrap(),
+                );
+                let dst_view =
+                    TypedImageViewMut::new(self.dst_width, one, slice::from_mut(&mut dst_row));
+                P::vert_convolution(src_view, dst_view, coeffs, self.cpu_extensions);
+                callback(dst_y, pixels_as_bytes(dst_row));
+                self.emitted_rows += 1;
+            }
+        }
+    }
+}
+
+fn pixels_as_bytes<P: Copy>(pixels: &[P]) -> &[u8] {
+    let size = std::mem::size_of_val(pixels);
+    unsafe { std::slice::from_raw_parts(pixels.as_ptr() as *const u8, size) }
+}
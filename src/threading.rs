@@ -0,0 +1,138 @@
+//! Passes of convolution and reducing that are run in several threads
+//! if the `rayon` feature is enabled.
+use crate::convolution::{Coefficients, Convolution};
+use crate::image_view::{TypedImageView, TypedImageViewMut};
+use crate::reduce::BoxReduce;
+use crate::CpuExtensions;
+
+#[cfg(not(feature = "rayon"))]
+#[inline(always)]
+pub(crate) fn horiz_convolution<P: Convolution>(
+    src_image: TypedImageView<P>,
+    dst_image: TypedImageViewMut<P>,
+    offset: u32,
+    coeffs: &Coefficients,
+    cpu_extensions: CpuExtensions,
+) {
+    P::horiz_convolution(src_image, dst_image, offset, coeffs, cpu_extensions);
+}
+
+#[cfg(not(feature = "rayon"))]
+#[inline(always)]
+pub(crate) fn vert_convolution<P: Convolution>(
+    src_image: TypedImageView<P>,
+    dst_image: TypedImageViewMut<P>,
+    coeffs: &Coefficients,
+    cpu_extensions: CpuExtensions,
+) {
+    P::vert_convolution(src_image, dst_image, coeffs, cpu_extensions);
+}
+
+#[cfg(not(feature = "rayon"))]
+#[inline(always)]
+pub(crate) fn box_reduce<P: BoxReduce>(
+    src_image: TypedImageView<P>,
+    dst_image: TypedImageViewMut<P>,
+    factors: (u32, u32),
+    cpu_extensions: CpuExtensions,
+) {
+    P::box_reduce(src_image, dst_image, factors, cpu_extensions);
+}
+
+#[cfg(feature = "rayon")]
+pub(crate) use parallel::{box_reduce, horiz_convolution, vert_convolution};
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use std::num::NonZeroU32;
+
+    use rayon::prelude::*;
+
+    use super::*;
+
+    /// Returns height of the part of destination image processed by one thread.
+    ///
+    /// Height is a multiple of 4 because SIMD-versions of convolution
+    /// process four rows at once.
+    fn part_height(dst_height: NonZeroU32) -> NonZeroU32 {
+        let threads = rayon::current_num_threads() as u32;
+        let height = dst_height.get().div_ceil(threads);
+        NonZeroU32::new((height + 3) & !3).unwrap()
+    }
+
+    /// Splits the destination image by rows into parts and
+    /// convolves every part in separate thread.
+    pub(crate) fn horiz_convolution<P: Convolution>(
+        src_image: TypedImageView<P>,
+        mut dst_image: TypedImageViewMut<P>,
+        offset: u32,
+        coeffs: &Coefficients,
+        cpu_extensions: CpuExtensions,
+    ) {
+        let part_height = part_height(dst_image.height());
+        if part_height >= dst_image.height() {
+            P::horiz_convolution(src_image, dst_image, offset, coeffs, cpu_extensions);
+            return;
+        }
+        let dst_parts: Vec<_> = dst_image.split_by_height(part_height).collect();
+        dst_parts
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(i, dst_part)| {
+                let start_y = offset + i as u32 * part_height.get();
+                let src_part = src_image.rows_view(start_y, dst_part.height());
+                P::horiz_convolution(src_part, dst_part, 0, coeffs, cpu_extensions);
+            });
+    }
+
+    /// Splits the destination image by rows into parts and
+    /// convolves every part in separate thread.
+    pub(crate) fn vert_convolution<P: Convolution>(
+        src_image: TypedImageView<P>,
+        mut dst_image: TypedImageViewMut<P>,
+        coeffs: &Coefficients,
+        cpu_extensions: CpuExtensions,
+    ) {
+        let part_height = part_height(dst_image.height());
+        if part_height >= dst_image.height() {
+            P::vert_convolution(src_image, dst_image, coeffs, cpu_extensions);
+            return;
+        }
+        let dst_parts: Vec<_> = dst_image.split_by_height(part_height).collect();
+        dst_parts
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(i, dst_part)| {
+                let first_row = i * part_height.get() as usize;
+                let rows_count = dst_part.height().get() as usize;
+                let part_coeffs = coeffs.part(first_row, rows_count);
+                P::vert_convolution(src_image, dst_part, &part_coeffs, cpu_extensions);
+            });
+    }
+
+    /// Splits the destination image by rows into parts and
+    /// reduces every part in separate thread.
+    pub(crate) fn box_reduce<P: BoxReduce>(
+        src_image: TypedImageView<P>,
+        mut dst_image: TypedImageViewMut<P>,
+        factors: (u32, u32),
+        cpu_extensions: CpuExtensions,
+    ) {
+        let part_height = part_height(dst_image.height());
+        if part_height >= dst_image.height() {
+            P::box_reduce(src_image, dst_image, factors, cpu_extensions);
+            return;
+        }
+        let src_height = src_image.height().get();
+        let dst_parts: Vec<_> = dst_image.split_by_height(part_height).collect();
+        dst_parts
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(i, dst_part)| {
+                let start_y = i as u32 * part_height.get() * factors.1;
+                let height = (dst_part.height().get() * factors.1).min(src_height - start_y);
+                let src_part = src_image.rows_view(start_y, NonZeroU32::new(height).unwrap());
+                P::box_reduce(src_part, dst_part, factors, cpu_extensions);
+            });
+    }
+}
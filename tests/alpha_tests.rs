@@ -1,10 +1,11 @@
 use std::num::NonZeroU32;
 
-use fast_image_resize::pixels::U8x4;
+use fast_image_resize::pixels::{F32x4, U16x4, U8x2, U8x4};
 use fast_image_resize::{
-    CpuExtensions, Image, ImageRows, ImageRowsMut, ImageView, ImageViewMut, MulDiv, PixelType,
+    CpuExtensions, Image, ImageRows, ImageRowsMut, ImageView, ImageViewMut, MulDiv,
+    MulDivImagesError, PixelType,
 };
-use utils::{cpu_ext_into_str, image_checksum};
+use utils::{cpu_ext_into_str, image_checksum, image_u16_checksum};
 
 mod utils;
 
@@ -12,6 +13,18 @@ const fn p(r: u8, g: u8, b: u8, a: u8) -> U8x4 {
     U8x4(u32::from_le_bytes([r, g, b, a]))
 }
 
+const fn p2(l: u8, a: u8) -> U8x2 {
+    U8x2(u16::from_le_bytes([l, a]))
+}
+
+const fn p16(r: u16, g: u16, b: u16, a: u16) -> U16x4 {
+    U16x4([r, g, b, a])
+}
+
+const fn pf32(r: f32, g: f32, b: f32, a: f32) -> F32x4 {
+    F32x4([r, g, b, a])
+}
+
 // Multiplies by alpha
 
 fn multiply_alpha_test(cpu_extensions: CpuExtensions) {
@@ -253,3 +266,611 @@ fn divide_alpha_real_image_test() {
         assert_eq!(checksum, [8292504, 8292504, 8292504, 8355840]);
     }
 }
+
+// U16x4
+
+fn multiply_alpha_u16x4_test(cpu_extensions: CpuExtensions) {
+    let width: u32 = 4 + 4 + 3;
+    let height: u32 = 3;
+
+    let src_pixels = [
+        p16(65535, 32768, 0, 32768),
+        p16(65535, 32768, 0, 65535),
+        p16(65535, 32768, 0, 0),
+    ];
+    let res_pixels = [
+        p16(32768, 16384, 0, 32768),
+        p16(65535, 32768, 0, 65535),
+        p16(0, 0, 0, 0),
+    ];
+
+    let mut src_rows: [Vec<U16x4>; 3] = [
+        vec![src_pixels[0]; width as usize],
+        vec![src_pixels[1]; width as usize],
+        vec![src_pixels[2]; width as usize],
+    ];
+
+    let rows: Vec<&[U16x4]> = src_rows.iter().map(|r| r.as_ref()).collect();
+    let src_image_view = ImageView::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRows::U16x4(rows),
+    )
+    .unwrap();
+
+    let mut dst_image = Image::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        PixelType::U16x4,
+    );
+    let mut dst_image_view = dst_image.view_mut();
+
+    let mut alpha_mul_div: MulDiv = Default::default();
+    unsafe {
+        alpha_mul_div.set_cpu_extensions(cpu_extensions);
+    }
+
+    alpha_mul_div
+        .multiply_alpha(&src_image_view, &mut dst_image_view)
+        .unwrap();
+
+    let dst_pixels = unsafe { dst_image.buffer().align_to::<U16x4>().1 };
+    let dst_rows = dst_pixels.chunks_exact(width as usize);
+    for (row, &valid_pixel) in dst_rows.zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+
+    // Inplace
+    let rows: Vec<&mut [U16x4]> = src_rows.iter_mut().map(|r| r.as_mut()).collect();
+    let mut image_view = ImageViewMut::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRowsMut::U16x4(rows),
+    )
+    .unwrap();
+    alpha_mul_div
+        .multiply_alpha_inplace(&mut image_view)
+        .unwrap();
+
+    for (row, &valid_pixel) in src_rows.iter().zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn multiply_alpha_u16x4_avx2_test() {
+    multiply_alpha_u16x4_test(CpuExtensions::Avx2);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn multiply_alpha_u16x4_sse4_test() {
+    multiply_alpha_u16x4_test(CpuExtensions::Sse4_1);
+}
+
+#[test]
+fn multiply_alpha_u16x4_native_test() {
+    multiply_alpha_u16x4_test(CpuExtensions::None);
+}
+
+fn divide_alpha_u16x4_test(cpu_extensions: CpuExtensions) {
+    let width: u32 = 4 + 4 + 3;
+    let height: u32 = 3;
+
+    let src_pixels = [
+        p16(32768, 16384, 0, 32768),
+        p16(65535, 32768, 0, 65535),
+        p16(65535, 32768, 0, 0),
+    ];
+    let res_pixels = [
+        p16(65535, 32768, 0, 32768),
+        p16(65535, 32768, 0, 65535),
+        p16(0, 0, 0, 0),
+    ];
+
+    let mut src_rows: [Vec<U16x4>; 3] = [
+        vec![src_pixels[0]; width as usize],
+        vec![src_pixels[1]; width as usize],
+        vec![src_pixels[2]; width as usize],
+    ];
+
+    let rows: Vec<&[U16x4]> = src_rows.iter().map(|r| r.as_ref()).collect();
+    let src_image_view = ImageView::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRows::U16x4(rows),
+    )
+    .unwrap();
+
+    let mut dst_image = Image::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        PixelType::U16x4,
+    );
+    let mut dst_image_view = dst_image.view_mut();
+
+    let mut alpha_mul_div: MulDiv = Default::default();
+    unsafe {
+        alpha_mul_div.set_cpu_extensions(cpu_extensions);
+    }
+
+    alpha_mul_div
+        .divide_alpha(&src_image_view, &mut dst_image_view)
+        .unwrap();
+
+    let dst_pixels = unsafe { dst_image.buffer().align_to::<U16x4>().1 };
+    let dst_rows = dst_pixels.chunks_exact(width as usize);
+    for (row, &valid_pixel) in dst_rows.zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+
+    // Inplace
+    let rows: Vec<&mut [U16x4]> = src_rows.iter_mut().map(|r| r.as_mut()).collect();
+    let mut image_view = ImageViewMut::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRowsMut::U16x4(rows),
+    )
+    .unwrap();
+    alpha_mul_div.divide_alpha_inplace(&mut image_view).unwrap();
+
+    for (row, &valid_pixel) in src_rows.iter().zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn divide_alpha_u16x4_avx2_test() {
+    divide_alpha_u16x4_test(CpuExtensions::Avx2);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn divide_alpha_u16x4_sse4_test() {
+    divide_alpha_u16x4_test(CpuExtensions::Sse4_1);
+}
+
+#[test]
+fn divide_alpha_u16x4_native_test() {
+    divide_alpha_u16x4_test(CpuExtensions::None);
+}
+
+#[test]
+fn multiply_and_divide_alpha_u16x4_real_image_test() {
+    let mut pixels = vec![0u16; 256 * 256 * 4];
+    let mut i: usize = 0;
+    for alpha in 0..=255u16 {
+        for color in 0..=255u16 {
+            let pixel = pixels.get_mut(i..i + 4).unwrap();
+            let (color, alpha) = (color * 257, alpha * 257);
+            pixel.copy_from_slice(&[color, color, color, alpha]);
+            i += 4;
+        }
+    }
+    let pixels: Vec<u8> = pixels.iter().flat_map(|c| c.to_le_bytes()).collect();
+    let size = NonZeroU32::new(256).unwrap();
+    let src_image = Image::from_vec_u8(size, size, pixels, PixelType::U16x4).unwrap();
+    let mut mul_image = Image::new(size, size, PixelType::U16x4);
+    let mut div_image = Image::new(size, size, PixelType::U16x4);
+
+    let mut alpha_mul_div: MulDiv = Default::default();
+
+    let mut cpu_extensions_vec = vec![CpuExtensions::None];
+    #[cfg(target_arch = "x86_64")]
+    {
+        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
+        cpu_extensions_vec.push(CpuExtensions::Avx2);
+    }
+    for cpu_extensions in cpu_extensions_vec {
+        unsafe {
+            alpha_mul_div.set_cpu_extensions(cpu_extensions);
+        }
+        alpha_mul_div
+            .multiply_alpha(&src_image.view(), &mut mul_image.view_mut())
+            .unwrap();
+
+        let name = format!("multiple_alpha_u16x4-{}", cpu_ext_into_str(cpu_extensions));
+        utils::save_result(&mul_image, &name);
+
+        let checksum = image_u16_checksum::<4>(mul_image.buffer());
+        assert_eq!(checksum, [1073725440, 1073725440, 1073725440, 2147450880]);
+
+        alpha_mul_div
+            .divide_alpha(&mul_image.view(), &mut div_image.view_mut())
+            .unwrap();
+
+        let name = format!("divide_alpha_u16x4-{}", cpu_ext_into_str(cpu_extensions));
+        utils::save_result(&div_image, &name);
+
+        let checksum = image_u16_checksum::<4>(div_image.buffer());
+        assert_eq!(checksum, [2139063537, 2139063537, 2139063537, 2147450880]);
+    }
+}
+
+// U8x2
+
+fn multiply_alpha_u8x2_test(cpu_extensions: CpuExtensions) {
+    let width: u32 = 16 + 8 + 7;
+    let height: u32 = 3;
+
+    let src_pixels = [p2(255, 128), p2(255, 255), p2(255, 0)];
+    let res_pixels = [p2(128, 128), p2(255, 255), p2(0, 0)];
+
+    let mut src_rows: [Vec<U8x2>; 3] = [
+        vec![src_pixels[0]; width as usize],
+        vec![src_pixels[1]; width as usize],
+        vec![src_pixels[2]; width as usize],
+    ];
+
+    let rows: Vec<&[U8x2]> = src_rows.iter().map(|r| r.as_ref()).collect();
+    let src_image_view = ImageView::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRows::U8x2(rows),
+    )
+    .unwrap();
+
+    let mut dst_image = Image::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        PixelType::U8x2,
+    );
+    let mut dst_image_view = dst_image.view_mut();
+
+    let mut alpha_mul_div: MulDiv = Default::default();
+    unsafe {
+        alpha_mul_div.set_cpu_extensions(cpu_extensions);
+    }
+
+    alpha_mul_div
+        .multiply_alpha(&src_image_view, &mut dst_image_view)
+        .unwrap();
+
+    let dst_pixels = unsafe { dst_image.buffer().align_to::<U8x2>().1 };
+    let dst_rows = dst_pixels.chunks_exact(width as usize);
+    for (row, &valid_pixel) in dst_rows.zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+
+    // Inplace
+    let rows: Vec<&mut [U8x2]> = src_rows.iter_mut().map(|r| r.as_mut()).collect();
+    let mut image_view = ImageViewMut::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRowsMut::U8x2(rows),
+    )
+    .unwrap();
+    alpha_mul_div
+        .multiply_alpha_inplace(&mut image_view)
+        .unwrap();
+
+    for (row, &valid_pixel) in src_rows.iter().zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn multiply_alpha_u8x2_avx2_test() {
+    multiply_alpha_u8x2_test(CpuExtensions::Avx2);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn multiply_alpha_u8x2_sse4_test() {
+    multiply_alpha_u8x2_test(CpuExtensions::Sse4_1);
+}
+
+#[test]
+fn multiply_alpha_u8x2_native_test() {
+    multiply_alpha_u8x2_test(CpuExtensions::None);
+}
+
+fn divide_alpha_u8x2_test(cpu_extensions: CpuExtensions) {
+    let width: u32 = 16 + 8 + 7;
+    let height: u32 = 3;
+
+    let src_pixels = [p2(128, 128), p2(255, 255), p2(255, 0)];
+    let res_pixels = [p2(255, 128), p2(255, 255), p2(0, 0)];
+
+    let mut src_rows: [Vec<U8x2>; 3] = [
+        vec![src_pixels[0]; width as usize],
+        vec![src_pixels[1]; width as usize],
+        vec![src_pixels[2]; width as usize],
+    ];
+
+    let rows: Vec<&[U8x2]> = src_rows.iter().map(|r| r.as_ref()).collect();
+    let src_image_view = ImageView::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRows::U8x2(rows),
+    )
+    .unwrap();
+
+    let mut dst_image = Image::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        PixelType::U8x2,
+    );
+    let mut dst_image_view = dst_image.view_mut();
+
+    let mut alpha_mul_div: MulDiv = Default::default();
+    unsafe {
+        alpha_mul_div.set_cpu_extensions(cpu_extensions);
+    }
+
+    alpha_mul_div
+        .divide_alpha(&src_image_view, &mut dst_image_view)
+        .unwrap();
+
+    let dst_pixels = unsafe { dst_image.buffer().align_to::<U8x2>().1 };
+    let dst_rows = dst_pixels.chunks_exact(width as usize);
+    for (row, &valid_pixel) in dst_rows.zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+
+    // Inplace
+    let rows: Vec<&mut [U8x2]> = src_rows.iter_mut().map(|r| r.as_mut()).collect();
+    let mut image_view = ImageViewMut::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRowsMut::U8x2(rows),
+    )
+    .unwrap();
+    alpha_mul_div.divide_alpha_inplace(&mut image_view).unwrap();
+
+    for (row, &valid_pixel) in src_rows.iter().zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn divide_alpha_u8x2_avx2_test() {
+    divide_alpha_u8x2_test(CpuExtensions::Avx2);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn divide_alpha_u8x2_sse4_test() {
+    divide_alpha_u8x2_test(CpuExtensions::Sse4_1);
+}
+
+#[test]
+fn divide_alpha_u8x2_native_test() {
+    divide_alpha_u8x2_test(CpuExtensions::None);
+}
+
+#[test]
+fn multiply_and_divide_alpha_u8x2_real_image_test() {
+    let mut pixels = vec![0u8; 256 * 256 * 2];
+    let mut i: usize = 0;
+    for alpha in 0..=255u8 {
+        for color in 0..=255u8 {
+            let pixel = pixels.get_mut(i..i + 2).unwrap();
+            pixel.copy_from_slice(&[color, alpha]);
+            i += 2;
+        }
+    }
+    let size = NonZeroU32::new(256).unwrap();
+    let src_image = Image::from_vec_u8(size, size, pixels, PixelType::U8x2).unwrap();
+    let mut mul_image = Image::new(size, size, PixelType::U8x2);
+    let mut div_image = Image::new(size, size, PixelType::U8x2);
+
+    let mut alpha_mul_div: MulDiv = Default::default();
+
+    let mut cpu_extensions_vec = vec![CpuExtensions::None];
+    #[cfg(target_arch = "x86_64")]
+    {
+        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
+        cpu_extensions_vec.push(CpuExtensions::Avx2);
+    }
+    for cpu_extensions in cpu_extensions_vec {
+        unsafe {
+            alpha_mul_div.set_cpu_extensions(cpu_extensions);
+        }
+        alpha_mul_div
+            .multiply_alpha(&src_image.view(), &mut mul_image.view_mut())
+            .unwrap();
+
+        let name = format!("multiple_alpha_u8x2-{}", cpu_ext_into_str(cpu_extensions));
+        utils::save_result(&mul_image, &name);
+
+        let checksum = image_checksum::<2>(mul_image.buffer());
+        assert_eq!(checksum, [4177920, 8355840]);
+
+        alpha_mul_div
+            .divide_alpha(&mul_image.view(), &mut div_image.view_mut())
+            .unwrap();
+
+        let name = format!("divide_alpha_u8x2-{}", cpu_ext_into_str(cpu_extensions));
+        utils::save_result(&div_image, &name);
+
+        let checksum = image_checksum::<2>(div_image.buffer());
+        assert_eq!(checksum, [8292504, 8355840]);
+    }
+}
+
+// F32x4
+
+fn multiply_alpha_f32x4_test(cpu_extensions: CpuExtensions) {
+    let width: u32 = 4 + 4 + 3;
+    let height: u32 = 3;
+
+    let src_pixels = [
+        pf32(1., 0.5, 0., 0.5),
+        pf32(1., 0.5, 0., 1.),
+        pf32(1., 0.5, 0., 0.),
+    ];
+    let res_pixels = [
+        pf32(0.5, 0.25, 0., 0.5),
+        pf32(1., 0.5, 0., 1.),
+        pf32(0., 0., 0., 0.),
+    ];
+
+    let mut src_rows: [Vec<F32x4>; 3] = [
+        vec![src_pixels[0]; width as usize],
+        vec![src_pixels[1]; width as usize],
+        vec![src_pixels[2]; width as usize],
+    ];
+
+    let rows: Vec<&[F32x4]> = src_rows.iter().map(|r| r.as_ref()).collect();
+    let src_image_view = ImageView::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRows::F32x4(rows),
+    )
+    .unwrap();
+
+    let mut dst_image = Image::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        PixelType::F32x4,
+    );
+    let mut dst_image_view = dst_image.view_mut();
+
+    let mut alpha_mul_div: MulDiv = Default::default();
+    unsafe {
+        alpha_mul_div.set_cpu_extensions(cpu_extensions);
+    }
+
+    alpha_mul_div
+        .multiply_alpha(&src_image_view, &mut dst_image_view)
+        .unwrap();
+
+    let dst_pixels = unsafe { dst_image.buffer().align_to::<F32x4>().1 };
+    let dst_rows = dst_pixels.chunks_exact(width as usize);
+    for (row, &valid_pixel) in dst_rows.zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+
+    // Inplace
+    let rows: Vec<&mut [F32x4]> = src_rows.iter_mut().map(|r| r.as_mut()).collect();
+    let mut image_view = ImageViewMut::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRowsMut::F32x4(rows),
+    )
+    .unwrap();
+    alpha_mul_div
+        .multiply_alpha_inplace(&mut image_view)
+        .unwrap();
+
+    for (row, &valid_pixel) in src_rows.iter().zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+}
+
+#[test]
+fn multiply_alpha_f32x4_native_test() {
+    multiply_alpha_f32x4_test(CpuExtensions::None);
+}
+
+fn divide_alpha_f32x4_test(cpu_extensions: CpuExtensions) {
+    let width: u32 = 4 + 4 + 3;
+    let height: u32 = 3;
+
+    let src_pixels = [
+        pf32(0.5, 0.25, 0., 0.5),
+        pf32(1., 0.5, 0., 1.),
+        pf32(1., 0.5, 0., 0.),
+    ];
+    let res_pixels = [
+        pf32(1., 0.5, 0., 0.5),
+        pf32(1., 0.5, 0., 1.),
+        pf32(0., 0., 0., 0.),
+    ];
+
+    let mut src_rows: [Vec<F32x4>; 3] = [
+        vec![src_pixels[0]; width as usize],
+        vec![src_pixels[1]; width as usize],
+        vec![src_pixels[2]; width as usize],
+    ];
+
+    let rows: Vec<&[F32x4]> = src_rows.iter().map(|r| r.as_ref()).collect();
+    let src_image_view = ImageView::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRows::F32x4(rows),
+    )
+    .unwrap();
+
+    let mut dst_image = Image::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        PixelType::F32x4,
+    );
+    let mut dst_image_view = dst_image.view_mut();
+
+    let mut alpha_mul_div: MulDiv = Default::default();
+    unsafe {
+        alpha_mul_div.set_cpu_extensions(cpu_extensions);
+    }
+
+    alpha_mul_div
+        .divide_alpha(&src_image_view, &mut dst_image_view)
+        .unwrap();
+
+    let dst_pixels = unsafe { dst_image.buffer().align_to::<F32x4>().1 };
+    let dst_rows = dst_pixels.chunks_exact(width as usize);
+    for (row, &valid_pixel) in dst_rows.zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+
+    // Inplace
+    let rows: Vec<&mut [F32x4]> = src_rows.iter_mut().map(|r| r.as_mut()).collect();
+    let mut image_view = ImageViewMut::new(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        ImageRowsMut::F32x4(rows),
+    )
+    .unwrap();
+    alpha_mul_div.divide_alpha_inplace(&mut image_view).unwrap();
+
+    for (row, &valid_pixel) in src_rows.iter().zip(res_pixels.iter()) {
+        for &pixel in row.iter() {
+            assert_eq!(pixel, valid_pixel);
+        }
+    }
+}
+
+#[test]
+fn divide_alpha_f32x4_native_test() {
+    divide_alpha_f32x4_test(CpuExtensions::None);
+}
+
+#[test]
+fn multiply_alpha_different_pixel_types_test() {
+    let size = NonZeroU32::new(8).unwrap();
+    let src_image = Image::new(size, size, PixelType::U8x4);
+    let mut dst_image = Image::new(size, size, PixelType::U16x4);
+    let alpha_mul_div: MulDiv = Default::default();
+    assert!(matches!(
+        alpha_mul_div.multiply_alpha(&src_image.view(), &mut dst_image.view_mut()),
+        Err(MulDivImagesError::PixelTypeIsDifferent)
+    ));
+}
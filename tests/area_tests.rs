@@ -0,0 +1,90 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{FilterType, Image, PixelType, ResizeAlg, Resizer};
+
+mod utils;
+
+#[test]
+fn area_reference_values() {
+    // Source image, its size, size of the destination image and
+    // expected result of `INTER_AREA` of OpenCV.
+    type Case<'a> = (&'a [u8], (u32, u32), (u32, u32), &'a [u8]);
+    let cases: [Case; 6] = [
+        // Downscaling by fractional factor
+        (&[0, 10, 20, 30, 40], (5, 1), (2, 1), &[8, 32]),
+        (
+            &[0, 30, 60, 90, 120, 150, 180],
+            (7, 1),
+            (3, 1),
+            &[21, 90, 159],
+        ),
+        (
+            &[0, 30, 60, 90, 120, 150, 180, 210, 240],
+            (3, 3),
+            (2, 2),
+            &[40, 80, 160, 200],
+        ),
+        // Upscaling
+        (&[0, 100], (2, 1), (3, 1), &[0, 50, 100]),
+        (&[0, 100], (2, 1), (4, 1), &[0, 0, 100, 100]),
+        (&[0, 100], (1, 2), (1, 5), &[0, 0, 50, 100, 100]),
+    ];
+    let cpu_extensions_vec = utils::cpu_extensions_vec();
+
+    for (src_pixels, (src_width, src_height), (dst_width, dst_height), expected) in cases {
+        let src_width = NonZeroU32::new(src_width).unwrap();
+        let src_height = NonZeroU32::new(src_height).unwrap();
+        let dst_width = NonZeroU32::new(dst_width).unwrap();
+        let dst_height = NonZeroU32::new(dst_height).unwrap();
+
+        let src_image =
+            Image::from_vec_u8(src_width, src_height, src_pixels.to_vec(), PixelType::U8).unwrap();
+        for &cpu_extensions in cpu_extensions_vec.iter() {
+            let mut dst_image = Image::new(dst_width, dst_height, PixelType::U8);
+            let mut resizer = Resizer::new(ResizeAlg::Area);
+            unsafe {
+                resizer.set_cpu_extensions(cpu_extensions);
+            }
+            resizer
+                .resize(&src_image.view(), &mut dst_image.view_mut())
+                .unwrap();
+            assert_eq!(dst_image.buffer(), expected, "{:?}", cpu_extensions);
+        }
+
+        let src_buffer: Vec<u8> = src_pixels
+            .iter()
+            .flat_map(|&v| (v as f32).to_le_bytes())
+            .collect();
+        let src_image =
+            Image::from_vec_u8(src_width, src_height, src_buffer, PixelType::F32).unwrap();
+        let mut dst_image = Image::new(dst_width, dst_height, PixelType::F32);
+        Resizer::new(ResizeAlg::Area)
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        let result: Vec<u8> = dst_image
+            .buffer()
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()).round() as u8)
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    // Box filter doesn't take pixels partially covered
+    // by the destination pixel with fractional weights.
+    let src_image = Image::from_vec_u8(
+        NonZeroU32::new(5).unwrap(),
+        NonZeroU32::new(1).unwrap(),
+        vec![0, 10, 20, 30, 40],
+        PixelType::U8,
+    )
+    .unwrap();
+    let mut dst_image = Image::new(
+        NonZeroU32::new(2).unwrap(),
+        NonZeroU32::new(1).unwrap(),
+        PixelType::U8,
+    );
+    Resizer::new(ResizeAlg::Convolution(FilterType::Box))
+        .resize(&src_image.view(), &mut dst_image.view_mut())
+        .unwrap();
+    assert_ne!(dst_image.buffer(), &[8, 32]);
+}
@@ -0,0 +1,167 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::color::mappers::{create_gamma_22_mapper, create_srgb_mapper};
+use fast_image_resize::color::{ColorMapper, MappingError};
+use fast_image_resize::{Image, PixelType};
+
+mod utils;
+
+fn new_image(width: u32, buffer: Vec<u8>, pixel_type: PixelType) -> Image<'static> {
+    let height = NonZeroU32::new(1).unwrap();
+    Image::from_vec_u8(NonZeroU32::new(width).unwrap(), height, buffer, pixel_type).unwrap()
+}
+
+#[test]
+fn srgb_u8x3_round_trip() {
+    // All possible values of component.
+    let src_buffer: Vec<u8> = (0..=255u8).flat_map(|v| [v, v, v]).collect();
+    let src_image = new_image(256, src_buffer.clone(), PixelType::U8x3);
+    let width = src_image.width();
+    let height = src_image.height();
+
+    let mut mapper = create_srgb_mapper();
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        unsafe {
+            mapper.set_cpu_extensions(cpu_extensions);
+        }
+        let mut linear_image = Image::new(width, height, PixelType::U16x3);
+        mapper
+            .forward_map(&src_image.view(), &mut linear_image.view_mut())
+            .unwrap();
+        let linear: Vec<u16> = linear_image
+            .buffer()
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        assert_eq!(&linear[..3], [0, 0, 0]);
+        // sRGB value 128 is about 21.6% of light intensity
+        assert_eq!(&linear[128 * 3..129 * 3], [14146, 14146, 14146]);
+        assert_eq!(&linear[255 * 3..], [65535, 65535, 65535]);
+
+        let mut dst_image = Image::new(width, height, PixelType::U8x3);
+        mapper
+            .backward_map(&linear_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        assert_eq!(dst_image.buffer(), src_buffer, "{:?}", cpu_extensions);
+    }
+}
+
+#[test]
+fn alpha_channel_is_not_mapped() {
+    let src_buffer = vec![128, 128, 128, 128, 0, 255, 64, 255];
+    let src_image = new_image(2, src_buffer, PixelType::U8x4);
+    let mut mapper = create_gamma_22_mapper();
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        unsafe {
+            mapper.set_cpu_extensions(cpu_extensions);
+        }
+        let mut dst_image = Image::new(src_image.width(), src_image.height(), PixelType::U8x4);
+        mapper
+            .forward_map(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        assert_eq!(dst_image.buffer(), [56, 56, 56, 128, 0, 255, 12, 255]);
+
+        let mut dst_image = Image::new(src_image.width(), src_image.height(), PixelType::F32x4);
+        mapper
+            .forward_map(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        let alpha: Vec<f32> = dst_image
+            .buffer()
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .skip(3)
+            .step_by(4)
+            .collect();
+        assert_eq!(alpha, [128. / 255., 1.]);
+    }
+}
+
+#[test]
+fn custom_mapper_for_f32_images() {
+    let mapper = ColorMapper::new(|v| v * 0.5, |v| v * 2.);
+    let src_buffer: Vec<u8> = [0.2f32, 0.5, 1.]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    let src_image = new_image(3, src_buffer.clone(), PixelType::F32);
+
+    let mut dst_image = Image::new(src_image.width(), src_image.height(), PixelType::F32);
+    mapper
+        .forward_map(&src_image.view(), &mut dst_image.view_mut())
+        .unwrap();
+    let expected: Vec<u8> = [0.1f32, 0.25, 0.5]
+        .iter()
+        .flat_map(|v| v.to_le_bytes())
+        .collect();
+    assert_eq!(dst_image.buffer(), expected);
+
+    let mut dst_image = Image::new(src_image.width(), src_image.height(), PixelType::U8);
+    mapper
+        .backward_map(&src_image.view(), &mut dst_image.view_mut())
+        .unwrap();
+    assert_eq!(dst_image.buffer(), [102, 255, 255]);
+}
+
+#[test]
+fn unsupported_images() {
+    let mapper = create_srgb_mapper();
+    let src_image = new_image(2, vec![0; 6], PixelType::U8x3);
+
+    let mut dst_image = Image::new(src_image.width(), src_image.height(), PixelType::U8x4);
+    assert!(matches!(
+        mapper.forward_map(&src_image.view(), &mut dst_image.view_mut()),
+        Err(MappingError::UnsupportedCombinationOfImageTypes)
+    ));
+
+    let mut dst_image = Image::new(src_image.height(), src_image.height(), PixelType::U8x3);
+    assert!(matches!(
+        mapper.forward_map(&src_image.view(), &mut dst_image.view_mut()),
+        Err(MappingError::SizeIsDifferent)
+    ));
+}
+
+#[test]
+fn simd_mapping_is_equal_to_native() {
+    let width = 8 + 8 + 7;
+    let pixel_types = [PixelType::U8x3, PixelType::U16x3, PixelType::F32x3];
+    let mut mapper = create_srgb_mapper();
+    for src_pixel_type in pixel_types {
+        let values = (0..width * 3).map(|v| v * 37 % 256);
+        let src_buffer: Vec<u8> = match src_pixel_type {
+            PixelType::U8x3 => values.map(|v| v as u8).collect(),
+            PixelType::U16x3 => values
+                .flat_map(|v| (v as u16 * 257).to_le_bytes())
+                .collect(),
+            _ => values
+                .flat_map(|v| (v as f32 / 255.).to_le_bytes())
+                .collect(),
+        };
+        let src_image = new_image(width, src_buffer, src_pixel_type);
+
+        for dst_pixel_type in pixel_types {
+            for forward in [true, false] {
+                let mut results = vec![];
+                for cpu_extensions in utils::cpu_extensions_vec() {
+                    unsafe {
+                        mapper.set_cpu_extensions(cpu_extensions);
+                    }
+                    let mut dst_image =
+                        Image::new(src_image.width(), src_image.height(), dst_pixel_type);
+                    let (src_view, mut dst_view) = (src_image.view(), dst_image.view_mut());
+                    if forward {
+                        mapper.forward_map(&src_view, &mut dst_view).unwrap();
+                    } else {
+                        mapper.backward_map(&src_view, &mut dst_view).unwrap();
+                    }
+                    results.push(dst_image.buffer().to_vec());
+                }
+                assert!(
+                    results.iter().all(|r| r == &results[0]),
+                    "{:?} -> {:?}",
+                    src_pixel_type,
+                    dst_pixel_type
+                );
+            }
+        }
+    }
+}
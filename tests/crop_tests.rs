@@ -0,0 +1,197 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{
+    CropBox, CropMode, EdgeMode, FilterType, FloatCropBox, Image, PixelType, ResizeAlg, Resizer,
+};
+
+#[test]
+fn fractional_crop_box() {
+    // Crop box with center between two pixels
+    let width = NonZeroU32::new(4).unwrap();
+    let one = NonZeroU32::new(1).unwrap();
+    let src_image = Image::from_vec_u8(width, one, vec![0, 200, 100, 50], PixelType::U8).unwrap();
+    let mut src_view = src_image.view();
+    src_view
+        .set_float_crop_box(FloatCropBox {
+            left: 0.5,
+            top: 0.,
+            width: 1.,
+            height: 1.,
+        })
+        .unwrap();
+    let mut dst_image = Image::new(one, one, PixelType::U8);
+
+    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Bilinear));
+    resizer
+        .resize(&src_view, &mut dst_image.view_mut())
+        .unwrap();
+    assert_eq!(dst_image.buffer(), [100]);
+
+    let mut resizer = Resizer::new(ResizeAlg::Nearest);
+    resizer
+        .resize(&src_view, &mut dst_image.view_mut())
+        .unwrap();
+    assert_eq!(dst_image.buffer(), [200]);
+
+    // Fractional crop box with integer bounds gives the same result
+    // as the integer crop box.
+    let mut src_view = src_image.view();
+    let crop_box = FloatCropBox {
+        left: 1.,
+        top: 0.,
+        width: 2.,
+        height: 1.,
+    };
+    src_view.set_float_crop_box(crop_box).unwrap();
+    let mut dst_image = Image::new(width, one, PixelType::U8);
+    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+    resizer
+        .resize(&src_view, &mut dst_image.view_mut())
+        .unwrap();
+    let mut src_view = src_image.view();
+    src_view
+        .set_crop_box(CropBox {
+            left: 1,
+            top: 0,
+            width: NonZeroU32::new(2).unwrap(),
+            height: one,
+        })
+        .unwrap();
+    assert_eq!(src_view.float_crop_box(), crop_box);
+    let mut expected = Image::new(width, one, PixelType::U8);
+    resizer.resize(&src_view, &mut expected.view_mut()).unwrap();
+    assert_eq!(dst_image.buffer(), expected.buffer());
+}
+
+#[test]
+fn crop_mode() {
+    let (width, height) = (40, 30);
+    let buffer: Vec<u8> = (0..width * height * 3)
+        .map(|i| (i * 7919 % 251) as u8)
+        .collect();
+    let src_image = Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        buffer.clone(),
+        PixelType::U8x3,
+    )
+    .unwrap();
+    // Copy of the region of the source image
+    let (left, top, crop_width, crop_height) = (7, 5, 20, 15);
+    let region_buffer: Vec<u8> = (top..top + crop_height)
+        .flat_map(|y| {
+            let start = ((y * width + left) * 3) as usize;
+            buffer[start..start + crop_width as usize * 3].to_vec()
+        })
+        .collect();
+    let region_image = Image::from_vec_u8(
+        NonZeroU32::new(crop_width).unwrap(),
+        NonZeroU32::new(crop_height).unwrap(),
+        region_buffer,
+        PixelType::U8x3,
+    )
+    .unwrap();
+
+    let algorithms = [
+        ResizeAlg::Convolution(FilterType::Lanczos3),
+        ResizeAlg::SuperSampling(FilterType::Bilinear, 2),
+    ];
+    for algorithm in algorithms {
+        for edge_mode in [EdgeMode::Renormalize, EdgeMode::Mirror] {
+            for (dst_width, dst_height) in [(9, 7), (20, 31), (45, 15)] {
+                let dst_width = NonZeroU32::new(dst_width).unwrap();
+                let dst_height = NonZeroU32::new(dst_height).unwrap();
+                let mut resizer = Resizer::new(algorithm);
+                resizer.edge_mode = edge_mode;
+
+                let mut expected = Image::new(dst_width, dst_height, PixelType::U8x3);
+                resizer
+                    .resize(&region_image.view(), &mut expected.view_mut())
+                    .unwrap();
+
+                let mut src_view = src_image.view();
+                src_view
+                    .set_crop_box(CropBox {
+                        left,
+                        top,
+                        width: NonZeroU32::new(crop_width).unwrap(),
+                        height: NonZeroU32::new(crop_height).unwrap(),
+                    })
+                    .unwrap();
+                let mut result = Image::new(dst_width, dst_height, PixelType::U8x3);
+                resizer.resize(&src_view, &mut result.view_mut()).unwrap();
+                // By default, the filter uses pixels outside of the crop box.
+                assert_ne!(result.buffer(), expected.buffer());
+
+                src_view.set_crop_mode(CropMode::Boundary);
+                resizer.resize(&src_view, &mut result.view_mut()).unwrap();
+                assert!(
+                    result.buffer() == expected.buffer(),
+                    "{:?} {:?} {}x{}",
+                    algorithm,
+                    edge_mode,
+                    dst_width,
+                    dst_height
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn crop_modes_with_tiny_float_crop_box() {
+    let (width, height) = (8, 4);
+    let buffer: Vec<u8> = (0..width * height)
+        .map(|i| (i * 7919 % 251) as u8)
+        .collect();
+    let src_image = Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        buffer.clone(),
+        PixelType::U8,
+    )
+    .unwrap();
+    // Size of the crop box is lost by rounding of its right and bottom edges.
+    let crop_box = FloatCropBox {
+        left: 3.,
+        top: 1.,
+        width: 1e-20,
+        height: 1e-20,
+    };
+    let pixel = buffer[(width + 3) as usize];
+
+    let algorithms = [
+        ResizeAlg::Nearest,
+        ResizeAlg::Convolution(FilterType::Lanczos3),
+        ResizeAlg::SuperSampling(FilterType::Bilinear, 2),
+        ResizeAlg::Reduce(FilterType::Bilinear, 2.),
+        ResizeAlg::Area,
+        ResizeAlg::Mode,
+        ResizeAlg::Min,
+        ResizeAlg::Max,
+    ];
+    for algorithm in algorithms {
+        for crop_mode in [CropMode::Surrounding, CropMode::Boundary] {
+            let mut src_view = src_image.view();
+            src_view.set_float_crop_box(crop_box).unwrap();
+            src_view.set_crop_mode(crop_mode);
+            let mut dst_image = Image::new(
+                NonZeroU32::new(3).unwrap(),
+                NonZeroU32::new(2).unwrap(),
+                PixelType::U8,
+            );
+            let mut resizer = Resizer::new(algorithm);
+            resizer
+                .resize(&src_view, &mut dst_image.view_mut())
+                .unwrap();
+            if crop_mode == CropMode::Boundary {
+                // Only one pixel of the source image is inside of the crop box.
+                assert!(
+                    dst_image.buffer().iter().all(|&v| v == pixel),
+                    "{:?}",
+                    algorithm
+                );
+            }
+        }
+    }
+}
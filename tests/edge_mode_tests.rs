@@ -0,0 +1,304 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{
+    CropBox, EdgeMode, FilterType, Image, PixelType, ResizeAlg, Resizer, SamplingGrid,
+};
+
+mod utils;
+
+/// Returns image extended by `pad` pixels from all sides
+/// according to the given edge mode.
+fn extend_image(
+    buffer: &[u8],
+    pixel_size: usize,
+    (width, height): (u32, u32),
+    pad: u32,
+    edge_mode: EdgeMode,
+) -> Vec<u8> {
+    let map = |x: i64, size: i64| -> Option<i64> {
+        match edge_mode {
+            EdgeMode::Clamp => Some(x.clamp(0, size - 1)),
+            EdgeMode::Mirror => {
+                let x = x.rem_euclid(2 * size);
+                Some(if x < size { x } else { 2 * size - 1 - x })
+            }
+            EdgeMode::Wrap => Some(x.rem_euclid(size)),
+            _ => (0..size).contains(&x).then_some(x),
+        }
+    };
+    let pad = pad as i64;
+    let (width, height) = (width as i64, height as i64);
+    let mut result = vec![];
+    for y in -pad..height + pad {
+        for x in -pad..width + pad {
+            match (map(x, width), map(y, height)) {
+                (Some(x), Some(y)) => {
+                    let i = (y * width + x) as usize * pixel_size;
+                    result.extend_from_slice(&buffer[i..i + pixel_size]);
+                }
+                _ => result.resize(result.len() + pixel_size, 0),
+            }
+        }
+    }
+    result
+}
+
+#[test]
+fn edge_modes() {
+    let (width, height, pad) = (23, 17, 12);
+    let buffer: Vec<u8> = (0..width * height * 4)
+        .map(|i| (i * 7919 % 251) as u8)
+        .collect();
+    let src_image = Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        buffer.clone(),
+        PixelType::U8x4,
+    )
+    .unwrap();
+    let cpu_extensions_vec = utils::cpu_extensions_vec();
+    let edge_modes = [
+        EdgeMode::Clamp,
+        EdgeMode::Mirror,
+        EdgeMode::Wrap,
+        EdgeMode::Transparent,
+    ];
+    for edge_mode in edge_modes {
+        // Resizing of the image with given edge mode must be equal to
+        // resizing of the crop box of the extended image.
+        let extended_image = Image::from_vec_u8(
+            NonZeroU32::new(width + 2 * pad).unwrap(),
+            NonZeroU32::new(height + 2 * pad).unwrap(),
+            extend_image(&buffer, 4, (width, height), pad, edge_mode),
+            PixelType::U8x4,
+        )
+        .unwrap();
+        let mut extended_view = extended_image.view();
+        extended_view
+            .set_crop_box(CropBox {
+                left: pad,
+                top: pad,
+                width: src_image.width(),
+                height: src_image.height(),
+            })
+            .unwrap();
+
+        for cpu_extensions in cpu_extensions_vec.iter().copied() {
+            for (dst_width, dst_height) in [(11, 31), (7, 5), (40, 17), (23, 3)] {
+                let dst_width = NonZeroU32::new(dst_width).unwrap();
+                let dst_height = NonZeroU32::new(dst_height).unwrap();
+                let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+                unsafe {
+                    resizer.set_cpu_extensions(cpu_extensions);
+                }
+
+                let mut expected = Image::new(dst_width, dst_height, PixelType::U8x4);
+                resizer
+                    .resize(&extended_view, &mut expected.view_mut())
+                    .unwrap();
+
+                resizer.edge_mode = edge_mode;
+                let mut result = Image::new(dst_width, dst_height, PixelType::U8x4);
+                resizer
+                    .resize(&src_image.view(), &mut result.view_mut())
+                    .unwrap();
+
+                // Normalized integer coefficients may have different precision
+                let max_diff = result
+                    .buffer()
+                    .iter()
+                    .zip(expected.buffer())
+                    .map(|(&a, &b)| (a as i32 - b as i32).abs())
+                    .max()
+                    .unwrap();
+                assert!(
+                    max_diff <= 1,
+                    "{:?} {:?} {}x{}",
+                    edge_mode,
+                    cpu_extensions,
+                    dst_width,
+                    dst_height
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn edge_modes_with_tiny_image() {
+    // Support of filters is larger than the size of the image,
+    // but taps outside of the image are valid mapped pixels.
+    let pad = 12;
+    let cpu_extensions_vec = utils::cpu_extensions_vec();
+    let filter_types = [FilterType::Lanczos3, FilterType::Gaussian(1.5)];
+    for (width, height) in [(1, 2), (2, 1), (2, 2)] {
+        let buffer: Vec<u8> = (0..width * height * 4)
+            .map(|i| (i * 7919 % 251) as u8)
+            .collect();
+        let src_image = Image::from_vec_u8(
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+            buffer.clone(),
+            PixelType::U8x4,
+        )
+        .unwrap();
+        for edge_mode in [EdgeMode::Mirror, EdgeMode::Wrap] {
+            let extended_image = Image::from_vec_u8(
+                NonZeroU32::new(width + 2 * pad).unwrap(),
+                NonZeroU32::new(height + 2 * pad).unwrap(),
+                extend_image(&buffer, 4, (width, height), pad, edge_mode),
+                PixelType::U8x4,
+            )
+            .unwrap();
+            let mut extended_view = extended_image.view();
+            extended_view
+                .set_crop_box(CropBox {
+                    left: pad,
+                    top: pad,
+                    width: src_image.width(),
+                    height: src_image.height(),
+                })
+                .unwrap();
+
+            for filter_type in filter_types.iter() {
+                for cpu_extensions in cpu_extensions_vec.iter().copied() {
+                    let dst_width = NonZeroU32::new(5).unwrap();
+                    let dst_height = NonZeroU32::new(7).unwrap();
+                    let mut resizer = Resizer::new(ResizeAlg::Convolution(*filter_type));
+                    unsafe {
+                        resizer.set_cpu_extensions(cpu_extensions);
+                    }
+
+                    let mut expected = Image::new(dst_width, dst_height, PixelType::U8x4);
+                    resizer
+                        .resize(&extended_view, &mut expected.view_mut())
+                        .unwrap();
+
+                    resizer.edge_mode = edge_mode;
+                    let mut result = Image::new(dst_width, dst_height, PixelType::U8x4);
+                    resizer
+                        .resize(&src_image.view(), &mut result.view_mut())
+                        .unwrap();
+
+                    // Normalized integer coefficients may have different precision
+                    let max_diff = result
+                        .buffer()
+                        .iter()
+                        .zip(expected.buffer())
+                        .map(|(&a, &b)| (a as i32 - b as i32).abs())
+                        .max()
+                        .unwrap();
+                    assert!(
+                        max_diff <= 1,
+                        "{:?} {:?} {:?} {}x{}",
+                        filter_type,
+                        edge_mode,
+                        cpu_extensions,
+                        width,
+                        height
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn wrap_edge_mode_with_upscale() {
+    // Filter reaches further than a half of its window outside of the image
+    // if the image is upscaled more than twice.
+    let (width, height, pad) = (8, 6, 12);
+    let cpu_extensions_vec = utils::cpu_extensions_vec();
+    let sampling_grids = [
+        SamplingGrid::HalfPixel,
+        SamplingGrid::AlignCorners,
+        SamplingGrid::Asymmetric,
+    ];
+    for (pixel_type, pixel_size) in [(PixelType::U16, 2), (PixelType::U8x4, 4)] {
+        let buffer: Vec<u8> = (0..width * height * pixel_size)
+            .map(|i| (i * 7919 % 251) as u8)
+            .collect();
+        let src_image = Image::from_vec_u8(
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+            buffer.clone(),
+            pixel_type,
+        )
+        .unwrap();
+        let extended_image = Image::from_vec_u8(
+            NonZeroU32::new(width + 2 * pad).unwrap(),
+            NonZeroU32::new(height + 2 * pad).unwrap(),
+            extend_image(
+                &buffer,
+                pixel_size as usize,
+                (width, height),
+                pad,
+                EdgeMode::Wrap,
+            ),
+            pixel_type,
+        )
+        .unwrap();
+        let mut extended_view = extended_image.view();
+        extended_view
+            .set_crop_box(CropBox {
+                left: pad,
+                top: pad,
+                width: src_image.width(),
+                height: src_image.height(),
+            })
+            .unwrap();
+
+        for sampling_grid in sampling_grids {
+            for cpu_extensions in cpu_extensions_vec.iter().copied() {
+                for (dst_width, dst_height) in [(43, 14), (17, 29)] {
+                    let dst_width = NonZeroU32::new(dst_width).unwrap();
+                    let dst_height = NonZeroU32::new(dst_height).unwrap();
+                    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+                    resizer.sampling_grid = sampling_grid;
+                    unsafe {
+                        resizer.set_cpu_extensions(cpu_extensions);
+                    }
+
+                    let mut expected = Image::new(dst_width, dst_height, pixel_type);
+                    resizer
+                        .resize(&extended_view, &mut expected.view_mut())
+                        .unwrap();
+
+                    resizer.edge_mode = EdgeMode::Wrap;
+                    let mut result = Image::new(dst_width, dst_height, pixel_type);
+                    resizer
+                        .resize(&src_image.view(), &mut result.view_mut())
+                        .unwrap();
+
+                    assert!(
+                        result.buffer() == expected.buffer(),
+                        "{:?} {:?} {:?} {}x{}",
+                        pixel_type,
+                        sampling_grid,
+                        cpu_extensions,
+                        dst_width,
+                        dst_height
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn edge_modes_keep_uniform_image() {
+    let size = NonZeroU32::new(20).unwrap();
+    let src_image = Image::from_vec_u8(size, size, vec![100; 20 * 20], PixelType::U8).unwrap();
+    for edge_mode in [EdgeMode::Clamp, EdgeMode::Mirror, EdgeMode::Wrap] {
+        for dst_size in [7, 53] {
+            let dst_size = NonZeroU32::new(dst_size).unwrap();
+            let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+            resizer.edge_mode = edge_mode;
+            let mut dst_image = Image::new(dst_size, dst_size, PixelType::U8);
+            resizer
+                .resize(&src_image.view(), &mut dst_image.view_mut())
+                .unwrap();
+            assert!(dst_image.buffer().iter().all(|&v| v == 100));
+        }
+    }
+}
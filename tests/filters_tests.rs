@@ -0,0 +1,308 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{
+    EdgeMode, Filter, FilterType, Image, PixelType, ResizeAlg, ResizePlan, Resizer,
+};
+
+mod utils;
+
+#[test]
+fn all_filters_keep_uniform_image() {
+    let filter_types = [
+        FilterType::Box,
+        FilterType::Bilinear,
+        FilterType::Hamming,
+        FilterType::CatmullRom,
+        FilterType::Mitchell,
+        FilterType::Lanczos3,
+        FilterType::Lanczos2,
+        FilterType::Lanczos4,
+        FilterType::Gaussian(0.5),
+        FilterType::Gaussian(1.5),
+        FilterType::Hermite,
+        FilterType::BSpline,
+        FilterType::Blackman,
+        FilterType::Kaiser,
+        FilterType::Cubic { b: 0., c: 0.75 },
+    ];
+    let src_size = NonZeroU32::new(50).unwrap();
+    let src_image =
+        Image::from_vec_u8(src_size, src_size, vec![100; 50 * 50], PixelType::U8).unwrap();
+    for filter_type in filter_types {
+        let mut resizer = Resizer::new(ResizeAlg::Convolution(filter_type));
+        for dst_size in [17, 113] {
+            let dst_size = NonZeroU32::new(dst_size).unwrap();
+            let mut dst_image = Image::new(dst_size, dst_size, PixelType::U8);
+            resizer
+                .resize(&src_image.view(), &mut dst_image.view_mut())
+                .unwrap();
+            assert!(
+                dst_image.buffer().iter().all(|&v| v == 100),
+                "{:?} {}",
+                filter_type,
+                dst_size
+            );
+        }
+    }
+}
+
+struct Triangle;
+
+impl Filter for Triangle {
+    fn value(&self, x: f64) -> f64 {
+        (1. - x.abs()).max(0.)
+    }
+
+    fn support(&self) -> f64 {
+        1.
+    }
+}
+
+#[test]
+fn custom_filter() {
+    let custom = FilterType::Custom(&Triangle);
+    assert_eq!(custom, custom.clone());
+    let cauchy = FilterType::Custom(&Cauchy(1.));
+    assert_ne!(custom, cauchy);
+    assert_ne!(cauchy, FilterType::Custom(Box::leak(Box::new(Cauchy(1.)))));
+
+    let width = NonZeroU32::new(37).unwrap();
+    let height = NonZeroU32::new(23).unwrap();
+    let pixels: Vec<u8> = (0..37 * 23 * 3).map(|i| (i * 7919 % 251) as u8).collect();
+    let src_image = Image::from_vec_u8(width, height, pixels, PixelType::U8x3).unwrap();
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        for (dst_width, dst_height) in [(15, 11), (70, 51)] {
+            let dst_width = NonZeroU32::new(dst_width).unwrap();
+            let dst_height = NonZeroU32::new(dst_height).unwrap();
+            let mut results = vec![];
+            for filter_type in [FilterType::Bilinear, custom] {
+                let mut resizer = Resizer::new(ResizeAlg::Convolution(filter_type));
+                unsafe {
+                    resizer.set_cpu_extensions(cpu_extensions);
+                }
+                let mut dst_image = Image::new(dst_width, dst_height, PixelType::U8x3);
+                resizer
+                    .resize(&src_image.view(), &mut dst_image.view_mut())
+                    .unwrap();
+                results.push(dst_image.buffer().to_vec());
+            }
+            assert!(results[0] == results[1], "{:?}", cpu_extensions);
+        }
+    }
+}
+
+/// Filter with heavy tails and configurable support.
+struct Cauchy(f64);
+
+impl Filter for Cauchy {
+    fn value(&self, x: f64) -> f64 {
+        1. / (1. + x * x)
+    }
+
+    fn support(&self) -> f64 {
+        self.0
+    }
+}
+
+#[test]
+fn custom_filter_with_huge_support() {
+    let width = NonZeroU32::new(19).unwrap();
+    let height = NonZeroU32::new(13).unwrap();
+    let pixels: Vec<u8> = (0..19 * 13).map(|i| (i * 7919 % 251) as u8).collect();
+    let src_image = Image::from_vec_u8(width, height, pixels, PixelType::U8).unwrap();
+    let dst_width = NonZeroU32::new(7).unwrap();
+    let dst_height = NonZeroU32::new(29).unwrap();
+
+    // Radius of the filter larger than the doubled size of the image
+    // is clamped by this size.
+    let mut results = vec![];
+    for support in [f64::INFINITY, f64::MAX, 1e300, 38.] {
+        let filter_type = FilterType::Custom(Box::leak(Box::new(Cauchy(support))));
+        let mut resizer = Resizer::new(ResizeAlg::Convolution(filter_type));
+        resizer.edge_mode = EdgeMode::Wrap;
+        let mut dst_image = Image::new(dst_width, dst_height, PixelType::U8);
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        results.push(dst_image.buffer().to_vec());
+    }
+    assert!(results.iter().all(|result| result == &results[0]));
+}
+
+#[test]
+fn gaussian_filter_with_huge_sigma() {
+    let width = NonZeroU32::new(19).unwrap();
+    let height = NonZeroU32::new(13).unwrap();
+    let pixels: Vec<u8> = (0..19 * 13).map(|i| (i * 7919 % 251) as u8).collect();
+    let src_image = Image::from_vec_u8(width, height, pixels, PixelType::U8).unwrap();
+    let dst_width = NonZeroU32::new(7).unwrap();
+    let dst_height = NonZeroU32::new(29).unwrap();
+
+    // Radius of the filter is clamped, so the filter is almost flat
+    // inside of the window and results are the same.
+    let mut results = vec![];
+    for sigma in [1e9, 1e300, f64::MAX] {
+        let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Gaussian(sigma)));
+        let mut dst_image = Image::new(dst_width, dst_height, PixelType::U8);
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        results.push(dst_image.buffer().to_vec());
+    }
+    assert!(results.iter().all(|result| result == &results[0]));
+}
+
+#[test]
+fn blur_factor() {
+    let src_size = NonZeroU32::new(64).unwrap();
+    let buffer: Vec<u8> = (0..64 * 64u32).map(|i| (i * 7919 % 251) as u8).collect();
+    let src_image = Image::from_vec_u8(src_size, src_size, buffer, PixelType::U8).unwrap();
+    let dst_size = NonZeroU32::new(16).unwrap();
+
+    let resize_with_blur = |blur: f64| {
+        let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Bilinear));
+        resizer.blur = blur;
+        let mut dst_image = Image::new(dst_size, dst_size, PixelType::U8);
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        dst_image.buffer().to_owned()
+    };
+    let variance = |buffer: &[u8]| {
+        let mean = buffer.iter().map(|&v| v as f64).sum::<f64>() / buffer.len() as f64;
+        buffer
+            .iter()
+            .map(|&v| (v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / buffer.len() as f64
+    };
+
+    let default_result = resize_with_blur(1.);
+    // Invalid values are replaced by 1.
+    assert_eq!(resize_with_blur(0.), default_result);
+    assert_eq!(resize_with_blur(f64::NAN), default_result);
+    assert_eq!(resize_with_blur(f64::INFINITY), default_result);
+    // Huge values are limited.
+    let max_blur_result = resize_with_blur(1000.);
+    assert_eq!(resize_with_blur(1e9), max_blur_result);
+    assert_eq!(resize_with_blur(f64::MAX), max_blur_result);
+    // Bigger blur factor gives more smooth result.
+    let sharp_variance = variance(&resize_with_blur(0.5));
+    let default_variance = variance(&default_result);
+    let soft_variance = variance(&resize_with_blur(2.));
+    assert!(sharp_variance > default_variance);
+    assert!(default_variance > soft_variance);
+
+    // Plan uses its own blur factor.
+    let plan = ResizePlan::new(
+        ResizeAlg::Convolution(FilterType::Bilinear),
+        PixelType::U8,
+        src_size,
+        src_size,
+        src_image.view().float_crop_box(),
+        dst_size,
+        dst_size,
+    )
+    .unwrap()
+    .with_blur(2.);
+    assert_eq!(plan.blur(), 2.);
+    let mut dst_image = Image::new(dst_size, dst_size, PixelType::U8);
+    Resizer::default()
+        .resize_with_plan(&plan, &src_image.view(), &mut dst_image.view_mut())
+        .unwrap();
+    assert_eq!(dst_image.buffer(), resize_with_blur(2.));
+}
+
+#[test]
+fn blur_factor_without_resizing() {
+    // Single bright pixel in the center of the image
+    let size = NonZeroU32::new(5).unwrap();
+    let mut buffer = vec![0u8; 5 * 5];
+    buffer[2 * 5 + 2] = 160;
+    let src_image = Image::from_vec_u8(size, size, buffer, PixelType::U8).unwrap();
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Bilinear));
+        resizer.blur = 2.;
+        unsafe {
+            resizer.set_cpu_extensions(cpu_extensions);
+        }
+        let mut dst_image = Image::new(size, size, PixelType::U8);
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        // Stretched filter has weights 0.25, 0.5 and 0.25
+        // in both directions.
+        #[rustfmt::skip]
+        let expected = [
+            0, 0, 0, 0, 0,
+            0, 10, 20, 10, 0,
+            0, 20, 40, 20, 0,
+            0, 10, 20, 10, 0,
+            0, 0, 0, 0, 0,
+        ];
+        assert_eq!(dst_image.buffer(), expected, "{:?}", cpu_extensions);
+    }
+}
+
+#[test]
+fn narrow_kernel_keeps_uniform_image() {
+    let src_size = NonZeroU32::new(10).unwrap();
+    let src_image =
+        Image::from_vec_u8(src_size, src_size, vec![100; 10 * 10], PixelType::U8).unwrap();
+    let dst_size = NonZeroU32::new(37).unwrap();
+    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Box));
+    resizer.blur = 0.3;
+    let mut dst_image = Image::new(dst_size, dst_size, PixelType::U8);
+    resizer
+        .resize(&src_image.view(), &mut dst_image.view_mut())
+        .unwrap();
+    assert!(dst_image.buffer().iter().all(|&v| v == 100));
+}
+
+#[test]
+fn separate_convolution() {
+    let src_size = NonZeroU32::new(64).unwrap();
+    let buffer: Vec<u8> = (0..64 * 64 * 3u32)
+        .map(|i| (i * 7919 % 251) as u8)
+        .collect();
+    let src_image = Image::from_vec_u8(src_size, src_size, buffer, PixelType::U8x3).unwrap();
+    let resize = |alg: ResizeAlg, dst_width: u32, dst_height: u32| {
+        let mut dst_image = Image::new(
+            NonZeroU32::new(dst_width).unwrap(),
+            NonZeroU32::new(dst_height).unwrap(),
+            PixelType::U8x3,
+        );
+        Resizer::new(alg)
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        dst_image.buffer().to_owned()
+    };
+    let separate = ResizeAlg::SeparateConvolution {
+        horizontal: FilterType::Lanczos3,
+        vertical: FilterType::Box,
+    };
+
+    // Only horizontal pass is used
+    assert_eq!(
+        resize(separate, 23, 64),
+        resize(ResizeAlg::Convolution(FilterType::Lanczos3), 23, 64)
+    );
+    // Only vertical pass is used
+    assert_eq!(
+        resize(separate, 64, 23),
+        resize(ResizeAlg::Convolution(FilterType::Box), 64, 23)
+    );
+    // Both passes are used
+    assert_ne!(
+        resize(separate, 23, 31),
+        resize(ResizeAlg::Convolution(FilterType::Lanczos3), 23, 31)
+    );
+    let same_filters = ResizeAlg::SeparateConvolution {
+        horizontal: FilterType::Mitchell,
+        vertical: FilterType::Mitchell,
+    };
+    assert_eq!(
+        resize(same_filters, 23, 31),
+        resize(ResizeAlg::Convolution(FilterType::Mitchell), 23, 31)
+    );
+}
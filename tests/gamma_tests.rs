@@ -0,0 +1,121 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::pixels::U8x4;
+use fast_image_resize::{
+    CropMode, EdgeMode, FilterType, FloatCropBox, Image, PixelType, ResizeAlg, Resizer,
+};
+use utils::PixelExt;
+
+mod utils;
+
+#[test]
+fn gamma_correction() {
+    // Black and white checkerboard 2x2 downscaled into one pixel
+    // must have 50% of light intensity.
+    let size = NonZeroU32::new(2).unwrap();
+    let one = NonZeroU32::new(1).unwrap();
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Box));
+        unsafe {
+            resizer.set_cpu_extensions(cpu_extensions);
+        }
+
+        let pixels = vec![0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0];
+        let src_image = Image::from_vec_u8(size, size, pixels, PixelType::U8x3).unwrap();
+        let mut dst_image = Image::new(one, one, PixelType::U8x3);
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        assert_eq!(dst_image.buffer(), [128, 128, 128]);
+
+        resizer.gamma_correction = true;
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        assert_eq!(dst_image.buffer(), [188, 188, 188]);
+
+        // Transparent pixels must not affect color of result.
+        let pixels = vec![
+            0, 0, 0, 0, 255, 255, 255, 255, //
+            255, 255, 255, 255, 0, 0, 0, 255,
+        ];
+        let src_image = Image::from_vec_u8(size, size, pixels, PixelType::U8x4).unwrap();
+        let mut dst_image = Image::new(one, one, PixelType::U8x4);
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        assert_eq!(dst_image.buffer(), [213, 213, 213, 191]);
+    }
+}
+
+#[test]
+fn gamma_correction_of_crop_box() {
+    // Only the region of the source image that is read by resizing is
+    // converted into linear color space, pixels outside of it in the
+    // buffer of resizer must not affect the result.
+    let size = |width: u32, height: u32| {
+        (
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+        )
+    };
+    let (src_width, src_height) = size(64, 48);
+    let src_image = U8x4::noise_image(src_width.get(), src_height.get());
+    let white_image = Image::from_vec_u8(
+        src_width,
+        src_height,
+        vec![255; 64 * 48 * 4],
+        PixelType::U8x4,
+    )
+    .unwrap();
+    let algorithms = [
+        ResizeAlg::Convolution(FilterType::Lanczos3),
+        ResizeAlg::SuperSampling(FilterType::Bilinear, 2),
+        ResizeAlg::Reduce(FilterType::Lanczos3, 2.),
+        ResizeAlg::Area,
+    ];
+    let edge_modes = [EdgeMode::Renormalize, EdgeMode::Mirror, EdgeMode::Wrap];
+    for algorithm in algorithms {
+        for edge_mode in edge_modes {
+            for crop_mode in [CropMode::Surrounding, CropMode::Boundary] {
+                let resize = |resizer: &mut Resizer, src_image: &Image, crop: bool| {
+                    let (dst_width, dst_height) = size(7, 5);
+                    let mut dst_image = Image::new(dst_width, dst_height, PixelType::U8x4);
+                    let mut src_view = src_image.view();
+                    if crop {
+                        src_view
+                            .set_float_crop_box(FloatCropBox {
+                                left: 21.5,
+                                top: 13.,
+                                width: 23.,
+                                height: 17.5,
+                            })
+                            .unwrap();
+                        src_view.set_crop_mode(crop_mode);
+                    }
+                    resizer
+                        .resize(&src_view, &mut dst_image.view_mut())
+                        .unwrap();
+                    dst_image.buffer().to_vec()
+                };
+                let new_resizer = || {
+                    let mut resizer = Resizer::new(algorithm);
+                    resizer.gamma_correction = true;
+                    resizer.edge_mode = edge_mode;
+                    resizer
+                };
+                let expected = resize(&mut new_resizer(), &src_image, true);
+                let mut resizer = new_resizer();
+                resize(&mut resizer, &white_image, false);
+                assert_eq!(
+                    resize(&mut resizer, &src_image, true),
+                    expected,
+                    "{:?} {:?} {:?}",
+                    algorithm,
+                    edge_mode,
+                    crop_mode
+                );
+            }
+        }
+    }
+}
@@ -52,3 +52,111 @@ fn create_image_view_from_big_buffer() {
     let res = fr::ImageView::from_buffer(width, height, &buffer, fr::PixelType::U8);
     assert!(res.is_ok());
 }
+
+#[test]
+fn float_crop_box() {
+    let width = NonZeroU32::new(8).unwrap();
+    let height = NonZeroU32::new(4).unwrap();
+    let buffer = vec![0; 8 * 4];
+    let mut view = fr::ImageView::from_buffer(width, height, &buffer, fr::PixelType::U8).unwrap();
+
+    let crop_box = fr::FloatCropBox {
+        left: 1.5,
+        top: 0.25,
+        width: 5.,
+        height: 2.5,
+    };
+    view.set_float_crop_box(crop_box).unwrap();
+    assert_eq!(view.float_crop_box(), crop_box);
+    // The smallest integer crop box that contains the fractional one
+    let int_crop_box = view.crop_box();
+    assert_eq!((int_crop_box.left, int_crop_box.top), (1, 0),);
+    assert_eq!(
+        (int_crop_box.width.get(), int_crop_box.height.get()),
+        (6, 3),
+    );
+
+    let errors = [
+        (
+            -0.5,
+            0.,
+            1.,
+            1.,
+            fr::CropBoxError::PositionIsOutOfImageBoundaries,
+        ),
+        (
+            8.,
+            0.,
+            1.,
+            1.,
+            fr::CropBoxError::PositionIsOutOfImageBoundaries,
+        ),
+        (
+            f64::NAN,
+            0.,
+            1.,
+            1.,
+            fr::CropBoxError::PositionIsOutOfImageBoundaries,
+        ),
+        (
+            0.,
+            0.,
+            0.,
+            1.,
+            fr::CropBoxError::WidthOrHeightLessOrEqualToZero,
+        ),
+        (
+            0.,
+            0.,
+            1.,
+            -1.,
+            fr::CropBoxError::WidthOrHeightLessOrEqualToZero,
+        ),
+        (
+            7.5,
+            0.,
+            1.,
+            1.,
+            fr::CropBoxError::SizeIsOutOfImageBoundaries,
+        ),
+        (
+            0.,
+            3.5,
+            1.,
+            0.6,
+            fr::CropBoxError::SizeIsOutOfImageBoundaries,
+        ),
+    ];
+    for (left, top, width, height, error) in errors {
+        let crop_box = fr::FloatCropBox {
+            left,
+            top,
+            width,
+            height,
+        };
+        assert_eq!(view.set_float_crop_box(crop_box), Err(error));
+    }
+}
+
+#[test]
+fn tiny_float_crop_box() {
+    let width = NonZeroU32::new(8).unwrap();
+    let height = NonZeroU32::new(4).unwrap();
+    let buffer = vec![0; 8 * 4];
+    let mut view = fr::ImageView::from_buffer(width, height, &buffer, fr::PixelType::U8).unwrap();
+
+    // Size of the crop box is lost by rounding of its right and bottom edges.
+    let crop_box = fr::FloatCropBox {
+        left: 3.,
+        top: 1.,
+        width: 1e-20,
+        height: 1e-20,
+    };
+    view.set_float_crop_box(crop_box).unwrap();
+    let int_crop_box = view.crop_box();
+    assert_eq!((int_crop_box.left, int_crop_box.top), (3, 1));
+    assert_eq!(
+        (int_crop_box.width.get(), int_crop_box.height.get()),
+        (1, 1),
+    );
+}
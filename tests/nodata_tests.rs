@@ -0,0 +1,177 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{FilterType, Image, PixelType, ResizeAlg, Resizer};
+
+#[test]
+fn nodata() {
+    let size = |width: u32, height: u32| {
+        (
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+        )
+    };
+    let resize = |src_image: &Image, (width, height), alg: ResizeAlg, nodata: Option<f64>| {
+        let mut dst_image = Image::new(width, height, src_image.pixel_type());
+        let mut resizer = Resizer::new(alg);
+        resizer.nodata = nodata;
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        dst_image.buffer().to_owned()
+    };
+    let to_f32 = |buffer: Vec<u8>| -> Vec<f32> {
+        buffer
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+            .collect()
+    };
+    let to_i32 = |buffer: Vec<u8>| -> Vec<i32> {
+        buffer
+            .chunks_exact(4)
+            .map(|b| i32::from_le_bytes(b.try_into().unwrap()))
+            .collect()
+    };
+
+    // Left half of the image is missing.
+    let (width, height) = size(8, 8);
+    let buffer: Vec<u8> = (0..64)
+        .flat_map(|i| if i % 8 < 4 { f32::NAN } else { 5. }.to_le_bytes())
+        .collect();
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::F32).unwrap();
+    let lanczos = ResizeAlg::Convolution(FilterType::Lanczos3);
+    let result = to_f32(resize(&src_image, size(4, 4), lanczos, None));
+    assert!(result.iter().all(|v| v.is_nan()));
+    // Weights of valid samples are renormalized only if their sum
+    // is not too small relative to absolute weights of the filter.
+    let result = to_f32(resize(&src_image, size(4, 4), lanczos, Some(f64::NAN)));
+    for row in result.chunks_exact(4) {
+        assert!(row[..2].iter().all(|v| v.is_nan()), "{:?}", row);
+        assert!(row[2..].iter().all(|&v| (v - 5.).abs() < 1e-5), "{:?}", row);
+    }
+    // Pixels without valid samples are set to the nodata value.
+    let result = to_f32(resize(
+        &src_image,
+        size(4, 4),
+        ResizeAlg::Convolution(FilterType::Bilinear),
+        Some(f64::NAN),
+    ));
+    for row in result.chunks_exact(4) {
+        assert!(row[0].is_nan());
+        assert_eq!(&row[1..], &[5., 5., 5.]);
+    }
+
+    // Negative lobes of the filter near the hole don't amplify
+    // values of valid samples.
+    let (width, height) = size(40, 40);
+    let buffer: Vec<u8> = (0..40 * 40)
+        .flat_map(|i| {
+            let (x, y) = (i % 40, i / 40);
+            let value = if (13..24).contains(&x) && (12..21).contains(&y) {
+                -9999.
+            } else {
+                (i * 7919 % 101) as f32
+            };
+            value.to_le_bytes()
+        })
+        .collect();
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::F32).unwrap();
+    for (dst_width, dst_height) in [(13, 17), (23, 31), (97, 83)] {
+        let result = to_f32(resize(
+            &src_image,
+            size(dst_width, dst_height),
+            ResizeAlg::Convolution(FilterType::Lanczos3),
+            Some(-9999.),
+        ));
+        assert!(result.contains(&-9999.));
+        let valid = result.iter().filter(|&&v| v != -9999.);
+        assert!(
+            valid.clone().all(|&v| (-100. ..=200.).contains(&v)),
+            "{}x{}: {:?}",
+            dst_width,
+            dst_height,
+            valid.fold((f32::MAX, f32::MIN), |(min, max), &v| (
+                min.min(v),
+                max.max(v)
+            ))
+        );
+    }
+
+    // Weights are renormalized by valid samples of the whole
+    // two-dimensional window.
+    let (width, height) = size(2, 2);
+    let buffer: Vec<u8> = [1, -9999, 3, 5]
+        .iter()
+        .flat_map(|v: &i32| v.to_le_bytes())
+        .collect();
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::I32).unwrap();
+    for alg in [
+        ResizeAlg::Convolution(FilterType::Box),
+        ResizeAlg::Area,
+        ResizeAlg::Reduce(FilterType::Lanczos3, 1.),
+        ResizeAlg::SuperSampling(FilterType::Box, 2),
+    ] {
+        let result = to_i32(resize(&src_image, size(1, 1), alg, Some(-9999.)));
+        assert_eq!(result, [3], "{:?}", alg);
+    }
+    let result = to_i32(resize(
+        &src_image,
+        size(1, 1),
+        ResizeAlg::Convolution(FilterType::Box),
+        None,
+    ));
+    assert_ne!(result, [3]);
+
+    // Zero is the nodata value of U8 image reduced by integer factors.
+    let (width, height) = size(6, 2);
+    let buffer = vec![0, 0, 10, 0, 7, 8, 0, 0, 0, 20, 9, 10];
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::U8).unwrap();
+    for alg in [
+        ResizeAlg::Convolution(FilterType::Box),
+        ResizeAlg::Reduce(FilterType::Lanczos3, 1.),
+    ] {
+        let result = resize(&src_image, size(3, 1), alg, Some(0.));
+        assert_eq!(result, [0, 15, 9]);
+    }
+}
+
+#[test]
+fn nodata_weight_threshold() {
+    let resize = |src_width: u32, valid_count: usize, alg: ResizeAlg| -> f32 {
+        let buffer: Vec<u8> = (0..src_width as usize)
+            .flat_map(|x| if x < valid_count { 7. } else { f32::NAN }.to_le_bytes())
+            .collect();
+        let src_image = Image::from_vec_u8(
+            NonZeroU32::new(src_width).unwrap(),
+            NonZeroU32::new(1).unwrap(),
+            buffer,
+            PixelType::F32,
+        )
+        .unwrap();
+        let one = NonZeroU32::new(1).unwrap();
+        let mut dst_image = Image::new(one, one, PixelType::F32);
+        let mut resizer = Resizer::new(alg);
+        resizer.nodata = Some(f64::NAN);
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        f32::from_le_bytes(dst_image.buffer().try_into().unwrap())
+    };
+
+    for alg in [
+        ResizeAlg::Convolution(FilterType::Box),
+        ResizeAlg::Area,
+        ResizeAlg::Reduce(FilterType::Lanczos3, 1.),
+    ] {
+        // Valid weight is exactly 10% of the window.
+        for src_width in (10..=100).step_by(10) {
+            let valid_count = src_width as usize / 10;
+            let result = resize(src_width, valid_count, alg);
+            assert_eq!(result, 7., "{:?}, {}/{}", alg, valid_count, src_width);
+        }
+        // Valid weight is less than 10% of the window.
+        for (src_width, valid_count) in [(11, 1), (21, 2), (101, 10)] {
+            let result = resize(src_width, valid_count, alg);
+            assert!(result.is_nan(), "{:?}, {}/{}", alg, valid_count, src_width);
+        }
+    }
+}
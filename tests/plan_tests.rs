@@ -0,0 +1,176 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{
+    CropBox, CropBoxError, CropMode, FilterType, Image, PixelType, ResizeAlg, ResizePlan,
+    ResizePlanError, Resizer,
+};
+
+fn new_src_image(width: u32, height: u32) -> Image<'static> {
+    let buffer: Vec<u8> = (0..width * height * 3)
+        .map(|i| (i * 7919 % 251) as u8)
+        .collect();
+    Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        buffer,
+        PixelType::U8x3,
+    )
+    .unwrap()
+}
+
+fn crop_box(left: u32, top: u32, width: u32, height: u32) -> CropBox {
+    CropBox {
+        left,
+        top,
+        width: NonZeroU32::new(width).unwrap(),
+        height: NonZeroU32::new(height).unwrap(),
+    }
+}
+
+#[test]
+fn plan_result_is_equal_to_resize_result() {
+    let src_image = new_src_image(211, 157);
+    let algorithms = [
+        ResizeAlg::Nearest,
+        ResizeAlg::Convolution(FilterType::Lanczos3),
+        ResizeAlg::SuperSampling(FilterType::Bilinear, 2),
+        ResizeAlg::Reduce(FilterType::Lanczos3, 2.),
+        ResizeAlg::Area,
+        ResizeAlg::Mode,
+    ];
+    for algorithm in algorithms {
+        for crop in [crop_box(0, 0, 211, 157), crop_box(10, 20, 150, 100)] {
+            for (dst_width, dst_height) in [(47, 31), (300, 401), (211, 31)] {
+                let dst_width = NonZeroU32::new(dst_width).unwrap();
+                let dst_height = NonZeroU32::new(dst_height).unwrap();
+                let mut src_view = src_image.view();
+                src_view.set_crop_box(crop).unwrap();
+
+                let mut expected = Image::new(dst_width, dst_height, PixelType::U8x3);
+                let mut resizer = Resizer::new(algorithm);
+                resizer.resize(&src_view, &mut expected.view_mut()).unwrap();
+
+                let plan = ResizePlan::new(
+                    algorithm,
+                    PixelType::U8x3,
+                    src_image.width(),
+                    src_image.height(),
+                    crop.into(),
+                    dst_width,
+                    dst_height,
+                )
+                .unwrap();
+                // The resizer's own algorithm is ignored.
+                let mut resizer = Resizer::new(ResizeAlg::Nearest);
+                // Execute the same plan several times.
+                for _ in 0..2 {
+                    let mut dst_image = Image::new(dst_width, dst_height, PixelType::U8x3);
+                    resizer
+                        .resize_with_plan(&plan, &src_view, &mut dst_image.view_mut())
+                        .unwrap();
+                    assert!(
+                        dst_image.buffer() == expected.buffer(),
+                        "{:?} {:?} {}x{}",
+                        algorithm,
+                        crop,
+                        dst_width,
+                        dst_height
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn plan_can_be_shared_between_threads() {
+    let src_image = new_src_image(64, 48);
+    let size = NonZeroU32::new(20).unwrap();
+    let plan = ResizePlan::new(
+        ResizeAlg::Convolution(FilterType::CatmullRom),
+        PixelType::U8x3,
+        src_image.width(),
+        src_image.height(),
+        src_image.view().float_crop_box(),
+        size,
+        size,
+    )
+    .unwrap();
+
+    let mut expected = Image::new(size, size, PixelType::U8x3);
+    Resizer::new(ResizeAlg::Convolution(FilterType::CatmullRom))
+        .resize(&src_image.view(), &mut expected.view_mut())
+        .unwrap();
+
+    std::thread::scope(|s| {
+        for _ in 0..3 {
+            s.spawn(|| {
+                let mut dst_image = Image::new(size, size, PixelType::U8x3);
+                Resizer::default()
+                    .resize_with_plan(&plan, &src_image.view(), &mut dst_image.view_mut())
+                    .unwrap();
+                assert_eq!(dst_image.buffer(), expected.buffer());
+            });
+        }
+    });
+}
+
+#[test]
+fn plan_errors() {
+    let src_image = new_src_image(64, 48);
+    let size = NonZeroU32::new(20).unwrap();
+    let new_plan = |crop: CropBox| {
+        ResizePlan::new(
+            ResizeAlg::default(),
+            PixelType::U8x3,
+            src_image.width(),
+            src_image.height(),
+            crop.into(),
+            size,
+            size,
+        )
+    };
+    assert_eq!(
+        new_plan(crop_box(64, 0, 10, 10)).unwrap_err(),
+        CropBoxError::PositionIsOutOfImageBoundaries
+    );
+    assert_eq!(
+        new_plan(crop_box(60, 0, 10, 10)).unwrap_err(),
+        CropBoxError::SizeIsOutOfImageBoundaries
+    );
+
+    let plan = new_plan(crop_box(0, 0, 64, 48)).unwrap();
+    let mut resizer = Resizer::default();
+
+    let mut dst_image = Image::new(size, size, PixelType::U8x4);
+    assert_eq!(
+        resizer.resize_with_plan(&plan, &src_image.view(), &mut dst_image.view_mut()),
+        Err(ResizePlanError::PixelTypeIsDifferent)
+    );
+
+    let mut dst_image = Image::new(size, size, PixelType::U8x3);
+    let mut src_view = src_image.view();
+    src_view.set_crop_box(crop_box(1, 0, 63, 48)).unwrap();
+    assert_eq!(
+        resizer.resize_with_plan(&plan, &src_view, &mut dst_image.view_mut()),
+        Err(ResizePlanError::SrcImageIsDifferent)
+    );
+
+    let mut src_view = src_image.view();
+    src_view.set_crop_mode(CropMode::Boundary);
+    assert_eq!(
+        resizer.resize_with_plan(&plan, &src_view, &mut dst_image.view_mut()),
+        Err(ResizePlanError::SrcImageIsDifferent)
+    );
+    let boundary_plan = plan.clone().with_crop_mode(CropMode::Boundary);
+    assert_eq!(
+        resizer.resize_with_plan(&boundary_plan, &src_view, &mut dst_image.view_mut()),
+        Ok(())
+    );
+
+    let mut dst_image = Image::new(size, src_image.height(), PixelType::U8x3);
+    assert_eq!(
+        resizer.resize_with_plan(&plan, &src_image.view(), &mut dst_image.view_mut()),
+        Err(ResizePlanError::DstImageIsDifferent)
+    );
+}
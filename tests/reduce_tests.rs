@@ -0,0 +1,212 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{CropBox, FilterType, Image, PixelType, ResizeAlg, Resizer};
+
+mod utils;
+
+#[test]
+fn reduce() {
+    let src_size = NonZeroU32::new(64).unwrap();
+    // Vertical stripes with width of one pixel
+    let stripes: Vec<u8> = (0..64 * 64 * 3u32)
+        .map(|i| if (i / 3) % 2 == 0 { 0 } else { 255 })
+        .collect();
+    let noise: Vec<u8> = (0..64 * 64 * 3u32)
+        .map(|i| (i * 7919 % 251) as u8)
+        .collect();
+    let resize = |buffer: &[u8], alg: ResizeAlg, crop: CropBox, dst_size: (u32, u32), cpu| {
+        let src_image =
+            Image::from_vec_u8(src_size, src_size, buffer.to_vec(), PixelType::U8x3).unwrap();
+        let mut src_view = src_image.view();
+        src_view.set_crop_box(crop).unwrap();
+        let mut dst_image = Image::new(
+            NonZeroU32::new(dst_size.0).unwrap(),
+            NonZeroU32::new(dst_size.1).unwrap(),
+            PixelType::U8x3,
+        );
+        let mut resizer = Resizer::new(alg);
+        unsafe {
+            resizer.set_cpu_extensions(cpu);
+        }
+        resizer
+            .resize(&src_view, &mut dst_image.view_mut())
+            .unwrap();
+        dst_image.buffer().to_owned()
+    };
+    let full = CropBox {
+        left: 0,
+        top: 0,
+        width: src_size,
+        height: src_size,
+    };
+    let crop = CropBox {
+        left: 8,
+        top: 4,
+        width: NonZeroU32::new(48).unwrap(),
+        height: NonZeroU32::new(40).unwrap(),
+    };
+
+    for cpu in utils::cpu_extensions_vec() {
+        // Nearest-neighbor prestep of super sampling skips odd columns.
+        let result = resize(
+            &stripes,
+            ResizeAlg::SuperSampling(FilterType::Bilinear, 2),
+            full,
+            (8, 8),
+            cpu,
+        );
+        assert!(result.iter().all(|&v| v == 0), "{:?}", cpu);
+        let result = resize(
+            &stripes,
+            ResizeAlg::Reduce(FilterType::Bilinear, 2.),
+            full,
+            (8, 8),
+            cpu,
+        );
+        assert!(result.iter().all(|&v| v.abs_diff(128) <= 1), "{:?}", cpu);
+
+        // Reducing and box filter give the same averages of blocks of pixels.
+        for (crop, dst_size) in [(full, (16, 8)), (crop, (12, 10)), (crop, (24, 5))] {
+            let expected = resize(
+                &noise,
+                ResizeAlg::Convolution(FilterType::Box),
+                crop,
+                dst_size,
+                cpu,
+            );
+            let result = resize(
+                &noise,
+                ResizeAlg::Reduce(FilterType::Box, 2.),
+                crop,
+                dst_size,
+                cpu,
+            );
+            assert!(
+                result
+                    .iter()
+                    .zip(&expected)
+                    .all(|(&r, &e)| r.abs_diff(e) <= 1),
+                "{:?} {:?} {:?}",
+                cpu,
+                crop,
+                dst_size
+            );
+        }
+
+        // Image is not reduced if the gap is too big.
+        for crop in [full, crop] {
+            assert_eq!(
+                resize(
+                    &noise,
+                    ResizeAlg::Reduce(FilterType::Lanczos3, 8.),
+                    crop,
+                    (13, 11),
+                    cpu
+                ),
+                resize(
+                    &noise,
+                    ResizeAlg::Convolution(FilterType::Lanczos3),
+                    crop,
+                    (13, 11),
+                    cpu
+                )
+            );
+        }
+    }
+}
+
+#[test]
+fn integer_factor_reduce() {
+    // Pixel type, count of components and size of component in bytes
+    let pixel_types = [
+        (PixelType::U8, 1, 1),
+        (PixelType::U8x3, 3, 1),
+        (PixelType::U8x4, 4, 1),
+        (PixelType::U16x3, 3, 2),
+    ];
+    let (width, height) = (97, 61);
+    // Crop box and factors of reducing
+    let cases = [
+        ((0, 0, 96, 60), (2, 2)),
+        ((1, 1, 78, 60), (3, 4)),
+        ((5, 0, 90, 61), (5, 1)),
+        ((0, 3, 97, 56), (1, 8)),
+    ];
+    let cpu_extensions_vec = utils::cpu_extensions_vec();
+
+    for (pixel_type, components_count, component_size) in pixel_types {
+        let max_value = if component_size == 1 { 255 } else { 65535 };
+        let components: Vec<u32> = (0..width * height * components_count)
+            .map(|i| (i * 7919) % (max_value + 1))
+            .collect();
+        let to_bytes = |values: &[u32]| -> Vec<u8> {
+            values
+                .iter()
+                .flat_map(|v| v.to_le_bytes()[..component_size].to_vec())
+                .collect()
+        };
+        let src_image = Image::from_vec_u8(
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+            to_bytes(&components),
+            pixel_type,
+        )
+        .unwrap();
+
+        for ((left, top, crop_width, crop_height), (factor_x, factor_y)) in cases {
+            let (dst_width, dst_height) = (crop_width / factor_x, crop_height / factor_y);
+            // Rounded averages of blocks of pixels
+            let mut expected = Vec::new();
+            for y in 0..dst_height {
+                for x in 0..dst_width {
+                    for c in 0..components_count {
+                        let mut sum = 0;
+                        for block_y in top + y * factor_y..top + (y + 1) * factor_y {
+                            for block_x in left + x * factor_x..left + (x + 1) * factor_x {
+                                let i = (block_y * width + block_x) * components_count + c;
+                                sum += components[i as usize];
+                            }
+                        }
+                        let count = factor_x * factor_y;
+                        expected.push((sum + count / 2) / count);
+                    }
+                }
+            }
+            let expected = to_bytes(&expected);
+
+            let mut src_view = src_image.view();
+            src_view
+                .set_crop_box(CropBox {
+                    left,
+                    top,
+                    width: NonZeroU32::new(crop_width).unwrap(),
+                    height: NonZeroU32::new(crop_height).unwrap(),
+                })
+                .unwrap();
+            let algorithm = ResizeAlg::Reduce(FilterType::Lanczos3, 1.);
+            for &cpu_extensions in cpu_extensions_vec.iter() {
+                let mut dst_image = Image::new(
+                    NonZeroU32::new(dst_width).unwrap(),
+                    NonZeroU32::new(dst_height).unwrap(),
+                    pixel_type,
+                );
+                let mut resizer = Resizer::new(algorithm);
+                unsafe {
+                    resizer.set_cpu_extensions(cpu_extensions);
+                }
+                resizer
+                    .resize(&src_view, &mut dst_image.view_mut())
+                    .unwrap();
+                assert!(
+                    dst_image.buffer() == expected,
+                    "{:?} {:?} {:?} {}x{}",
+                    pixel_type,
+                    algorithm,
+                    cpu_extensions,
+                    factor_x,
+                    factor_y
+                );
+            }
+        }
+    }
+}
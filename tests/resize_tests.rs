@@ -176,6 +176,25 @@ fn upscale_u8() {
     }
 }
 
+#[test]
+fn upscale_u8x2() {
+    type P = U8x2;
+    let buffer = upscale_test::<P>(ResizeAlg::Nearest, CpuExtensions::None);
+    assert_eq!(
+        utils::image_checksum::<2>(&buffer),
+        [1148754010, 4269569040]
+    );
+
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        let buffer =
+            upscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
+        assert_eq!(
+            utils::image_checksum::<2>(&buffer),
+            [1148811406, 4269569040]
+        );
+    }
+}
+
 #[test]
 fn downscale_u8x3() {
     type P = U8x3;
@@ -185,13 +204,7 @@ fn downscale_u8x3() {
         [2937940, 2945380, 2882679]
     );
 
-    let mut cpu_extensions_vec = vec![CpuExtensions::None];
-    #[cfg(target_arch = "x86_64")]
-    {
-        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
-        cpu_extensions_vec.push(CpuExtensions::Avx2);
-    }
-    for cpu_extensions in cpu_extensions_vec {
+    for cpu_extensions in utils::cpu_extensions_vec() {
         let buffer =
             downscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
         assert_eq!(
@@ -210,13 +223,7 @@ fn upscale_u8x3() {
         [1156008260, 1158417906, 1135087540]
     );
 
-    let mut cpu_extensions_vec = vec![CpuExtensions::None];
-    #[cfg(target_arch = "x86_64")]
-    {
-        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
-        cpu_extensions_vec.push(CpuExtensions::Avx2);
-    }
-    for cpu_extensions in cpu_extensions_vec {
+    for cpu_extensions in utils::cpu_extensions_vec() {
         let buffer =
             upscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
         assert_eq!(
@@ -226,6 +233,19 @@ fn upscale_u8x3() {
     }
 }
 
+#[test]
+fn upscale_u16() {
+    type P = U16;
+    let buffer = upscale_test::<P>(ResizeAlg::Nearest, CpuExtensions::None);
+    assert_eq!(utils::image_u16_checksum::<1>(&buffer), [295229780570]);
+
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        let buffer =
+            upscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
+        assert_eq!(utils::image_u16_checksum::<1>(&buffer), [295246940755]);
+    }
+}
+
 #[test]
 fn downscale_u16x3() {
     type P = U16x3;
@@ -235,13 +255,7 @@ fn downscale_u16x3() {
         [755050580, 756962660, 740848503]
     );
 
-    let mut cpu_extensions_vec = vec![CpuExtensions::None];
-    #[cfg(target_arch = "x86_64")]
-    {
-        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
-        cpu_extensions_vec.push(CpuExtensions::Avx2);
-    }
-    for cpu_extensions in cpu_extensions_vec {
+    for cpu_extensions in utils::cpu_extensions_vec() {
         let buffer =
             downscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
         assert_eq!(
@@ -260,13 +274,7 @@ fn upscale_u16x3() {
         [297094122820, 297713401842, 291717497780]
     );
 
-    let mut cpu_extensions_vec = vec![CpuExtensions::None];
-    #[cfg(target_arch = "x86_64")]
-    {
-        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
-        cpu_extensions_vec.push(CpuExtensions::Avx2);
-    }
-    for cpu_extensions in cpu_extensions_vec {
+    for cpu_extensions in utils::cpu_extensions_vec() {
         let buffer =
             upscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
         assert_eq!(
@@ -276,6 +284,63 @@ fn upscale_u16x3() {
     }
 }
 
+#[test]
+fn upscale_u16x4() {
+    type P = U16x4;
+    let buffer = upscale_test::<P>(ResizeAlg::Nearest, CpuExtensions::None);
+    assert_eq!(
+        utils::image_u16_checksum::<4>(&buffer),
+        [297094122820, 297713401842, 291717497780, 1097279243280]
+    );
+
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        let buffer =
+            upscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
+        assert_eq!(
+            utils::image_u16_checksum::<4>(&buffer),
+            [297122154090, 297723994984, 291725294637, 1097279243280]
+        );
+    }
+}
+
+#[test]
+fn upscale_f32x3() {
+    type P = F32x3;
+    let buffer = upscale_test::<P>(ResizeAlg::Nearest, CpuExtensions::None);
+    assert_eq!(
+        utils::image_f32_checksum::<3>(&buffer),
+        [297094122820, 297713401842, 291717497780]
+    );
+
+    let buffer = upscale_test::<P>(
+        ResizeAlg::Convolution(FilterType::Lanczos3),
+        CpuExtensions::None,
+    );
+    assert_eq!(
+        utils::image_f32_checksum::<3>(&buffer),
+        [297095293900, 297711730476, 291716802340]
+    );
+}
+
+#[test]
+fn upscale_f32x4() {
+    type P = F32x4;
+    let buffer = upscale_test::<P>(ResizeAlg::Nearest, CpuExtensions::None);
+    assert_eq!(
+        utils::image_f32_checksum::<4>(&buffer),
+        [297094122820, 297713401842, 291717497780, 1097279243280]
+    );
+
+    let buffer = upscale_test::<P>(
+        ResizeAlg::Convolution(FilterType::Lanczos3),
+        CpuExtensions::None,
+    );
+    assert_eq!(
+        utils::image_f32_checksum::<4>(&buffer),
+        [297095293900, 297711730476, 291716802340, 1097279243280]
+    );
+}
+
 #[test]
 fn downscale_u8x4() {
     type P = U8x4;
@@ -285,13 +350,7 @@ fn downscale_u8x4() {
         [2937940, 2945380, 2882679, 11054250]
     );
 
-    let mut cpu_extensions_vec = vec![CpuExtensions::None];
-    #[cfg(target_arch = "x86_64")]
-    {
-        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
-        cpu_extensions_vec.push(CpuExtensions::Avx2);
-    }
-    for cpu_extensions in cpu_extensions_vec {
+    for cpu_extensions in utils::cpu_extensions_vec() {
         let buffer =
             downscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
         assert_eq!(
@@ -315,13 +374,7 @@ fn upscale_u8x4() {
         [1156008260, 1158417906, 1135087540, 4269569040]
     );
 
-    let mut cpu_extensions_vec = vec![CpuExtensions::None];
-    #[cfg(target_arch = "x86_64")]
-    {
-        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
-        cpu_extensions_vec.push(CpuExtensions::Avx2);
-    }
-    for cpu_extensions in cpu_extensions_vec {
+    for cpu_extensions in utils::cpu_extensions_vec() {
         let buffer =
             upscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
         assert_eq!(
@@ -331,11 +384,106 @@ fn upscale_u8x4() {
     }
 }
 
+#[test]
+fn upscale_i32() {
+    type P = I32;
+    let buffer = upscale_test::<P>(ResizeAlg::Nearest, CpuExtensions::None);
+    assert_eq!(utils::image_i32_checksum::<1>(&buffer), [9674089449717760]);
+
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        let buffer =
+            upscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
+        assert_eq!(utils::image_i32_checksum::<1>(&buffer), [9673993801702996]);
+    }
+}
+
+#[test]
+fn upscale_f32() {
+    type P = F32;
+    let buffer = upscale_test::<P>(ResizeAlg::Nearest, CpuExtensions::None);
+    assert_eq!(utils::image_f32_checksum::<1>(&buffer), [9674089449717760]);
+
+    for cpu_extensions in utils::cpu_extensions_vec() {
+        let buffer =
+            upscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
+        assert_eq!(utils::image_f32_checksum::<1>(&buffer), [9674062931645574]);
+    }
+}
+
+/// Resizes images with odd sizes by convolution with every CPU extension
+/// and checks that results are identical to results of native code.
+fn downscale_matches_native<P: PixelExt>() {
+    let mut cpu_extensions_vec = vec![];
+    #[cfg(target_arch = "x86_64")]
+    {
+        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
+        cpu_extensions_vec.push(CpuExtensions::Avx2);
+    }
+    for (src_width, src_height, dst_width, dst_height) in [(203, 117, 37, 21), (1000, 7, 99, 3)] {
+        let src_image = P::noise_image(src_width, src_height);
+        for filter_type in [FilterType::Box, FilterType::Bilinear, FilterType::Lanczos3] {
+            let resize = |cpu_extensions| {
+                let mut resizer = Resizer::new(ResizeAlg::Convolution(filter_type));
+                unsafe {
+                    resizer.set_cpu_extensions(cpu_extensions);
+                }
+                let mut dst_image = Image::new(
+                    NonZeroU32::new(dst_width).unwrap(),
+                    NonZeroU32::new(dst_height).unwrap(),
+                    P::pixel_type(),
+                );
+                resizer
+                    .resize(&src_image.view(), &mut dst_image.view_mut())
+                    .unwrap();
+                dst_image.buffer().to_vec()
+            };
+            let expected = resize(CpuExtensions::None);
+            for &cpu_extensions in &cpu_extensions_vec {
+                assert!(
+                    resize(cpu_extensions) == expected,
+                    "{}x{} -> {}x{} with {}",
+                    src_width,
+                    src_height,
+                    dst_width,
+                    dst_height,
+                    cpu_ext_into_str(cpu_extensions),
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn downscale_i32_matches_native() {
+    downscale_matches_native::<I32>();
+}
+
+#[test]
+fn downscale_f32_matches_native() {
+    downscale_matches_native::<F32>();
+}
+
+#[test]
+fn downscale_u16_matches_native() {
+    downscale_matches_native::<U16>();
+}
+
+#[test]
+fn downscale_u8x2_matches_native() {
+    downscale_matches_native::<U8x2>();
+}
+
+#[test]
+fn downscale_u16x4_matches_native() {
+    downscale_matches_native::<U16x4>();
+}
+
 // #[test]
 fn _resize_i32() {
     type P = I32;
     downscale_test::<P>(ResizeAlg::Nearest, CpuExtensions::None);
-    for cpu_extensions in [CpuExtensions::None] {
-        downscale_test::<P>(ResizeAlg::Convolution(FilterType::Lanczos3), cpu_extensions);
-    }
+    downscale_test::<P>(
+        ResizeAlg::Convolution(FilterType::Lanczos3),
+        CpuExtensions::None,
+    );
 }
@@ -0,0 +1,77 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{FilterType, Image, PixelType, ResizeAlg, Resizer, SamplingGrid};
+
+#[test]
+fn sampling_grid() {
+    let resize_row = |src: &[f32], dst_width: u32, alg: ResizeAlg, grid: SamplingGrid| {
+        let buffer: Vec<u8> = src.iter().flat_map(|v| v.to_le_bytes()).collect();
+        let one = NonZeroU32::new(1).unwrap();
+        let src_width = NonZeroU32::new(src.len() as u32).unwrap();
+        let src_image = Image::from_vec_u8(src_width, one, buffer, PixelType::F32).unwrap();
+        let mut dst_image = Image::new(NonZeroU32::new(dst_width).unwrap(), one, PixelType::F32);
+        let mut resizer = Resizer::new(alg);
+        resizer.sampling_grid = grid;
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        dst_image
+            .buffer()
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes(c.try_into().unwrap()))
+            .collect::<Vec<f32>>()
+    };
+    let bilinear = ResizeAlg::Convolution(FilterType::Bilinear);
+    let src = [0., 100., 200., 300.];
+    // Reference values of `torch.nn.functional.interpolate(mode="bilinear")`
+    // and `tf.compat.v1.image.resize_bilinear()`.
+    let cases: [(SamplingGrid, u32, &[f32]); 3] = [
+        (
+            SamplingGrid::HalfPixel,
+            8,
+            &[0., 25., 75., 125., 175., 225., 275., 300.],
+        ),
+        (
+            SamplingGrid::AlignCorners,
+            7,
+            &[0., 50., 100., 150., 200., 250., 300.],
+        ),
+        (
+            SamplingGrid::Asymmetric,
+            8,
+            &[0., 50., 100., 150., 200., 250., 300., 300.],
+        ),
+    ];
+    for (grid, dst_width, expected) in cases {
+        let result = resize_row(&src, dst_width, bilinear, grid);
+        assert_eq!(result.len(), expected.len());
+        for (&r, &e) in result.iter().zip(expected) {
+            assert!((r - e).abs() < 1e-4, "{:?} {:?}", grid, result);
+        }
+    }
+
+    // Filter isn't stretched while downscaling with `AlignCorners` and
+    // `Asymmetric` grids, like with `antialias=False` in PyTorch.
+    let src = [0., 10., 20., 40., 80., 160., 100.];
+    let cases: [(SamplingGrid, u32, &[f32]); 3] = [
+        (SamplingGrid::AlignCorners, 4, &[0., 20., 80., 100.]),
+        (SamplingGrid::AlignCorners, 3, &[0., 40., 100.]),
+        (SamplingGrid::Asymmetric, 2, &[0., 60.]),
+    ];
+    for (grid, dst_width, expected) in cases {
+        let result = resize_row(&src, dst_width, bilinear, grid);
+        assert_eq!(result, expected, "{:?}", grid);
+    }
+
+    // Reference values of nearest-neighbor resampling.
+    let src = [0., 10., 20., 30., 40.];
+    let cases: [(SamplingGrid, &[f32]); 3] = [
+        (SamplingGrid::HalfPixel, &[10., 30.]),
+        (SamplingGrid::AlignCorners, &[0., 40.]),
+        (SamplingGrid::Asymmetric, &[0., 20.]),
+    ];
+    for (grid, expected) in cases {
+        let result = resize_row(&src, 2, ResizeAlg::Nearest, grid);
+        assert_eq!(result, expected, "{:?}", grid);
+    }
+}
@@ -0,0 +1,150 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{Image, PixelType, ResizeAlg, Resizer};
+
+#[test]
+fn mode_min_max() {
+    let size = |width: u32, height: u32| {
+        (
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+        )
+    };
+    let resize = |src_image: &Image, (width, height), alg: ResizeAlg, nodata: Option<f64>| {
+        let mut dst_image = Image::new(width, height, src_image.pixel_type());
+        let mut resizer = Resizer::new(alg);
+        resizer.nodata = nodata;
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        dst_image.buffer().to_owned()
+    };
+
+    // Every pixel of the destination image covers 3x3 block
+    // of the source mask.
+    #[rustfmt::skip]
+    let buffer = vec![
+        1, 1, 3,   0, 0, 0,
+        5, 1, 3,   0, 9, 0,
+        7, 3, 1,   0, 0, 0,
+
+        2, 2, 2,   4, 4, 6,
+        2, 2, 2,   6, 6, 4,
+        2, 2, 2,   8, 8, 8,
+    ];
+    let (width, height) = size(6, 6);
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::U8).unwrap();
+    assert_eq!(
+        resize(&src_image, size(2, 2), ResizeAlg::Mode, None),
+        [1, 0, 2, 4]
+    );
+    assert_eq!(
+        resize(&src_image, size(2, 2), ResizeAlg::Min, None),
+        [1, 0, 2, 4]
+    );
+    assert_eq!(
+        resize(&src_image, size(2, 2), ResizeAlg::Max, None),
+        [7, 9, 2, 8]
+    );
+    // Zero is excluded from statistics.
+    assert_eq!(
+        resize(&src_image, size(2, 2), ResizeAlg::Mode, Some(0.)),
+        [1, 9, 2, 4]
+    );
+    assert_eq!(
+        resize(&src_image, size(2, 2), ResizeAlg::Min, Some(0.)),
+        [1, 9, 2, 4]
+    );
+
+    // Footprints with fractional scale are the same as
+    // for convolution with `Box` filter.
+    let to_bytes =
+        |values: &[i32]| -> Vec<u8> { values.iter().flat_map(|v| v.to_le_bytes()).collect() };
+    let (width, height) = size(5, 1);
+    let buffer = to_bytes(&[4, 1, 4, 2, 3]);
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::I32).unwrap();
+    let results = [
+        (ResizeAlg::Mode, [4, 2]),
+        (ResizeAlg::Min, [1, 2]),
+        (ResizeAlg::Max, [4, 3]),
+    ];
+    for (alg, expected) in results {
+        assert_eq!(
+            resize(&src_image, size(2, 1), alg, None),
+            to_bytes(&expected)
+        );
+    }
+    // Pixels without valid samples are set to the nodata value.
+    let buffer = to_bytes(&[-1, -1, -1, 2, -1]);
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::I32).unwrap();
+    for alg in [ResizeAlg::Mode, ResizeAlg::Min, ResizeAlg::Max] {
+        assert_eq!(
+            resize(&src_image, size(2, 1), alg, Some(-1.)),
+            to_bytes(&[-1, 2])
+        );
+    }
+
+    // All NaN values belong to the same class.
+    let nans = [f32::NAN, -f32::NAN, f32::from_bits(0x7fc0_0001)];
+    let buffer: Vec<u8> = [1., nans[0], 1., nans[1], nans[2]]
+        .iter()
+        .flat_map(|v: &f32| v.to_le_bytes())
+        .collect();
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::F32).unwrap();
+    let result = resize(&src_image, size(1, 1), ResizeAlg::Mode, None);
+    assert!(f32::from_le_bytes(result.try_into().unwrap()).is_nan());
+
+    // Footprint of upscaled image consists of the nearest pixel.
+    let (width, height) = size(7, 5);
+    let buffer: Vec<u8> = (0..7 * 5 * 3u32).map(|i| (i * 7919 % 251) as u8).collect();
+    let src_image = Image::from_vec_u8(width, height, buffer, PixelType::U8x3).unwrap();
+    let nearest = resize(&src_image, size(16, 11), ResizeAlg::Nearest, None);
+    for alg in [ResizeAlg::Mode, ResizeAlg::Min, ResizeAlg::Max] {
+        assert_eq!(resize(&src_image, size(16, 11), alg, None), nearest);
+    }
+}
+
+#[test]
+fn mode_ties() {
+    let one = NonZeroU32::new(1).unwrap();
+    let mode = |src_image: &Image| {
+        let mut dst_image = Image::new(one, one, src_image.pixel_type());
+        Resizer::new(ResizeAlg::Mode)
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap();
+        dst_image.buffer().to_owned()
+    };
+    let three = NonZeroU32::new(3).unwrap();
+
+    // Values 1, 5 and 3 are met twice. Value 1 is met first
+    // while reading the footprint row by row, although value 3
+    // is in the left column.
+    #[rustfmt::skip]
+    let buffer = vec![
+        9, 1, 5,
+        3, 5, 1,
+        3, 7, 8,
+    ];
+    let src_image = Image::from_vec_u8(three, three, buffer, PixelType::U8).unwrap();
+    assert_eq!(mode(&src_image), [1]);
+
+    // Ties are resolved by the first pixel regardless of values.
+    #[rustfmt::skip]
+    let buffer = vec![
+        8, 2, 6,
+        2, 8, 4,
+        6, 4, 0,
+    ];
+    let src_image = Image::from_vec_u8(three, three, buffer, PixelType::U8).unwrap();
+    assert_eq!(mode(&src_image), [8]);
+
+    // Pixels are compared by all components.
+    #[rustfmt::skip]
+    let buffer = vec![
+        1, 2, 3,   1, 2, 4,   1, 2, 4,
+        1, 2, 3,   0, 0, 0,   5, 5, 5,
+        9, 9, 9,   8, 8, 8,   7, 7, 7,
+    ];
+    let src_image = Image::from_vec_u8(three, three, buffer, PixelType::U8x3).unwrap();
+    assert_eq!(mode(&src_image), [1, 2, 3]);
+}
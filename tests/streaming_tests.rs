@@ -0,0 +1,296 @@
+use std::num::NonZeroU32;
+
+use fast_image_resize::{
+    CropMode, EdgeMode, FilterType, FloatCropBox, Image, ImageView, PixelType, ResizeAlg,
+    ResizePlan, Resizer, SamplingGrid, StreamingResizeError, StreamingResizer,
+};
+
+mod utils;
+
+fn new_src_image(
+    width: u32,
+    height: u32,
+    pixel_type: PixelType,
+    pixel_size: u32,
+) -> Image<'static> {
+    let buffer: Vec<u8> = if pixel_type == PixelType::F32 {
+        (0..width * height)
+            .flat_map(|i| ((i * 7919 % 251) as f32).to_le_bytes())
+            .collect()
+    } else {
+        (0..width * height * pixel_size)
+            .map(|i| (i * 7919 % 251) as u8)
+            .collect()
+    };
+    Image::from_vec_u8(
+        NonZeroU32::new(width).unwrap(),
+        NonZeroU32::new(height).unwrap(),
+        buffer,
+        pixel_type,
+    )
+    .unwrap()
+}
+
+#[test]
+fn streaming_result_is_equal_to_resizer_result() {
+    let pixel_types = [
+        (PixelType::U8, 1),
+        (PixelType::U8x3, 3),
+        (PixelType::U8x4, 4),
+        (PixelType::U16x3, 6),
+        (PixelType::F32, 4),
+    ];
+    let (src_width, src_height) = (211, 157);
+    let filter_type = FilterType::Lanczos3;
+    for (pixel_type, pixel_size) in pixel_types {
+        let src_image = new_src_image(src_width, src_height, pixel_type, pixel_size);
+        let row_size = (src_width * pixel_size) as usize;
+        for cpu_extensions in utils::cpu_extensions_vec() {
+            for (dst_width, dst_height) in [(97, 61), (300, 401), (211, 61), (97, 157)] {
+                let dst_width = NonZeroU32::new(dst_width).unwrap();
+                let dst_height = NonZeroU32::new(dst_height).unwrap();
+
+                let mut expected = Image::new(dst_width, dst_height, pixel_type);
+                let mut resizer = Resizer::new(ResizeAlg::Convolution(filter_type));
+                unsafe {
+                    resizer.set_cpu_extensions(cpu_extensions);
+                }
+                resizer
+                    .resize(&src_image.view(), &mut expected.view_mut())
+                    .unwrap();
+
+                let mut streaming = StreamingResizer::new(
+                    filter_type,
+                    pixel_type,
+                    src_image.width(),
+                    src_image.height(),
+                    dst_width,
+                    dst_height,
+                );
+                unsafe {
+                    streaming.set_cpu_extensions(cpu_extensions);
+                }
+                let mut result: Vec<u8> = Vec::new();
+                let mut next_y = 0;
+                // Push rows by bands with different heights.
+                let mut bands = src_image.buffer().chunks(row_size * 5).peekable();
+                while let Some(band) = bands.next() {
+                    for rows in band.chunks(row_size * 3) {
+                        let height = (rows.len() / row_size) as u32;
+                        let rows_view = ImageView::from_buffer(
+                            src_image.width(),
+                            NonZeroU32::new(height).unwrap(),
+                            rows,
+                            pixel_type,
+                        )
+                        .unwrap();
+                        streaming
+                            .push_rows(&rows_view, |y, row| {
+                                assert_eq!(y, next_y);
+                                next_y += 1;
+                                result.extend_from_slice(row);
+                            })
+                            .unwrap();
+                    }
+                    assert_eq!(streaming.is_finished(), bands.peek().is_none());
+                }
+                assert!(
+                    result == expected.buffer(),
+                    "{:?} {:?} {}x{}",
+                    pixel_type,
+                    cpu_extensions,
+                    dst_width,
+                    dst_height
+                );
+            }
+        }
+    }
+}
+
+/// Pushes rows of the source image by three and returns
+/// the destination image.
+fn resize_by_rows(streaming: &mut StreamingResizer, src_image: &Image) -> Vec<u8> {
+    let row_size = src_image.buffer().len() / src_image.height().get() as usize;
+    let mut result: Vec<u8> = Vec::new();
+    let mut next_y = 0;
+    for rows in src_image.buffer().chunks(row_size * 3) {
+        let height = (rows.len() / row_size) as u32;
+        let rows_view = ImageView::from_buffer(
+            src_image.width(),
+            NonZeroU32::new(height).unwrap(),
+            rows,
+            src_image.pixel_type(),
+        )
+        .unwrap();
+        streaming
+            .push_rows(&rows_view, |y, row| {
+                assert_eq!(y, next_y);
+                next_y += 1;
+                result.extend_from_slice(row);
+            })
+            .unwrap();
+    }
+    assert!(streaming.is_finished());
+    result
+}
+
+#[test]
+fn streaming_result_with_plan_is_equal_to_resizer_result() {
+    let pixel_type = PixelType::U8x4;
+    let src_image = new_src_image(211, 157, pixel_type, 4);
+    let algorithms = [
+        ResizeAlg::Convolution(FilterType::Lanczos3),
+        ResizeAlg::SeparateConvolution {
+            horizontal: FilterType::Bilinear,
+            vertical: FilterType::Lanczos3,
+        },
+        ResizeAlg::Area,
+    ];
+    let options = [
+        (1., EdgeMode::Renormalize, SamplingGrid::HalfPixel),
+        (1.5, EdgeMode::Clamp, SamplingGrid::AlignCorners),
+        (0.8, EdgeMode::Mirror, SamplingGrid::Asymmetric),
+        (1., EdgeMode::Transparent, SamplingGrid::HalfPixel),
+    ];
+    let crop_boxes = [
+        FloatCropBox {
+            left: 0.,
+            top: 0.,
+            width: 211.,
+            height: 157.,
+        },
+        FloatCropBox {
+            left: 13.5,
+            top: 21.25,
+            width: 150.,
+            height: 101.7,
+        },
+    ];
+    for algorithm in algorithms {
+        for (blur, edge_mode, sampling_grid) in options {
+            for crop_box in crop_boxes {
+                for crop_mode in [CropMode::Surrounding, CropMode::Boundary] {
+                    for (dst_width, dst_height) in [(97, 61), (300, 101)] {
+                        let dst_width = NonZeroU32::new(dst_width).unwrap();
+                        let dst_height = NonZeroU32::new(dst_height).unwrap();
+                        let plan = ResizePlan::new(
+                            algorithm,
+                            pixel_type,
+                            src_image.width(),
+                            src_image.height(),
+                            crop_box,
+                            dst_width,
+                            dst_height,
+                        )
+                        .unwrap()
+                        .with_blur(blur)
+                        .with_edge_mode(edge_mode)
+                        .with_sampling_grid(sampling_grid)
+                        .with_crop_mode(crop_mode);
+
+                        let mut src_view = src_image.view();
+                        src_view.set_float_crop_box(crop_box).unwrap();
+                        src_view.set_crop_mode(crop_mode);
+                        let mut expected = Image::new(dst_width, dst_height, pixel_type);
+                        Resizer::new(ResizeAlg::Nearest)
+                            .resize_with_plan(&plan, &src_view, &mut expected.view_mut())
+                            .unwrap();
+
+                        let mut streaming = StreamingResizer::from_plan(&plan).unwrap();
+                        assert!(
+                            resize_by_rows(&mut streaming, &src_image) == expected.buffer(),
+                            "{:?} {:?} {:?} {:?} {}x{}",
+                            algorithm,
+                            edge_mode,
+                            crop_box,
+                            crop_mode,
+                            dst_width,
+                            dst_height
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn streaming_errors() {
+    let src_image = new_src_image(4, 2, PixelType::U8x3, 3);
+    let size = NonZeroU32::new(2).unwrap();
+    let mut streaming = StreamingResizer::new(
+        FilterType::Bilinear,
+        PixelType::U8,
+        src_image.width(),
+        src_image.height(),
+        size,
+        size,
+    );
+    assert_eq!(
+        streaming.push_rows(&src_image.view(), |_, _| {}),
+        Err(StreamingResizeError::PixelTypeIsDifferent)
+    );
+
+    let mut streaming = StreamingResizer::new(
+        FilterType::Bilinear,
+        PixelType::U8x3,
+        size,
+        src_image.height(),
+        size,
+        size,
+    );
+    assert_eq!(
+        streaming.push_rows(&src_image.view(), |_, _| {}),
+        Err(StreamingResizeError::InvalidRowSize)
+    );
+
+    let mut streaming = StreamingResizer::new(
+        FilterType::Bilinear,
+        PixelType::U8x3,
+        src_image.width(),
+        NonZeroU32::new(1).unwrap(),
+        size,
+        size,
+    );
+    assert_eq!(
+        streaming.push_rows(&src_image.view(), |_, _| {}),
+        Err(StreamingResizeError::TooManyRows)
+    );
+
+    let size = |width: u32, height: u32| {
+        (
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+        )
+    };
+    let plan = |algorithm: ResizeAlg, (src_width, src_height), (dst_width, dst_height)| {
+        ResizePlan::new(
+            algorithm,
+            PixelType::U8x3,
+            src_width,
+            src_height,
+            FloatCropBox {
+                left: 0.,
+                top: 0.,
+                width: 100.,
+                height: 100.,
+            },
+            dst_width,
+            dst_height,
+        )
+        .unwrap()
+    };
+    let convolution = ResizeAlg::Convolution(FilterType::Lanczos3);
+    let unsupported_plans = [
+        plan(ResizeAlg::Nearest, size(100, 100), size(10, 10)),
+        plan(convolution, size(100, 100), size(10, 10)).with_nodata(Some(0.)),
+        // Taps of the filter are wrapped around to the opposite edge.
+        plan(convolution, size(100, 100), size(10, 10)).with_edge_mode(EdgeMode::Wrap),
+    ];
+    for plan in unsupported_plans {
+        assert_eq!(
+            StreamingResizer::from_plan(&plan).err(),
+            Some(StreamingResizeError::UnsupportedPlan)
+        );
+    }
+}
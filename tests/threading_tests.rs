@@ -0,0 +1,80 @@
+#![cfg(feature = "rayon")]
+use std::num::NonZeroU32;
+
+use fast_image_resize::{CpuExtensions, FilterType, Image, PixelType, ResizeAlg, Resizer};
+
+fn resize_in_pool(
+    threads: usize,
+    src_image: &Image,
+    dst_width: u32,
+    dst_height: u32,
+    cpu_extensions: CpuExtensions,
+) -> Vec<u8> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap();
+    let mut dst_image = Image::new(
+        NonZeroU32::new(dst_width).unwrap(),
+        NonZeroU32::new(dst_height).unwrap(),
+        src_image.pixel_type(),
+    );
+    let mut resizer = Resizer::new(ResizeAlg::Convolution(FilterType::Lanczos3));
+    unsafe {
+        resizer.set_cpu_extensions(cpu_extensions);
+    }
+    pool.install(|| {
+        resizer
+            .resize(&src_image.view(), &mut dst_image.view_mut())
+            .unwrap()
+    });
+    dst_image.buffer().to_vec()
+}
+
+#[test]
+fn multi_threaded_result_is_equal_to_single_threaded() {
+    let mut cpu_extensions_vec = vec![CpuExtensions::None];
+    #[cfg(target_arch = "x86_64")]
+    {
+        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
+        cpu_extensions_vec.push(CpuExtensions::Avx2);
+    }
+    let width = NonZeroU32::new(211).unwrap();
+    let height = NonZeroU32::new(157).unwrap();
+    let pixel_types = [
+        (PixelType::U8, 1),
+        (PixelType::U8x3, 3),
+        (PixelType::U8x4, 4),
+        (PixelType::U16x3, 6),
+        (PixelType::F32, 4),
+    ];
+    for (pixel_type, pixel_size) in pixel_types {
+        let buffer: Vec<u8> = if pixel_type == PixelType::F32 {
+            (0..width.get() * height.get())
+                .flat_map(|i| ((i * 7919 % 251) as f32).to_le_bytes())
+                .collect()
+        } else {
+            (0..width.get() * height.get() * pixel_size)
+                .map(|i| (i * 7919 % 251) as u8)
+                .collect()
+        };
+        let src_image = Image::from_vec_u8(width, height, buffer, pixel_type).unwrap();
+
+        for &cpu_extensions in cpu_extensions_vec.iter() {
+            for (dst_width, dst_height) in [(97, 61), (300, 401)] {
+                let expected = resize_in_pool(1, &src_image, dst_width, dst_height, cpu_extensions);
+                for threads in [2, 3, 7] {
+                    let result =
+                        resize_in_pool(threads, &src_image, dst_width, dst_height, cpu_extensions);
+                    assert!(
+                        result == expected,
+                        "{:?} {:?} {} threads",
+                        pixel_type,
+                        cpu_extensions,
+                        threads
+                    );
+                }
+            }
+        }
+    }
+}
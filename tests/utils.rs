@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use std::num::NonZeroU32;
 
 use image::io::Reader as ImageReader;
@@ -23,15 +25,38 @@ pub fn image_u16_checksum<const N: usize>(buffer: &[u8]) -> [u64; N] {
     res
 }
 
+pub fn image_i32_checksum<const N: usize>(buffer: &[u8]) -> [i64; N] {
+    let buffer_i32 = unsafe { buffer.align_to::<i32>().1 };
+    let mut res = [0i64; N];
+    for pixel in buffer_i32.chunks_exact(N) {
+        res.iter_mut().zip(pixel).for_each(|(d, &s)| *d += s as i64);
+    }
+    res
+}
+
+pub fn image_f32_checksum<const N: usize>(buffer: &[u8]) -> [u64; N] {
+    let buffer_f32 = unsafe { buffer.align_to::<f32>().1 };
+    let mut res = [0f64; N];
+    for pixel in buffer_f32.chunks_exact(N) {
+        res.iter_mut().zip(pixel).for_each(|(d, &s)| *d += s as f64);
+    }
+    res.map(|v| v.round() as u64)
+}
+
 pub trait PixelExt: Pixel {
     fn pixel_type_str() -> &'static str {
         match Self::pixel_type() {
             PixelType::U8 => "u8",
+            PixelType::U8x2 => "u8x2",
             PixelType::U8x3 => "u8x3",
             PixelType::U8x4 => "u8x4",
+            PixelType::U16 => "u16",
             PixelType::U16x3 => "u16x3",
+            PixelType::U16x4 => "u16x4",
             PixelType::I32 => "i32",
             PixelType::F32 => "f32",
+            PixelType::F32x3 => "f32x3",
+            PixelType::F32x4 => "f32x4",
             _ => unreachable!(),
         }
     }
@@ -78,6 +103,32 @@ pub trait PixelExt: Pixel {
         .unwrap()
     }
 
+    /// Creates image with pseudo-random values of components of pixels.
+    fn noise_image(width: u32, height: u32) -> Image<'static> {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let words = std::iter::repeat_with(move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 32) as u32
+        });
+        let size = (width * height) as usize * Self::size();
+        let buffer: Vec<u8> = match Self::pixel_type() {
+            PixelType::F32 | PixelType::F32x3 | PixelType::F32x4 => words
+                .flat_map(|w| ((w >> 1) as f32).to_le_bytes())
+                .take(size)
+                .collect(),
+            _ => words.flat_map(|w| w.to_le_bytes()).take(size).collect(),
+        };
+        Image::from_vec_u8(
+            NonZeroU32::new(width).unwrap(),
+            NonZeroU32::new(height).unwrap(),
+            buffer,
+            Self::pixel_type(),
+        )
+        .unwrap()
+    }
+
     fn img_into_bytes(img: DynamicImage) -> Vec<u8>;
 }
 
@@ -87,6 +138,12 @@ impl PixelExt for U8 {
     }
 }
 
+impl PixelExt for U8x2 {
+    fn img_into_bytes(img: DynamicImage) -> Vec<u8> {
+        img.to_luma_alpha8().into_raw()
+    }
+}
+
 impl PixelExt for U8x3 {
     fn img_into_bytes(img: DynamicImage) -> Vec<u8> {
         img.to_rgb8().into_raw()
@@ -99,6 +156,16 @@ impl PixelExt for U8x4 {
     }
 }
 
+impl PixelExt for U16 {
+    fn img_into_bytes(img: DynamicImage) -> Vec<u8> {
+        img.to_luma16()
+            .as_raw()
+            .iter()
+            .flat_map(|&c| c.to_le_bytes())
+            .collect()
+    }
+}
+
 impl PixelExt for U16x3 {
     fn img_into_bytes(img: DynamicImage) -> Vec<u8> {
         img.to_rgb8()
@@ -109,6 +176,16 @@ impl PixelExt for U16x3 {
     }
 }
 
+impl PixelExt for U16x4 {
+    fn img_into_bytes(img: DynamicImage) -> Vec<u8> {
+        img.to_rgba8()
+            .as_raw()
+            .iter()
+            .flat_map(|&c| [c, c])
+            .collect()
+    }
+}
+
 impl PixelExt for I32 {
     fn img_into_bytes(img: DynamicImage) -> Vec<u8> {
         img.to_luma16()
@@ -131,22 +208,67 @@ impl PixelExt for F32 {
     }
 }
 
+impl PixelExt for F32x3 {
+    fn img_into_bytes(img: DynamicImage) -> Vec<u8> {
+        img.to_rgb16()
+            .as_raw()
+            .iter()
+            .flat_map(|&c| (c as f32).to_le_bytes())
+            .collect()
+    }
+}
+
+impl PixelExt for F32x4 {
+    fn img_into_bytes(img: DynamicImage) -> Vec<u8> {
+        img.to_rgba16()
+            .as_raw()
+            .iter()
+            .flat_map(|&c| (c as f32).to_le_bytes())
+            .collect()
+    }
+}
+
 pub fn save_result(image: &Image, name: &str) {
     if std::env::var("DONT_SAVE_RESULT").unwrap_or_else(|_| "".to_owned()) == "1" {
         return;
     }
     std::fs::create_dir_all("./data/result").unwrap();
     let path = format!("./data/result/{}.png", name);
-    let color_type = match image.pixel_type() {
-        PixelType::U8x3 => ColorType::Rgb8,
-        PixelType::U8x4 => ColorType::Rgba8,
-        PixelType::U16x3 => ColorType::Rgb16,
-        PixelType::U8 => ColorType::L8,
+    // Images with floating point components are saved as 16-bit images.
+    let f32_into_u16 = |scale: f32| -> Vec<u8> {
+        let buffer_f32 = unsafe { image.buffer().align_to::<f32>().1 };
+        buffer_f32
+            .iter()
+            .flat_map(|&c| ((c / scale).round() as u16).to_ne_bytes())
+            .collect()
+    };
+    // Test images with I32 and F32 pixels are made from 16-bit images
+    // multiplied by this value.
+    let scale_i32 = i16::MAX as f32 + 1.0;
+    let i32_into_u16 = || -> Vec<u8> {
+        let buffer_i32 = unsafe { image.buffer().align_to::<i32>().1 };
+        buffer_i32
+            .iter()
+            .flat_map(|&c| ((c as f32 / scale_i32).round() as u16).to_ne_bytes())
+            .collect()
+    };
+    let (color_type, buffer) = match image.pixel_type() {
+        PixelType::U8x2 => (ColorType::La8, image.buffer().to_vec()),
+        PixelType::U8x3 => (ColorType::Rgb8, image.buffer().to_vec()),
+        PixelType::U8x4 => (ColorType::Rgba8, image.buffer().to_vec()),
+        PixelType::U16 => (ColorType::L16, image.buffer().to_vec()),
+        PixelType::U16x3 => (ColorType::Rgb16, image.buffer().to_vec()),
+        PixelType::U16x4 => (ColorType::Rgba16, image.buffer().to_vec()),
+        PixelType::U8 => (ColorType::L8, image.buffer().to_vec()),
+        PixelType::I32 => (ColorType::L16, i32_into_u16()),
+        PixelType::F32 => (ColorType::L16, f32_into_u16(scale_i32)),
+        PixelType::F32x3 => (ColorType::Rgb16, f32_into_u16(1.0)),
+        PixelType::F32x4 => (ColorType::Rgba16, f32_into_u16(1.0)),
         _ => panic!("Unsupported type of pixels"),
     };
     image::save_buffer(
         &path,
-        image.buffer(),
+        &buffer,
         image.width().get(),
         image.height().get(),
         color_type,
@@ -163,3 +285,14 @@ pub fn cpu_ext_into_str(cpu_extensions: CpuExtensions) -> &'static str {
         CpuExtensions::Avx2 => "avx2",
     }
 }
+
+/// Returns all CPU-extensions that may be used on the current architecture.
+pub fn cpu_extensions_vec() -> Vec<CpuExtensions> {
+    let mut cpu_extensions_vec = vec![CpuExtensions::None];
+    #[cfg(target_arch = "x86_64")]
+    {
+        cpu_extensions_vec.push(CpuExtensions::Sse4_1);
+        cpu_extensions_vec.push(CpuExtensions::Avx2);
+    }
+    cpu_extensions_vec
+}